@@ -1,3 +0,0 @@
-fn main() {
-    println!("cargo:rustc-link-search=C:\\VulkanSDK\\1.0.11.0\\Bin")
-}
@@ -1,44 +1,60 @@
 extern crate spock;
 
 use spock::*;
+use spock::instance::InstanceBuilder;
+use spock::swapchain::{self, Swapchain, SwapchainOptions};
 use spock::types::*;
+use spock::wsi::{self, Surface};
 use std::ffi::CString;
 
 fn main() {
-    let application_info = ApplicationInfo {
-        pApplicationName: CString::new("Testing").unwrap().as_ptr(),
-        pEngineName:      CString::new("Test").unwrap().as_ptr(),
-        ..Default::default()
-    };
-
-    let instance_create_info = InstanceCreateInfo {
-        pApplicationInfo: &application_info,
-        ..Default::default()
-    };
-
-    let instance: Instance = create_instance(instance_create_info, None).unwrap();
-    let physical_device: PhyiscalDevice = instance.enumerate_all_physical_devices().unwrap()[0];
+    let instance = InstanceBuilder::new()
+        .application("Testing", make_version(0, 1, 0))
+        .engine("Test", make_version(0, 1, 0))
+        .extensions(wsi::HEADLESS_EXTENSIONS)
+        .build(None)
+        .unwrap();
+
+    let surface = Surface::headless(&instance, None).unwrap();
+    let physical_device: PhysicalDevice = instance.enumerate_all_physical_devices().unwrap()[0];
     let queue_properties: Vec<QueueFamilyProperties> = physical_device.get_all_queue_family_properties();
 
-    let graphics_queue_index = queue_properties.iter().position(|&p| (p.queueFlags as u32 & QueueFlags::Graphics as u32) > 0).unwrap();
+    let graphics_queue_index = (0..queue_properties.len() as u32).find(|&index| {
+        queue_properties[index as usize].queueFlags.contains(QueueFlags::Graphics) &&
+            physical_device.get_surface_support(index, surface.handle()).unwrap()
+    }).unwrap();
 
+    let queue_priority = 1.0;
     let queue_create_info = DeviceQueueCreateInfo {
-        queueFamilyIndex: graphics_queue_index as u32,
+        queueFamilyIndex: graphics_queue_index,
         queueCount: 1,
+        pQueuePriorities: &queue_priority,
         ..Default::default()
     };
 
+    let device_extensions: Vec<CString> = swapchain::EXTENSIONS.iter().map(|&name| CString::new(name).unwrap()).collect();
+    let device_extension_pointers: Vec<*const _> = device_extensions.iter().map(|name| name.as_ptr()).collect();
     let device_create_info = DeviceCreateInfo {
         queueCreateInfoCount: 1,
         pQueueCreateInfos: &queue_create_info,
+        enabledExtensionCount: device_extension_pointers.len() as u32,
+        ppEnabledExtensionNames: device_extension_pointers.as_ptr(),
         ..Default::default()
     };
 
-    let device: Device = physical_device.create_device(device_create_info, None).unwrap();
+    let device = instance.create_device(physical_device, device_create_info, None).unwrap();
+
+    let swapchain_options = SwapchainOptions {
+        extent: Extent2D { width: 640, height: 480 },
+        ..Default::default()
+    };
+    let swapchain = Swapchain::new(&device, &surface, graphics_queue_index, swapchain_options, None).unwrap();
+    let Extent2D { width, height } = swapchain.extent();
+    let depth_format = Format::D32Sfloat;
 
     let create_command_pool_info = CommandPoolCreateInfo {
         flags: CommandPoolCreateFlags::ResetCommandBuffer,
-        queueFamilyIndex: swapchain.queue_node_index,
+        queueFamilyIndex: swapchain.queue_node_index(),
         ..Default::default()
     };
 
@@ -54,13 +70,13 @@ fn main() {
     let image_command_buffer_allocate_info = CommandBufferAllocateInfo {
         commandPool: command_pool,
         level: CommandBufferLevel::Primary,
-        commandBufferCount: swapchain.image_count,
+        commandBufferCount: swapchain.image_count(),
         ..Default::default()
     };
 
-    let setup_command_buffer: CommandBuffer = device.allocate_command_buffers(single_command_buffer_allocate_info).unwrap()[0];
+    let mut setup_command_buffer: CommandBuffer = device.allocate_command_buffers(single_command_buffer_allocate_info).unwrap()[0];
     let begin_command_buffer_info = CommandBufferBeginInfo{..Default::default()};
-    setup_command_buffer.begin(begin_command_buffer_info);
+    setup_command_buffer.begin(begin_command_buffer_info).unwrap();
 
     let draw_command_buffers = device.allocate_command_buffers(image_command_buffer_allocate_info).unwrap();
     let post_present_command_buffer = device.allocate_command_buffers(single_command_buffer_allocate_info).unwrap()[0];
@@ -68,9 +84,9 @@ fn main() {
     let create_depth_stencil_info = ImageCreateInfo {
         imageType: ImageType::Type2D,
         format: depth_format,
-        extent: Extend3D {
+        extent: Extent3D {
             width: width,
-            height: heightSampleCountFlags,
+            height: height,
             depth: 1
         },
         mipLevels: 1,
@@ -146,6 +162,4 @@ fn main() {
     const triangle_indices: [u32; 3] = [ 0, 1, 2 ];
 
     println!("success!");
-
-    instance.destroy(None);
 }
@@ -0,0 +1,569 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use names::{enumerant, member_name, type_name};
+use registry::{CType, Command, Constant, Enums, Format, Member, Registry, Type, TypeKind};
+
+const HEADER: &str = "// Generated from generator/vk.xml by `cargo run -p spock-generator`. Edit the\n\
+                              // registry or the generator and regenerate rather than changing this file.\n";
+
+// Command declarations longer than this are wrapped onto continuation lines.
+const LINE_WIDTH: usize = 160;
+
+// The parts of the registry pulled in by the selected features and
+// extensions, along with everything they refer to.
+pub struct Selection<'a> {
+    registry: &'a Registry,
+    types: HashSet<&'a str>,
+    constants: Vec<Constant>,
+    // Constants used as array lengths, which Rust wants as usize.
+    lengths: HashSet<String>,
+    commands: Vec<&'a Command>
+}
+
+impl<'a> Selection<'a> {
+    pub fn new(registry: &'a Registry, features: &[&str], extensions: &[&str]) -> Result<Selection<'a>, String> {
+        let mut selection = Selection {
+            registry,
+            types: HashSet::new(),
+            constants: Vec::new(),
+            lengths: HashSet::new(),
+            commands: Vec::new()
+        };
+
+        let mut interfaces = Vec::new();
+        for name in features {
+            interfaces.push(registry.features.iter().find(|feature| feature.name == *name).ok_or_else(|| format!("unknown feature {}", name))?);
+        }
+        for name in extensions {
+            interfaces.push(registry.extensions.iter().find(|extension| extension.name == *name).ok_or_else(|| format!("unknown extension {}", name))?);
+        }
+
+        let mut constants = HashSet::new();
+        for interface in &interfaces {
+            for name in &interface.types {
+                selection.require_type(name)?;
+            }
+            for name in &interface.commands {
+                let command = registry.commands.get(name).ok_or_else(|| format!("{} requires unknown command {}", interface.name, name))?;
+                if !selection.commands.iter().any(|existing| existing.name == command.name) {
+                    selection.require_ctype(&command.ret)?;
+                    for param in &command.params {
+                        selection.require_ctype(&param.ty)?;
+                    }
+                    selection.commands.push(command);
+                }
+            }
+            for name in &interface.constants {
+                constants.insert(name.clone());
+            }
+        }
+        for length in &selection.lengths {
+            constants.insert(length.clone());
+        }
+
+        for constant in &registry.constants {
+            if constants.contains(&constant.name) {
+                selection.constants.push(Constant { name: constant.name.clone(), ty: constant.ty.clone(), value: constant.value.clone() });
+            }
+        }
+        for interface in &interfaces {
+            for definition in &interface.definitions {
+                selection.constants.push(Constant { name: definition.name.clone(), ty: definition.ty.clone(), value: definition.value.clone() });
+            }
+        }
+        for name in &constants {
+            if registry.find_constant(name).is_none() {
+                return Err(format!("unknown constant {}", name));
+            }
+        }
+
+        Ok(selection)
+    }
+
+    fn require_type(&mut self, name: &'a str) -> Result<(), String> {
+        if !self.types.insert(name) {
+            return Ok(());
+        }
+        let registry = self.registry;
+        let ty = registry.find_type(name).ok_or_else(|| format!("unknown type {}", name))?;
+        match ty.kind {
+            TypeKind::Alias(ref target) => self.require_type(target)?,
+            TypeKind::Base(Some(ref underlying)) => self.require_ctype(underlying)?,
+            TypeKind::Bitmask { bits: Some(ref bits), .. } => self.require_type(bits)?,
+            TypeKind::Enum => if let Some(flags) = registry.flags_for_bits(name) {
+                self.require_type(flags)?;
+            },
+            TypeKind::FuncPointer { ref ret, ref params } => {
+                self.require_ctype(ret)?;
+                for param in params {
+                    self.require_ctype(&param.ty)?;
+                }
+            },
+            TypeKind::Struct { ref members, .. } => for member in members {
+                self.require_ctype(&member.ty)?;
+            },
+            _ => ()
+        }
+        Ok(())
+    }
+
+    fn require_ctype(&mut self, ty: &'a CType) -> Result<(), String> {
+        match *ty {
+            CType::Named(ref name) => self.require_type(name),
+            CType::Pointer { ref to, .. } => self.require_ctype(to),
+            CType::Array { ref of, ref length, .. } => {
+                if !length.chars().all(|c| c.is_ascii_digit()) {
+                    self.lengths.insert(length.clone());
+                }
+                self.require_ctype(of)
+            }
+        }
+    }
+
+    // The selected types of one kind, in registry order.
+    fn types_where<F>(&self, filter: F) -> Vec<&'a Type> where F: Fn(&TypeKind) -> bool {
+        self.registry.types.iter().filter(|ty| self.types.contains(ty.name.as_str()) && filter(&ty.kind)).collect()
+    }
+
+    fn kind(&self, name: &str) -> Option<&'a TypeKind> {
+        let registry = self.registry;
+        registry.find_type(registry.resolve(name)).map(|ty| &ty.kind)
+    }
+
+    fn is_funcpointer(&self, name: &str) -> bool {
+        matches!(self.kind(name), Some(&TypeKind::FuncPointer { .. }))
+    }
+
+    // Commands are dispatched through the object their first parameter names.
+    fn table(&self, command: &Command) -> Table {
+        let first = match command.params.first().map(|param| &param.ty) {
+            Some(CType::Named(name)) => self.registry.resolve(name),
+            _                             => return Table::Entry
+        };
+        match (self.kind(first), first) {
+            (Some(&TypeKind::Handle { dispatchable: true, .. }), "VkInstance")       => Table::Instance,
+            (Some(&TypeKind::Handle { dispatchable: true, .. }), "VkPhysicalDevice") => Table::Instance,
+            (Some(&TypeKind::Handle { dispatchable: true, .. }), _)                  => Table::Device,
+            _                                                                    => Table::Entry
+        }
+    }
+
+    fn rust_type(&self, ty: &CType) -> String {
+        match *ty {
+            CType::Named(ref name) if self.is_funcpointer(name) => format!("Option<{}>", type_name(self.registry, name)),
+            CType::Named(ref name) => type_name(self.registry, name),
+            CType::Pointer { mutable, ref to } => format!("*{} {}", if mutable { "mut" } else { "const" }, self.rust_type(to)),
+            CType::Array { ref of, ref length, .. } => format!("[{}; {}]", self.rust_type(of), length)
+        }
+    }
+
+    // Arrays are passed to commands by pointer.
+    fn param_type(&self, ty: &CType) -> String {
+        match *ty {
+            CType::Array { constant, .. } => format!("*{} {}", if constant { "const" } else { "mut" }, self.rust_type(ty)),
+            _                             => self.rust_type(ty)
+        }
+    }
+
+    fn return_type(&self, ty: &CType) -> Option<String> {
+        match *ty {
+            CType::Named(ref name) if name == "void" => None,
+            _                                        => Some(self.rust_type(ty))
+        }
+    }
+
+    fn default_value(&self, ty: &CType) -> Result<String, String> {
+        let name = match *ty {
+            CType::Pointer { mutable: true, .. }    => return Ok("ptr::null_mut()".to_string()),
+            CType::Pointer { mutable: false, .. }   => return Ok("ptr::null()".to_string()),
+            CType::Array { ref of, ref length, .. } => return Ok(format!("[{}; {}]", self.default_value(of)?, length)),
+            CType::Named(ref name)                  => name
+        };
+
+        let rust = type_name(self.registry, name);
+        Ok(match self.kind(name) {
+            Some(&TypeKind::Base(Some(ref underlying))) => self.default_value(underlying)?,
+            Some(&TypeKind::Bitmask { .. }) => format!("{}::empty()", rust),
+            Some(&TypeKind::Handle { .. }) => format!("{}::null()", rust),
+            Some(&TypeKind::FuncPointer { .. }) => "None".to_string(),
+            Some(&TypeKind::Struct { union: false, .. }) => format!("{}{{..Default::default()}}", rust),
+            Some(&TypeKind::Struct { union: true, .. }) => format!("{}::default()", rust),
+            Some(&TypeKind::Enum) if self.registry.flags_for_bits(name).is_some() => format!("{}::empty()", rust),
+            Some(&TypeKind::Enum) => {
+                let resolved = self.registry.resolve(name);
+                let enums = self.registry.enums.get(resolved).ok_or_else(|| format!("{} has no values", name))?;
+                let value = enums.values.iter().find(|value| value.value == 0).or(enums.values.first())
+                                 .ok_or_else(|| format!("{} has no values", name))?;
+                format!("{}::{}", rust, enumerant(self.registry, resolved, &value.name))
+            },
+            _ if name == "float" || name == "double" => "0.0".to_string(),
+            _ => "0".to_string()
+        })
+    }
+}
+
+// The body of src/generated/types.rs. Enums named in `open` become open_enum!
+// newtypes rather than Rust enums.
+pub fn types(selection: &Selection, open: &[&str]) -> Result<String, String> {
+    let registry = selection.registry;
+    let mut out = String::from(HEADER);
+
+    if !selection.constants.is_empty() {
+        out.push('\n');
+        for constant in &selection.constants {
+            let ty = if selection.lengths.contains(&constant.name) {
+                "usize".to_string()
+            } else {
+                match constant.ty.as_deref() {
+                    Some(ty) if ty.starts_with('"') => "&'static str".to_string(),
+                    Some(ty)                        => type_name(registry, ty),
+                    None if constant.value.starts_with('"') => "&'static str".to_string(),
+                    None                            => "uint32_t".to_string()
+                }
+            };
+            writeln!(out, "pub const {}: {} = {};", constant.name, ty, constant_value(&constant.value)).unwrap();
+        }
+    }
+
+    let bases = selection.types_where(|kind| matches!(*kind, TypeKind::Base(Some(_)) | TypeKind::Alias(_)));
+    if !bases.is_empty() {
+        out.push('\n');
+        for ty in bases {
+            let target = match ty.kind {
+                TypeKind::Base(Some(ref underlying)) => selection.rust_type(underlying),
+                TypeKind::Alias(ref target)          => type_name(registry, target),
+                _                                    => unreachable!()
+            };
+            writeln!(out, "pub type {} = {};", type_name(registry, &ty.name), target).unwrap();
+        }
+    }
+
+    let handles = selection.types_where(|kind| matches!(*kind, TypeKind::Handle { .. }));
+    if !handles.is_empty() {
+        out.push('\n');
+        for ty in handles {
+            // Dispatchable handles are pointers to loader objects; the rest are
+            // 64-bit values even on 32-bit targets.
+            let (raw, null) = match ty.kind {
+                TypeKind::Handle { dispatchable: true, .. } => ("*mut c_void", "ptr::null_mut()"),
+                _                                           => ("uint64_t", "0")
+            };
+            match ty.kind {
+                TypeKind::Handle { object_type: Some(ref object_type), .. } => {
+                    let variant = enumerant(registry, "VkObjectType", object_type);
+                    writeln!(out, "handle!({}, {}, {}, {});", type_name(registry, &ty.name), raw, null, variant).unwrap();
+                },
+                _ => writeln!(out, "handle!({}, {}, {});", type_name(registry, &ty.name), raw, null).unwrap()
+            }
+        }
+    }
+
+    let funcpointers = selection.types_where(|kind| matches!(*kind, TypeKind::FuncPointer { .. }));
+    if !funcpointers.is_empty() {
+        out.push('\n');
+        for ty in funcpointers {
+            if let TypeKind::FuncPointer { ref ret, ref params } = ty.kind {
+                let params: Vec<String> = params.iter().map(|param| selection.param_type(&param.ty)).collect();
+                let ret = selection.return_type(ret).map(|ret| format!(" -> {}", ret)).unwrap_or_default();
+                writeln!(out, "pub type {} = unsafe extern \"system\" fn({}){};", type_name(registry, &ty.name), params.join(", "), ret).unwrap();
+            }
+        }
+    }
+
+    for ty in selection.types_where(|kind| matches!(*kind, TypeKind::Enum)) {
+        if registry.flags_for_bits(&ty.name).is_some() {
+            continue;
+        }
+        let enums = match registry.enums.get(&ty.name) {
+            Some(enums) => enums,
+            None        => continue
+        };
+
+        if ty.name == "VkResult" {
+            out.push('\n');
+            write_result_codes(&mut out, registry, ty, enums);
+            continue;
+        }
+
+        let mut seen = HashSet::new();
+        let mut variants = Vec::new();
+        for value in &enums.values {
+            if seen.insert(value.value) {
+                variants.push(format!("    {} = {}", enumerant(registry, &ty.name, &value.name), decimal(value.value)));
+            }
+        }
+
+        out.push('\n');
+        write_comment(&mut out, ty);
+        if open.contains(&ty.name.as_str()) {
+            writeln!(out, "open_enum!({} {{\n{}\n}});", type_name(registry, &ty.name), variants.join(",\n")).unwrap();
+        } else {
+            writeln!(out, "#[repr(C)]\n#[derive(Copy, Clone, Debug, PartialEq, Eq)]\npub enum {} {{", type_name(registry, &ty.name)).unwrap();
+            writeln!(out, "{}\n}}", variants.join(",\n")).unwrap();
+        }
+
+        if ty.name == "VkFormat" && !registry.formats.is_empty() {
+            out.push('\n');
+            write_format_blocks(&mut out, registry, enums);
+        }
+    }
+
+    for ty in selection.types_where(|kind| matches!(*kind, TypeKind::Bitmask { .. })) {
+        let bits = match ty.kind {
+            TypeKind::Bitmask { wide: true, .. } => return Err(format!("64-bit flags such as {} are not supported yet", ty.name)),
+            TypeKind::Bitmask { ref bits, .. }   => bits,
+            _                                    => unreachable!()
+        };
+        let values: Vec<(String, i64)> = match bits.as_ref().and_then(|bits| registry.enums.get(bits).map(|enums| (bits, enums))) {
+            Some((bits, enums)) => enums.values.iter().filter(|value| value.value != 0)
+                                        .map(|value| (enumerant(registry, bits, &value.name), value.value)).collect(),
+            None                => Vec::new()
+        };
+
+        out.push('\n');
+        write_comment(&mut out, ty);
+        let name = type_name(registry, &ty.name);
+        if values.is_empty() {
+            writeln!(out, "bitmask!({} {{}});", name).unwrap();
+        } else {
+            let width = if values.iter().any(|&(_, value)| value > 0xFF) { 8 } else { 2 };
+            let flags: Vec<String> = values.iter().map(|&(ref flag, value)| format!("    {} = 0x{:0width$X}", flag, value, width = width)).collect();
+            writeln!(out, "bitmask!({} {{\n{}\n}});", name, flags.join(",\n")).unwrap();
+        }
+    }
+
+    for ty in selection.types_where(|kind| matches!(*kind, TypeKind::Struct { .. })) {
+        if let TypeKind::Struct { ref members, union } = ty.kind {
+            out.push('\n');
+            write_struct(&mut out, selection, ty, members, union)?;
+        }
+    }
+
+    Ok(out)
+}
+
+// VkResult is split by sign into the codes commands return on success and
+// the ones they fail with. The `Error` prefix is redundant on the latter.
+fn write_result_codes(out: &mut String, registry: &Registry, ty: &Type, enums: &Enums) {
+    let mut seen = HashSet::new();
+    let mut success = Vec::new();
+    let mut error = Vec::new();
+    for value in &enums.values {
+        if !seen.insert(value.value) {
+            continue;
+        }
+        let variant = enumerant(registry, &ty.name, &value.name);
+        if value.value < 0 {
+            let variant = variant.strip_prefix("Error").unwrap_or(&variant);
+            error.push(format!("        {} = {}", variant, decimal(value.value)));
+        } else {
+            success.push(format!("        {} = {}", variant, decimal(value.value)));
+        }
+    }
+
+    write_comment(out, ty);
+    writeln!(out, "result_codes! {{\n    success {{\n{}\n    }}\n    error {{\n{}\n    }}\n}}", success.join(",\n"), error.join(",\n")).unwrap();
+}
+
+// Texel block sizes and extents for the formats the registry describes. Any
+// other format, such as Undefined, has a block size of 0.
+fn write_format_blocks(out: &mut String, registry: &Registry, enums: &Enums) {
+    let formats: Vec<(String, &Format)> = registry.formats.iter()
+        .filter(|format| enums.values.iter().any(|value| value.name == format.name))
+        .map(|format| (enumerant(registry, "VkFormat", &format.name), format))
+        .collect();
+
+    writeln!(out, "impl Format {{").unwrap();
+    writeln!(out, "    // The size in bytes of one texel block.").unwrap();
+    writeln!(out, "    pub fn block_size(self) -> u32 {{\n        match self {{").unwrap();
+    for &(ref variant, format) in &formats {
+        writeln!(out, "            Format::{} => {},", variant, format.block_size).unwrap();
+    }
+    writeln!(out, "            _ => 0\n        }}\n    }}\n").unwrap();
+
+    writeln!(out, "    // The width, height and depth of one texel block, in texels.").unwrap();
+    writeln!(out, "    pub fn block_extent(self) -> (u32, u32, u32) {{\n        match self {{").unwrap();
+    for &(ref variant, format) in formats.iter().filter(|&&(_, format)| format.block_extent != [1, 1, 1]) {
+        let [width, height, depth] = format.block_extent;
+        writeln!(out, "            Format::{} => ({}, {}, {}),", variant, width, height, depth).unwrap();
+    }
+    writeln!(out, "            _ => (1, 1, 1)\n        }}\n    }}\n}}").unwrap();
+}
+
+fn write_comment(out: &mut String, ty: &Type) {
+    if let Some(ref comment) = ty.comment {
+        for line in comment.lines() {
+            writeln!(out, "// {}", line.trim()).unwrap();
+        }
+    }
+}
+
+fn write_struct(out: &mut String, selection: &Selection, ty: &Type, members: &[Member], union: bool) -> Result<(), String> {
+    let registry = selection.registry;
+    let name = type_name(registry, &ty.name);
+    let fields: Vec<(String, &Member)> = members.iter().map(|member| (field_name(registry, member), member)).collect();
+
+    write_comment(out, ty);
+    writeln!(out, "#[repr(C)]\n#[allow(non_snake_case)]\n#[derive(Copy, Clone)]\npub {} {} {{", if union { "union" } else { "struct" }, name).unwrap();
+    let declarations: Vec<String> = fields.iter().map(|&(ref field, member)| format!("    pub {}: {}", field, selection.rust_type(&member.ty))).collect();
+    writeln!(out, "{}\n}}", declarations.join(",\n")).unwrap();
+
+    let structure_type = members.iter().find(|member| member.name == "sType").and_then(|member| member.values.as_ref());
+    if let Some(value) = structure_type {
+        writeln!(out, "\nunsafe impl VkStruct for {} {{", name).unwrap();
+        writeln!(out, "    const STRUCTURE_TYPE: StructureType = StructureType::{};", enumerant(registry, "VkStructureType", value)).unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    writeln!(out, "\nimpl Default for {} {{\n    fn default() -> {} {{", name, name).unwrap();
+    if union {
+        let &(ref field, member) = fields.first().ok_or_else(|| format!("{} has no members", ty.name))?;
+        writeln!(out, "        {} {{ {}: {} }}", name, field, selection.default_value(&member.ty)?).unwrap();
+    } else {
+        let mut values = Vec::new();
+        for &(ref field, member) in &fields {
+            let value = if member.name == "sType" && member.values.is_some() {
+                format!("{}::STRUCTURE_TYPE", name)
+            } else {
+                selection.default_value(&member.ty)?
+            };
+            values.push(format!("            {}: {}", field, value));
+        }
+        writeln!(out, "        {} {{\n{}\n        }}", name, values.join(",\n")).unwrap();
+    }
+    writeln!(out, "    }}\n}}").unwrap();
+    Ok(())
+}
+
+fn field_name(registry: &Registry, member: &Member) -> String {
+    match member.ty {
+        CType::Named(ref ty) => member_name(registry, &member.name, ty),
+        _                    => member.name.clone()
+    }
+}
+
+// Which dispatch table a command is loaded into.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Table {
+    Entry,
+    Instance,
+    Device
+}
+
+pub struct TableConfig<'a> {
+    pub table: Table,
+    pub name: &'a str,
+    pub lookup: &'a str,
+    // Commands that belong to another table but are loaded into this one as
+    // well, to load that table with.
+    pub borrowed: &'a [&'a str]
+}
+
+// The body of src/generated/vk.rs. Each table loads the `wrapped` commands
+// dispatched through it without generating free functions for them, which
+// src/vk.rs writes by hand.
+pub fn commands(selection: &Selection, tables: &[TableConfig], wrapped: &[String], skip: &[&str]) -> Result<String, String> {
+    let mut out = String::from(HEADER);
+
+    for name in wrapped {
+        if !selection.commands.iter().any(|command| command.name == *name) {
+            return Err(format!("{} is wrapped by hand but not selected", name));
+        }
+    }
+
+    for config in tables {
+        let mut manual: Vec<&Command> = wrapped.iter()
+            .filter_map(|name| selection.commands.iter().cloned().find(|command| command.name == *name))
+            .filter(|command| selection.table(command) == config.table)
+            .collect();
+        for name in config.borrowed {
+            let command = selection.commands.iter().find(|command| command.name == *name)
+                                   .ok_or_else(|| format!("{} is loaded by {} but not selected", name, config.name))?;
+            manual.push(*command);
+        }
+        let dispatched: Vec<&Command> = selection.commands.iter().cloned()
+            .filter(|command| !skip.contains(&command.name.as_str()) && !wrapped.contains(&command.name))
+            .filter(|command| selection.table(command) == config.table)
+            .collect();
+
+        writeln!(out, "\ndispatch_table! {{\n    {}({}) {{", config.name, config.lookup).unwrap();
+        for command in manual {
+            write_command(&mut out, selection, command);
+        }
+        writeln!(out, "    }} {{").unwrap();
+        for command in dispatched {
+            write_command(&mut out, selection, command);
+        }
+        writeln!(out, "    }}\n}}").unwrap();
+    }
+
+    Ok(out)
+}
+
+fn write_command(out: &mut String, selection: &Selection, command: &Command) {
+    let registry = selection.registry;
+    let params: Vec<String> = command.params.iter().map(|param| {
+        let name = match param.ty {
+            CType::Named(ref ty) => member_name(registry, &param.name, ty),
+            _                    => param.name.clone()
+        };
+        format!("{}: {}", name, selection.param_type(&param.ty))
+    }).collect();
+    let ret = selection.return_type(&command.ret).map(|ret| format!(" -> {}", ret)).unwrap_or_default();
+
+    let opening = format!("        fn {}(", command.name);
+    let indent = " ".repeat(opening.len());
+    let mut line = opening;
+    let mut lines = Vec::new();
+    for (index, param) in params.iter().enumerate() {
+        let last = index + 1 == params.len();
+        let piece = if last { format!("{});", param) } else { format!("{},", param) };
+        let tail = if last { ret.len() } else { 0 };
+        if !line.ends_with('(') && line.len() + 1 + piece.len() + tail > LINE_WIDTH {
+            lines.push(line);
+            line = indent.clone();
+        } else if !line.ends_with('(') {
+            line.push(' ');
+        }
+        line.push_str(&piece);
+    }
+    if params.is_empty() {
+        line.push_str(");");
+    }
+    // The return type sits before the semicolon.
+    line.pop();
+    line.push_str(&ret);
+    line.push(';');
+    lines.push(line);
+
+    for line in lines {
+        writeln!(out, "{}", line).unwrap();
+    }
+}
+
+// Translates the C spelling of an API constant into Rust.
+fn constant_value(value: &str) -> String {
+    match value {
+        "(~0U)" | "(~0ULL)" => "!0".to_string(),
+        "(~1U)"             => "!1".to_string(),
+        "(~2U)"             => "!2".to_string(),
+        _                   => value.trim_end_matches('F').trim_end_matches("ULL").trim_end_matches('U').to_string()
+    }
+}
+
+// Large enumerant values read better grouped, as in `1_000_001_002`.
+fn decimal(value: i64) -> String {
+    let digits = value.abs().to_string();
+    if value.abs() < 1_000_000_000 {
+        return value.to_string();
+    }
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push('_');
+        }
+        grouped.push(digit);
+    }
+    if value < 0 { format!("-{}", grouped) } else { grouped }
+}
@@ -0,0 +1,134 @@
+// Generates spock's Vulkan types and command tables from the API registry.
+//
+//     cargo run -p spock-generator [--check] [path/to/vk.xml]
+//
+// With no path the vendored generator/vk.xml is read. The output replaces
+// src/generated/types.rs and src/generated/vk.rs.
+//
+// The vendored registry is a subset transcribed by hand, so with --check
+// nothing is written; the output is compared with what is already in
+// src/generated instead, and the generator fails if they differ. Run against
+// the upstream vk.xml after editing the subset, this shows that the subset
+// still describes everything spock uses the way upstream does:
+//
+//     cargo run -p spock-generator -- --check path/to/Vulkan-Docs/xml/vk.xml
+
+mod emit;
+mod names;
+mod registry;
+mod xml;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use emit::{Selection, Table, TableConfig};
+
+// The parts of the registry spock exposes.
+const FEATURES: &[&str] = &["VK_VERSION_1_0"];
+const EXTENSIONS: &[&str] = &[
+    "VK_KHR_surface",
+    "VK_KHR_swapchain",
+    "VK_KHR_xlib_surface",
+    "VK_KHR_xcb_surface",
+    "VK_KHR_wayland_surface",
+    "VK_EXT_headless_surface",
+    "VK_EXT_debug_report",
+    "VK_EXT_debug_utils"
+];
+
+// Enums that drivers write into structures we read back, or that new
+// extensions keep adding to, as open_enum! newtypes. A value outside a Rust
+// enum's variants is undefined behaviour, whereas these keep it as it is.
+const OPEN_ENUMS: &[&str] = &["VkStructureType", "VkFormat", "VkColorSpaceKHR"];
+
+// Resolved by the loader rather than through a table.
+const SKIPPED_COMMANDS: &[&str] = &["vkGetInstanceProcAddr"];
+
+const TABLES: &[TableConfig<'static>] = &[
+    TableConfig { table: Table::Entry, name: "EntryFns", lookup: "entry_table", borrowed: &[] },
+    TableConfig { table: Table::Instance, name: "InstanceFns", lookup: "instance_table", borrowed: &["vkGetDeviceProcAddr"] },
+    TableConfig { table: Table::Device, name: "DeviceFns", lookup: "device_table", borrowed: &[] }
+];
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut args = env::args_os().skip(1).peekable();
+    let check = args.next_if(|arg| arg == "--check").is_some();
+    let input = match args.next() {
+        Some(path) => PathBuf::from(path),
+        None       => root.join("vk.xml")
+    };
+    let source = root.join("..").join("src");
+    let output = source.join("generated");
+
+    let result = generate(&input, &source.join("vk.rs")).and_then(|files| {
+        if check {
+            compare(&output, &files)
+        } else {
+            write(&output, &files)
+        }
+    });
+    if let Err(error) = result {
+        eprintln!("spock-generator: {}", error);
+        process::exit(1);
+    }
+}
+
+// The name and contents of each file in src/generated.
+fn generate(input: &Path, wrappers: &Path) -> Result<Vec<(&'static str, String)>, String> {
+    let source = fs::read_to_string(input).map_err(|error| format!("cannot read {}: {}", input.display(), error))?;
+    let document = xml::parse(&source).map_err(|error| format!("{}: {}", input.display(), error))?;
+    let registry = registry::load(&document)?;
+    let selection = Selection::new(&registry, FEATURES, EXTENSIONS)?;
+    let wrapped = wrapped_commands(wrappers)?;
+
+    Ok(vec![
+        ("types.rs", emit::types(&selection, OPEN_ENUMS)?),
+        ("vk.rs", emit::commands(&selection, TABLES, &wrapped, SKIPPED_COMMANDS)?)
+    ])
+}
+
+// The commands src/vk.rs wraps by hand, which register or unregister the
+// tables for the objects they create and destroy, or keep track of the memory
+// and images they allocate and free. They are found by their definitions,
+// `pub unsafe fn vkName(`, in the order they are written.
+fn wrapped_commands(path: &Path) -> Result<Vec<String>, String> {
+    let source = fs::read_to_string(path).map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    Ok(source.lines()
+        .filter_map(|line| line.strip_prefix("pub unsafe fn vk"))
+        .filter_map(|rest| rest.find('(').map(|end| format!("vk{}", &rest[..end])))
+        .filter(|name| !SKIPPED_COMMANDS.contains(&name.as_str()))
+        .collect())
+}
+
+fn write(output: &Path, files: &[(&str, String)]) -> Result<(), String> {
+    fs::create_dir_all(output).map_err(|error| format!("cannot create {}: {}", output.display(), error))?;
+    for &(name, ref contents) in files {
+        let path = output.join(name);
+        fs::write(&path, contents).map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+    }
+    Ok(())
+}
+
+// Fails at the first line of the first file that differs.
+fn compare(output: &Path, files: &[(&str, String)]) -> Result<(), String> {
+    for &(name, ref contents) in files {
+        let path = output.join(name);
+        let existing = fs::read_to_string(&path).map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+        let mut expected = contents.lines();
+        let mut actual = existing.lines();
+        for line in 1.. {
+            match (expected.next(), actual.next()) {
+                (None, None) => break,
+                (expected, actual) if expected == actual => continue,
+                (expected, actual) => {
+                    return Err(format!("{} differs at line {}:\n  checked in: {}\n  generated:  {}", path.display(), line,
+                                       actual.unwrap_or("<end of file>"), expected.unwrap_or("<end of file>")));
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// Maps registry names onto the names spock uses: `VkImageCreateInfo` becomes
+// `ImageCreateInfo`, `VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL` becomes
+// `ImageLayout::TransferSrcOptimal`, and so on.
+
+use registry::Registry;
+
+// The Rust spelling of a C type name.
+pub fn type_name(registry: &Registry, name: &str) -> String {
+    match name {
+        "void"     => return "c_void".to_string(),
+        "char"     => return "c_char".to_string(),
+        "float"    => return "c_float".to_string(),
+        "double"   => return "c_double".to_string(),
+        "int"      => return "c_int".to_string(),
+        "VkResult" => return "ResultCode".to_string(),
+        _          => ()
+    }
+
+    if let Some(function) = name.strip_prefix("PFN_vk") {
+        return function_name(registry, function);
+    }
+
+    if name.contains("FlagBits") {
+        if let Some(flags) = registry.flags_for_bits(name) {
+            return type_name(registry, flags);
+        }
+    }
+
+    match name.strip_prefix("Vk") {
+        Some(stripped) => stripped.to_string(),
+        None           => name.to_string()
+    }
+}
+
+// `PFN_vkAllocationFunction` is `AllocationFunction`, and
+// `PFN_vkInternalFreeNotification` is `InternalFreeNotificationFunction`.
+fn function_name(registry: &Registry, name: &str) -> String {
+    let (base, tag) = split_tag(registry, name);
+    if base.ends_with("Function") {
+        format!("{}{}", base, tag)
+    } else {
+        format!("{}Function{}", base, tag)
+    }
+}
+
+// Splits a trailing vendor tag such as `KHR` or `EXT` off a name.
+fn split_tag<'a>(registry: &'a Registry, name: &'a str) -> (&'a str, &'a str) {
+    for tag in &registry.tags {
+        if name.len() > tag.len() && name.ends_with(tag.as_str()) {
+            return (&name[..name.len() - tag.len()], tag);
+        }
+    }
+    (name, "")
+}
+
+// The variant name for the enumerant `value` of the C enum `ty`.
+pub fn enumerant(registry: &Registry, ty: &str, value: &str) -> String {
+    let (ty, tag) = split_tag(registry, ty.strip_prefix("Vk").unwrap_or(ty));
+    let ty = ty.replace("FlagBits", "");
+    let type_words = split_camel(&ty);
+
+    let mut words: Vec<&str> = value.strip_prefix("VK_").unwrap_or(value).split('_').collect();
+    let mut prefix = 0;
+    while prefix < type_words.len() && prefix < words.len() - 1 && words[prefix] == type_words[prefix] {
+        prefix += 1;
+    }
+    words.drain(..prefix);
+
+    if !tag.is_empty() && words.len() > 1 && words.last() == Some(&tag) {
+        words.pop();
+    }
+    if words.len() > 1 && words.last() == Some(&"BIT") {
+        words.pop();
+    }
+
+    let last = words.len() - 1;
+    let mut variant: String = words.iter().enumerate().map(|(index, word)| {
+        if index == last && registry.tags.iter().any(|tag| tag == word) {
+            tag_case(word)
+        } else {
+            word_case(word)
+        }
+    }).collect();
+    if variant.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Some(last) = type_words.last() {
+            variant = format!("{}{}", word_case(last), variant);
+        }
+    }
+    variant
+}
+
+// `PhysicalDeviceType` is `["PHYSICAL", "DEVICE", "TYPE"]`. Digits stay with
+// the word around them, so `Image2D` is a single word; that never matches an
+// enumerant prefix, which is the only thing the words are used for.
+fn split_camel(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let chars: Vec<char> = name.chars().collect();
+    for (index, &c) in chars.iter().enumerate() {
+        let boundary = index > 0 && c.is_uppercase() &&
+            (chars[index - 1].is_lowercase() || chars.get(index + 1).is_some_and(|next| next.is_lowercase()));
+        if boundary || words.is_empty() {
+            words.push(String::new());
+        }
+        words.last_mut().unwrap().push(c.to_ascii_uppercase());
+    }
+    words
+}
+
+// A trailing vendor tag: `KHR` and most others stay as they are, but `EXT`
+// has always been spelled `Ext`.
+fn tag_case(tag: &str) -> String {
+    if tag == "EXT" { "Ext".to_string() } else { tag.to_string() }
+}
+
+fn word_case(word: &str) -> String {
+    // Format components (`R8G8B8A8`, `D24`, `E5B9G9R9`), dimensions (`2D`) and
+    // block sizes (`4x4`) read better as they are.
+    if is_components(word) || is_dimension(word) || is_block_size(word) {
+        return word.to_string();
+    }
+
+    let digits = word.len() - word.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let (number, rest) = word.split_at(digits);
+    let mut cased = number.to_string();
+    // A letter after a digit starts a new part, as in `Bc6H`.
+    let mut previous = None;
+    for c in rest.chars() {
+        let upper = previous.is_none_or(|previous: char| previous.is_ascii_digit());
+        cased.push(if upper { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() });
+        previous = Some(c);
+    }
+    cased
+}
+
+fn is_components(word: &str) -> bool {
+    let mut chars = word.chars().peekable();
+    let mut any = false;
+    while let Some(c) = chars.next() {
+        if !"RGBADSXE".contains(c) || !chars.peek().is_some_and(|next| next.is_ascii_digit()) {
+            return false;
+        }
+        while chars.peek().is_some_and(|next| next.is_ascii_digit()) {
+            chars.next();
+        }
+        any = true;
+    }
+    any
+}
+
+fn is_dimension(word: &str) -> bool {
+    word.len() > 1 && word.ends_with('D') && word[..word.len() - 1].chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_block_size(word: &str) -> bool {
+    let mut parts = word.split('x');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(width), Some(height), None) => {
+            !width.is_empty() && !height.is_empty() && width.chars().chain(height.chars()).all(|c| c.is_ascii_digit())
+        },
+        _ => false
+    }
+}
+
+// Struct members and parameters keep their C names, except where the name is
+// a Rust keyword.
+pub fn member_name(registry: &Registry, name: &str, ty: &str) -> String {
+    if name != "type" {
+        return name.to_string();
+    }
+    let ty = type_name(registry, ty);
+    let mut chars = ty.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None        => name.to_string()
+    }
+}
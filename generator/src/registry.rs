@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+
+use xml::Element;
+
+// A C type as it is spelled in a member, parameter or return value.
+#[derive(Clone, Debug)]
+pub enum CType {
+    Named(String),
+    Pointer { mutable: bool, to: Box<CType> },
+    // `constant` records a leading `const`, which matters once an array
+    // parameter decays to a pointer.
+    Array { of: Box<CType>, length: String, constant: bool }
+}
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub ty: CType,
+    // The sType value a structure must carry, from the `values` attribute.
+    pub values: Option<String>
+}
+
+#[derive(Debug)]
+pub enum TypeKind {
+    // Platform and C types, includes and defines.
+    External,
+    Base(Option<CType>),
+    Bitmask { bits: Option<String>, wide: bool },
+    // `object_type` is the VkObjectType enumerant naming the handle's type.
+    Handle { dispatchable: bool, object_type: Option<String> },
+    Enum,
+    FuncPointer { ret: CType, params: Vec<Member> },
+    Struct { members: Vec<Member>, union: bool },
+    Alias(String)
+}
+
+#[derive(Debug)]
+pub struct Type {
+    pub name: String,
+    pub kind: TypeKind,
+    pub comment: Option<String>
+}
+
+#[derive(Debug)]
+pub struct EnumValue {
+    pub name: String,
+    pub value: i64
+}
+
+#[derive(Debug)]
+pub struct Enums {
+    pub values: Vec<EnumValue>
+}
+
+#[derive(Debug)]
+pub struct Constant {
+    pub name: String,
+    pub ty: Option<String>,
+    pub value: String
+}
+
+// The texel block layout of a VkFormat, from the <formats> block.
+#[derive(Debug)]
+pub struct Format {
+    pub name: String,
+    pub block_size: u32,
+    pub block_extent: [u32; 3]
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub name: String,
+    pub ret: CType,
+    pub params: Vec<Member>
+}
+
+// Everything a feature or extension pulls into the API.
+#[derive(Debug, Default)]
+pub struct Interface {
+    pub name: String,
+    pub types: Vec<String>,
+    pub commands: Vec<String>,
+    // API constants referenced by name.
+    pub constants: Vec<String>,
+    // Constants defined in place, such as an extension's name and version.
+    pub definitions: Vec<Constant>
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    pub tags: Vec<String>,
+    pub types: Vec<Type>,
+    pub enums: HashMap<String, Enums>,
+    pub constants: Vec<Constant>,
+    pub commands: HashMap<String, Command>,
+    pub formats: Vec<Format>,
+    pub features: Vec<Interface>,
+    pub extensions: Vec<Interface>
+}
+
+impl Registry {
+    pub fn find_type(&self, name: &str) -> Option<&Type> {
+        self.types.iter().find(|ty| ty.name == name)
+    }
+
+    pub fn find_constant(&self, name: &str) -> Option<&Constant> {
+        self.constants.iter().find(|constant| constant.name == name)
+    }
+
+    // Follows type aliases to the type they name.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        match self.find_type(name) {
+            Some(&Type { kind: TypeKind::Alias(ref target), .. }) => self.resolve(target),
+            _                                                     => name
+        }
+    }
+
+    // The *Flags type whose bits are declared by the *FlagBits type `bits`.
+    pub fn flags_for_bits(&self, bits: &str) -> Option<&str> {
+        self.types.iter().find(|ty| match ty.kind {
+            TypeKind::Bitmask { bits: Some(ref name), .. } => name == bits,
+            _                                              => false
+        }).map(|ty| ty.name.as_str())
+    }
+}
+
+// Anything marked for another API, such as Vulkan SC, is left out.
+fn for_vulkan(element: &Element) -> bool {
+    match element.attribute("api") {
+        Some(apis) => apis.split(',').any(|api| api == "vulkan"),
+        None       => true
+    }
+}
+
+pub fn load(root: &Element) -> Result<Registry, String> {
+    let mut registry = Registry::default();
+
+    for tag in root.elements_named("tags").flat_map(|tags| tags.elements_named("tag")) {
+        if let Some(name) = tag.attribute("name") {
+            registry.tags.push(name.to_string());
+        }
+    }
+
+    for element in root.elements_named("types").flat_map(|types| types.elements_named("type")).filter(|element| for_vulkan(element)) {
+        let ty = load_type(element)?;
+        if registry.find_type(&ty.name).is_none() {
+            registry.types.push(ty);
+        }
+    }
+
+    for block in root.elements_named("enums") {
+        let name = block.attribute("name").unwrap_or("");
+        if block.attribute("type") == Some("constants") || name == "API Constants" {
+            for element in block.elements_named("enum").filter(|element| for_vulkan(element) && element.attribute("alias").is_none()) {
+                registry.constants.push(Constant {
+                    name: attribute(element, "name")?.to_string(),
+                    ty: element.attribute("type").map(|ty| ty.to_string()),
+                    value: attribute(element, "value")?.to_string()
+                });
+            }
+        } else {
+            let mut enums = Enums { values: Vec::new() };
+            for element in block.elements_named("enum").filter(|element| for_vulkan(element)) {
+                if let Some(value) = enum_value(element, None)? {
+                    add_value(&mut enums, attribute(element, "name")?, value);
+                }
+            }
+            registry.enums.insert(name.to_string(), enums);
+        }
+    }
+
+    let mut aliases = Vec::new();
+    for element in root.elements_named("commands").flat_map(|commands| commands.elements_named("command")).filter(|element| for_vulkan(element)) {
+        match element.attribute("alias") {
+            Some(target) => aliases.push((attribute(element, "name")?.to_string(), target.to_string())),
+            None         => {
+                let command = load_command(element)?;
+                registry.commands.insert(command.name.clone(), command);
+            }
+        }
+    }
+    for (name, target) in aliases {
+        let mut command = match registry.commands.get(&target) {
+            Some(command) => command.clone(),
+            None          => return Err(format!("{} is an alias of unknown command {}", name, target))
+        };
+        command.name = name.clone();
+        registry.commands.insert(name, command);
+    }
+
+    for feature in root.elements_named("feature").filter(|element| for_vulkan(element)) {
+        let interface = load_interface(&mut registry, feature, None)?;
+        registry.features.push(interface);
+    }
+
+    for extension in root.elements_named("extensions").flat_map(|extensions| extensions.elements_named("extension")) {
+        let supported = extension.attribute("supported").unwrap_or("vulkan");
+        if !supported.split(',').any(|api| api == "vulkan") {
+            continue;
+        }
+        let number = match extension.attribute("number") {
+            Some(number) => Some(number.parse().map_err(|_| format!("bad extension number {}", number))?),
+            None         => None
+        };
+        let interface = load_interface(&mut registry, extension, number)?;
+        registry.extensions.push(interface);
+    }
+
+    for element in root.elements_named("formats").flat_map(|formats| formats.elements_named("format")) {
+        registry.formats.push(load_format(element)?);
+    }
+
+    Ok(registry)
+}
+
+fn load_format(element: &Element) -> Result<Format, String> {
+    let name = attribute(element, "name")?;
+    let block_size = attribute(element, "blockSize")?;
+    let block_size = block_size.parse().map_err(|_| format!("{} has a bad blockSize {}", name, block_size))?;
+
+    let mut block_extent = [1, 1, 1];
+    if let Some(extent) = element.attribute("blockExtent") {
+        let dimensions: Vec<u32> = extent.split(',').map(|dimension| dimension.trim().parse()).collect::<Result<_, _>>()
+            .map_err(|_| format!("{} has a bad blockExtent {}", name, extent))?;
+        if dimensions.len() != 3 {
+            return Err(format!("{} has a bad blockExtent {}", name, extent));
+        }
+        block_extent.copy_from_slice(&dimensions);
+    }
+
+    Ok(Format { name: name.to_string(), block_size, block_extent })
+}
+
+fn attribute<'a>(element: &'a Element, name: &str) -> Result<&'a str, String> {
+    element.attribute(name).ok_or_else(|| format!("<{}> is missing its `{}` attribute", element.name, name))
+}
+
+fn element_name(element: &Element) -> Result<String, String> {
+    match element.attribute("name") {
+        Some(name) => Ok(name.to_string()),
+        None       => match element.child("name") {
+            Some(name) => Ok(name.text()),
+            None       => Err(format!("<{}> has no name", element.name))
+        }
+    }
+}
+
+fn load_type(element: &Element) -> Result<Type, String> {
+    let name = element_name(element)?;
+    if let Some(target) = element.attribute("alias") {
+        return Ok(Type { name, kind: TypeKind::Alias(target.to_string()), comment: None });
+    }
+
+    let kind = match element.attribute("category") {
+        Some("basetype") => {
+            let text = element.text();
+            let declaration = text.trim().trim_start_matches("typedef").trim_end_matches(';');
+            match (element.child("type"), parse_declaration(declaration)) {
+                (Some(_), Ok((ty, _))) => TypeKind::Base(Some(ty)),
+                _                      => TypeKind::Base(None)
+            }
+        },
+        Some("bitmask") => {
+            let underlying = element.child("type").map(|ty| ty.text()).unwrap_or_default();
+            TypeKind::Bitmask {
+                bits: element.attribute("requires").or(element.attribute("bitvalues")).map(|bits| bits.to_string()),
+                wide: underlying == "VkFlags64"
+            }
+        },
+        Some("handle") => {
+            let definition = element.child("type").map(|ty| ty.text()).unwrap_or_default();
+            TypeKind::Handle {
+                dispatchable: definition == "VK_DEFINE_HANDLE",
+                object_type: element.attribute("objtypeenum").map(|object_type| object_type.to_string())
+            }
+        },
+        Some("enum") => TypeKind::Enum,
+        Some("funcpointer") => load_funcpointer(element)?,
+        Some(category) if category == "struct" || category == "union" => {
+            let mut members = Vec::new();
+            for member in element.elements_named("member").filter(|member| for_vulkan(member)) {
+                let (ty, member_name) = parse_declaration(&member.text()).map_err(|error| format!("{}: {}", name, error))?;
+                members.push(Member { name: member_name, ty, values: member.attribute("values").map(|values| values.to_string()) });
+            }
+            TypeKind::Struct { members, union: category == "union" }
+        },
+        _ => TypeKind::External
+    };
+
+    Ok(Type { name, kind, comment: element.attribute("comment").map(|comment| comment.to_string()) })
+}
+
+fn load_funcpointer(element: &Element) -> Result<TypeKind, String> {
+    // Newer registries spell function pointers out like commands.
+    if let Some(proto) = element.child("proto") {
+        let (ret, _) = parse_declaration(&proto.text())?;
+        let mut params = Vec::new();
+        for param in element.elements_named("param") {
+            let (ty, name) = parse_declaration(&param.text())?;
+            params.push(Member { name, ty, values: None });
+        }
+        return Ok(TypeKind::FuncPointer { ret, params });
+    }
+
+    // Older ones use the C typedef: `typedef R (VKAPI_PTR *PFN_x)(P p, ...);`
+    let text = element.text();
+    let malformed = || format!("cannot read function pointer `{}`", text);
+    let start = text.find("(VKAPI_PTR").ok_or_else(&malformed)?;
+    let (ret, _) = parse_declaration(&format!("{} _", text[..start].trim().trim_start_matches("typedef")))?;
+    let rest = &text[start..];
+    let open = rest.find(")(").ok_or_else(&malformed)? + 2;
+    let close = rest.rfind(')').ok_or_else(&malformed)?;
+
+    let mut params = Vec::new();
+    for param in rest[open..close].split(',').map(|param| param.trim()).filter(|param| !param.is_empty() && *param != "void") {
+        let (ty, name) = parse_declaration(param)?;
+        params.push(Member { name, ty, values: None });
+    }
+    Ok(TypeKind::FuncPointer { ret, params })
+}
+
+fn load_command(element: &Element) -> Result<Command, String> {
+    let proto = element.child("proto").ok_or_else(|| "<command> has no <proto>".to_string())?;
+    let (ret, name) = parse_declaration(&proto.text())?;
+    let mut params = Vec::new();
+    for param in element.elements_named("param").filter(|param| for_vulkan(param)) {
+        let (ty, param_name) = parse_declaration(&param.text()).map_err(|error| format!("{}: {}", name, error))?;
+        params.push(Member { name: param_name, ty, values: None });
+    }
+    Ok(Command { name, ret, params })
+}
+
+fn load_interface(registry: &mut Registry, element: &Element, number: Option<i64>) -> Result<Interface, String> {
+    let mut interface = Interface { name: attribute(element, "name")?.to_string(), ..Interface::default() };
+
+    for require in element.elements_named("require").filter(|require| for_vulkan(require)) {
+        for item in require.elements() {
+            match item.name.as_str() {
+                "type"    => interface.types.push(attribute(item, "name")?.to_string()),
+                "command" => interface.commands.push(attribute(item, "name")?.to_string()),
+                "enum"    => {
+                    let name = attribute(item, "name")?;
+                    if let Some(extends) = item.attribute("extends") {
+                        if let Some(value) = enum_value(item, number)? {
+                            match registry.enums.get_mut(extends) {
+                                Some(enums) => add_value(enums, name, value),
+                                None        => return Err(format!("{} extends unknown enum {}", name, extends))
+                            }
+                        }
+                    } else if let Some(value) = item.attribute("value") {
+                        interface.definitions.push(Constant { name: name.to_string(), ty: item.attribute("type").map(|ty| ty.to_string()), value: value.to_string() });
+                    } else if item.attribute("alias").is_none() {
+                        interface.constants.push(name.to_string());
+                    }
+                },
+                _ => ()
+            }
+        }
+    }
+
+    Ok(interface)
+}
+
+// The numeric value of an <enum>, or None for an alias.
+fn enum_value(element: &Element, extension: Option<i64>) -> Result<Option<i64>, String> {
+    let name = attribute(element, "name")?;
+    if element.attribute("alias").is_some() {
+        return Ok(None);
+    }
+    if let Some(value) = element.attribute("value") {
+        return parse_integer(value).map(Some).ok_or_else(|| format!("{} has a malformed value {}", name, value));
+    }
+    if let Some(bitpos) = element.attribute("bitpos") {
+        let bitpos: u32 = bitpos.parse().map_err(|_| format!("{} has a malformed bitpos", name))?;
+        return Ok(Some(1i64 << bitpos));
+    }
+    if let Some(offset) = element.attribute("offset") {
+        let offset: i64 = offset.parse().map_err(|_| format!("{} has a malformed offset", name))?;
+        let number = match element.attribute("extnumber") {
+            Some(number) => number.parse().map_err(|_| format!("{} has a malformed extnumber", name))?,
+            None         => extension.ok_or_else(|| format!("{} has an offset but no extension number", name))?
+        };
+        let value = 1_000_000_000 + (number - 1) * 1000 + offset;
+        return Ok(Some(if element.attribute("dir") == Some("-") { -value } else { value }));
+    }
+    Err(format!("{} has no value", name))
+}
+
+fn parse_integer(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None         => (false, text)
+    };
+    let value = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None      => digits.parse().ok()?
+    };
+    Some(if negative { -value } else { value })
+}
+
+// Several extensions may add the same value; the first one wins.
+fn add_value(enums: &mut Enums, name: &str, value: i64) {
+    if !enums.values.iter().any(|existing| existing.name == name) {
+        enums.values.push(EnumValue { name: name.to_string(), value });
+    }
+}
+
+// Splits a C declaration such as `const char* const* ppNames` or
+// `float matrix[3][4]` into its type and declared name.
+pub fn parse_declaration(text: &str) -> Result<(CType, String), String> {
+    let tokens = tokenize(text);
+    let malformed = || format!("cannot read declaration `{}`", text.trim());
+    let mut position = 0;
+    let mut constant = false;
+
+    while position < tokens.len() && (tokens[position] == "const" || tokens[position] == "struct") {
+        constant |= tokens[position] == "const";
+        position += 1;
+    }
+    let base = tokens.get(position).ok_or_else(&malformed)?.clone();
+    position += 1;
+    while position < tokens.len() && tokens[position] == "const" {
+        constant = true;
+        position += 1;
+    }
+
+    let mut ty = CType::Named(base);
+    let mut pointee_constant = constant;
+    while position < tokens.len() && tokens[position] == "*" {
+        position += 1;
+        ty = CType::Pointer { mutable: !pointee_constant, to: Box::new(ty) };
+        pointee_constant = position < tokens.len() && tokens[position] == "const";
+        if pointee_constant {
+            position += 1;
+        }
+    }
+
+    let name = tokens.get(position).ok_or_else(&malformed)?.clone();
+    position += 1;
+
+    let mut lengths = Vec::new();
+    while position < tokens.len() && tokens[position] == "[" {
+        let length = tokens.get(position + 1).ok_or_else(&malformed)?.clone();
+        if tokens.get(position + 2).map(|token| token.as_str()) != Some("]") {
+            return Err(malformed());
+        }
+        lengths.push(length);
+        position += 3;
+    }
+    for length in lengths.into_iter().rev() {
+        ty = CType::Array { of: Box::new(ty), length, constant };
+    }
+
+    if position < tokens.len() {
+        if tokens[position] == ":" {
+            return Err(format!("bit field `{}` is not supported", text.trim()));
+        }
+        return Err(malformed());
+    }
+    Ok((ty, name))
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_alphanumeric() || c == '_' {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        } else {
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+            chars.next();
+        }
+    }
+    tokens
+}
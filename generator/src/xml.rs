@@ -0,0 +1,222 @@
+// A small non-validating XML reader. It understands exactly what the Vulkan
+// registry uses: elements, attributes, text, comments, processing
+// instructions, a doctype, CDATA sections and character references.
+
+#[derive(Debug)]
+pub enum Node {
+    Element(Element),
+    Text(String)
+}
+
+#[derive(Debug)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|&(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match *node {
+            Node::Element(ref element) => Some(element),
+            Node::Text(_)              => None
+        })
+    }
+
+    pub fn elements_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.elements().filter(move |element| element.name == name)
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|element| element.name == name)
+    }
+
+    // The concatenated text of this element and its descendants, leaving out
+    // any <comment> elements.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        self.append_text(&mut text);
+        text
+    }
+
+    fn append_text(&self, text: &mut String) {
+        for node in &self.children {
+            match *node {
+                Node::Text(ref value)                                    => text.push_str(value),
+                Node::Element(ref element) if element.name != "comment" => element.append_text(text),
+                Node::Element(_)                                         => ()
+            }
+        }
+    }
+}
+
+pub fn parse(source: &str) -> Result<Element, String> {
+    let mut parser = Parser { source, position: 0 };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if parser.position < source.len() {
+        return Err(parser.error("unexpected content after the root element"));
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.source[..self.position].matches('\n').count() + 1;
+        format!("line {}: {}", line, message)
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", token)))
+        }
+    }
+
+    // Advances past `terminator`, returning everything before it.
+    fn until(&mut self, terminator: &str) -> Result<&'a str, String> {
+        match self.rest().find(terminator) {
+            Some(end) => {
+                let skipped = &self.rest()[..end];
+                self.position += end + terminator.len();
+                Ok(skipped)
+            },
+            None => Err(self.error(&format!("unterminated construct, expected `{}`", terminator)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    // Whitespace, comments, processing instructions and doctypes.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<!--") {
+                self.until("-->")?;
+            } else if self.rest().starts_with("<?") {
+                self.until("?>")?;
+            } else if self.rest().starts_with("<!DOCTYPE") {
+                self.until(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let length = rest.find(|c: char| c.is_whitespace() || c == '=' || c == '/' || c == '>').unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.position += length;
+        Ok(rest[..length].to_string())
+    }
+
+    fn element(&mut self) -> Result<Element, String> {
+        self.expect("<")?;
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(Element { name, attributes, children: Vec::new() });
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+
+            let key = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = if self.rest().starts_with('"') { "\"" } else { "'" };
+            self.expect(quote)?;
+            let value = self.until(quote)?;
+            attributes.push((key, self.unescape(value)?));
+        }
+
+        let mut children = Vec::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.position += 2;
+                let closing = self.name()?;
+                if closing != name {
+                    return Err(self.error(&format!("`</{}>` does not close `<{}>`", closing, name)));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(Element { name, attributes, children });
+            } else if rest.starts_with("<!--") {
+                self.until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                let text = self.until("]]>")?;
+                children.push(Node::Text(text.to_string()));
+            } else if rest.starts_with("<?") {
+                self.until("?>")?;
+            } else if rest.starts_with('<') {
+                children.push(Node::Element(self.element()?));
+            } else if rest.is_empty() {
+                return Err(self.error(&format!("`<{}>` is never closed", name)));
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                self.position += length;
+                children.push(Node::Text(self.unescape(&rest[..length])?));
+            }
+        }
+    }
+
+    fn unescape(&self, text: &str) -> Result<String, String> {
+        let mut unescaped = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('&') {
+            unescaped.push_str(&rest[..start]);
+            let end = match rest[start..].find(';') {
+                Some(end) => start + end,
+                None      => return Err(self.error("unterminated character reference"))
+            };
+            let reference = &rest[start + 1..end];
+            let character = match reference {
+                "lt"   => Some('<'),
+                "gt"   => Some('>'),
+                "amp"  => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ if reference.starts_with("#x") => u32::from_str_radix(&reference[2..], 16).ok().and_then(std::char::from_u32),
+                _ if reference.starts_with('#')  => reference[1..].parse().ok().and_then(std::char::from_u32),
+                _ => None
+            };
+            match character {
+                Some(character) => unescaped.push(character),
+                None            => return Err(self.error(&format!("unknown character reference `&{};`", reference)))
+            }
+            rest = &rest[end + 1..];
+        }
+        unescaped.push_str(rest);
+        Ok(unescaped)
+    }
+}
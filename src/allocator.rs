@@ -0,0 +1,388 @@
+// Sub-allocates device memory. Rather than making one allocation per buffer or
+// image, which soon runs into maxMemoryAllocationCount, the Allocator takes
+// large blocks from each memory type and hands out ranges of them. Requests
+// larger than a block get a block of their own.
+//
+// Each block keeps its allocations sorted by offset, and the gaps between
+// them are its free list, so freeing a range merges it with the gaps on
+// either side. New ranges go in the first gap that fits.
+//
+// Memory can only be mapped once at a time, so allocations sharing a block
+// cannot each map it. Instead a block is mapped whole the first time one of
+// its allocations asks for its bytes, and stays mapped until it is freed;
+// each allocation is handed the part of the mapping that is its own.
+
+use std::error;
+use std::fmt;
+use std::slice;
+use std::sync::Mutex;
+
+use memory::MappedMemory;
+use types::*;
+use SpockDevice;
+use SpockPhysicalDevice;
+
+pub const DEFAULT_BLOCK_SIZE: DeviceSize = 64 * 1024 * 1024;
+
+#[derive(Copy, Clone)]
+pub enum AllocatorError {
+    // The requirements' memoryTypeBits do not allow the memory type asked
+    // for.
+    MemoryTypeNotAllowed(u32),
+    // The requirements ask for 0 bytes, which Vulkan cannot allocate.
+    ZeroSize,
+    Vulkan(Error)
+}
+
+impl fmt::Display for AllocatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AllocatorError::MemoryTypeNotAllowed(index) => write!(f, "Memory type {} is not allowed by the requirements", index),
+            AllocatorError::ZeroSize                    => write!(f, "Cannot allocate 0 bytes"),
+            AllocatorError::Vulkan(error)               => write!(f, "{}", error)
+        }
+    }
+}
+
+impl fmt::Debug for AllocatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for AllocatorError {}
+
+impl From<Error> for AllocatorError {
+    fn from(error: Error) -> AllocatorError {
+        AllocatorError::Vulkan(error)
+    }
+}
+
+struct Range {
+    offset: DeviceSize,
+    size: DeviceSize,
+    tiling: ImageTiling
+}
+
+struct Block<'d> {
+    memory: DeviceMemory,
+    memory_type_index: u32,
+    size: DeviceSize,
+    dedicated: bool,
+    ranges: Vec<Range>,
+    mapped: Option<MappedMemory<'d>>
+}
+
+impl<'d> Block<'d> {
+    // Finds the first offset at which `size` bytes fit, aligned to `alignment`
+    // and kept `granularity` apart from neighbours of the other tiling. There
+    // is never room for an empty range.
+    fn find(&self, size: DeviceSize, alignment: DeviceSize, tiling: ImageTiling, granularity: DeviceSize) -> Option<DeviceSize> {
+        if size == 0 {
+            return None;
+        }
+        for index in 0..self.ranges.len() + 1 {
+            let previous = if index > 0 { self.ranges.get(index - 1) } else { None };
+            let next = self.ranges.get(index);
+            let gap_start = previous.map_or(0, |range| range.offset + range.size);
+            let gap_end = next.map_or(self.size, |range| range.offset);
+
+            let mut offset = align_up(gap_start, alignment);
+            if let Some(previous) = previous {
+                if previous.tiling != tiling && same_page(previous.offset + previous.size - 1, offset, granularity) {
+                    offset = align_up(offset, granularity);
+                }
+            }
+
+            let end = match offset.checked_add(size) {
+                Some(end) if end <= gap_end => end,
+                _                           => continue
+            };
+            if let Some(next) = next {
+                if next.tiling != tiling && same_page(end - 1, next.offset, granularity) {
+                    continue;
+                }
+            }
+            return Some(offset);
+        }
+        None
+    }
+
+    fn insert(&mut self, offset: DeviceSize, size: DeviceSize, tiling: ImageTiling) {
+        let index = self.ranges.iter().position(|range| range.offset > offset).unwrap_or(self.ranges.len());
+        self.ranges.insert(index, Range { offset: offset, size: size, tiling: tiling });
+    }
+
+    fn remove(&mut self, offset: DeviceSize) {
+        if let Some(index) = self.ranges.iter().position(|range| range.offset == offset) {
+            self.ranges.remove(index);
+        }
+    }
+}
+
+// `alignment` is always a power of two.
+fn align_up(value: DeviceSize, alignment: DeviceSize) -> DeviceSize {
+    (value + alignment - 1) & !(alignment - 1)
+}
+
+fn same_page(a: DeviceSize, b: DeviceSize, page_size: DeviceSize) -> bool {
+    a & !(page_size - 1) == b & !(page_size - 1)
+}
+
+pub struct Allocator<'d> {
+    device: &'d Device,
+    block_size: DeviceSize,
+    granularity: DeviceSize,
+    blocks: Mutex<Vec<Block<'d>>>
+}
+
+impl<'d> Allocator<'d> {
+    pub fn new(device: &'d Device) -> Allocator<'d> {
+        Allocator::with_block_size(device, DEFAULT_BLOCK_SIZE)
+    }
+
+    pub fn with_block_size(device: &'d Device, block_size: DeviceSize) -> Allocator<'d> {
+        let granularity = device.get_physical_device().get_properties().limits.bufferImageGranularity;
+        Allocator {
+            device: device,
+            block_size: block_size,
+            granularity: if granularity == 0 { 1 } else { granularity },
+            blocks: Mutex::new(Vec::new())
+        }
+    }
+
+    pub fn device(&self) -> &'d Device {
+        self.device
+    }
+
+    // Allocates a range meeting `requirements` from the memory type
+    // `memory_type_index`. Buffers, and images with linear tiling, are
+    // ImageTiling::Linear.
+    pub fn allocate(&self, requirements: MemoryRequirements, memory_type_index: u32, tiling: ImageTiling) -> Result<Allocation<'_, 'd>, AllocatorError> {
+        if 1u32.checked_shl(memory_type_index).is_none_or(|bit| requirements.memoryTypeBits & bit == 0) {
+            return Err(AllocatorError::MemoryTypeNotAllowed(memory_type_index));
+        }
+        if requirements.size == 0 {
+            return Err(AllocatorError::ZeroSize);
+        }
+
+        let size = requirements.size;
+        let alignment = if requirements.alignment == 0 { 1 } else { requirements.alignment };
+        let mut blocks = self.blocks.lock().unwrap();
+
+        if size <= self.block_size {
+            for block in blocks.iter_mut().filter(|block| block.memory_type_index == memory_type_index && !block.dedicated) {
+                if let Some(offset) = block.find(size, alignment, tiling, self.granularity) {
+                    block.insert(offset, size, tiling);
+                    return Ok(Allocation::new(self, block.memory, memory_type_index, offset, size));
+                }
+            }
+        }
+
+        let dedicated = size > self.block_size;
+        let block_size = if dedicated { size } else { self.block_size };
+        let allocate_info = MemoryAllocateInfo {
+            allocationSize: block_size,
+            memoryTypeIndex: memory_type_index,
+            ..Default::default()
+        };
+        let memory = self.device.allocate_memory(allocate_info, None)?;
+
+        let mut block = Block { memory: memory, memory_type_index: memory_type_index, size: block_size, dedicated: dedicated, ranges: Vec::new(), mapped: None };
+        block.insert(0, size, tiling);
+        blocks.push(block);
+        Ok(Allocation::new(self, memory, memory_type_index, 0, size))
+    }
+
+    // Allocates memory for `buffer` and binds it.
+    pub fn allocate_buffer(&self, buffer: Buffer, memory_type_index: u32) -> Result<Allocation<'_, 'd>, AllocatorError> {
+        let requirements = self.device.get_buffer_memory_requirements(buffer);
+        let allocation = self.allocate(requirements, memory_type_index, ImageTiling::Linear)?;
+        self.device.bind_buffer_memory(buffer, allocation.memory, allocation.offset)?;
+        Ok(allocation)
+    }
+
+    // Allocates memory for `image`, which was created with `tiling`, and
+    // binds it.
+    pub fn allocate_image(&self, image: Image, tiling: ImageTiling, memory_type_index: u32) -> Result<Allocation<'_, 'd>, AllocatorError> {
+        let requirements = self.device.get_image_memory_requirements(image);
+        let allocation = self.allocate(requirements, memory_type_index, tiling)?;
+        self.device.bind_image_memory(image, allocation.memory, allocation.offset)?;
+        Ok(allocation)
+    }
+
+    // Dedicated blocks are given back as soon as they are empty. One empty
+    // block of each memory type is kept for reuse, so that a range being
+    // freed and allocated again does not free and allocate a whole block;
+    // any others are given back too.
+    fn free(&self, memory: DeviceMemory, offset: DeviceSize) {
+        let mut blocks = self.blocks.lock().unwrap();
+        if let Some(index) = blocks.iter().position(|block| block.memory == memory) {
+            blocks[index].remove(offset);
+            if !blocks[index].ranges.is_empty() {
+                return;
+            }
+            let memory_type_index = blocks[index].memory_type_index;
+            let spare = blocks.iter().enumerate().any(|(other, block)| {
+                other != index && block.memory_type_index == memory_type_index && !block.dedicated && block.ranges.is_empty()
+            });
+            if blocks[index].dedicated || spare {
+                let mut block = blocks.remove(index);
+                block.mapped = None;
+                self.device.free_memory(block.memory, None);
+            }
+        }
+    }
+
+    // Maps the block `memory` belongs to if it is not mapped already, and
+    // returns the start of its mapping.
+    fn map_block(&self, memory: DeviceMemory) -> Result<*mut u8, Error> {
+        let mut blocks = self.blocks.lock().unwrap();
+        let block = blocks.iter_mut().find(|block| block.memory == memory).expect("Allocation outlived its block");
+        if block.mapped.is_none() {
+            block.mapped = Some(self.device.map_memory(memory, 0, VK_WHOLE_SIZE)?);
+        }
+        Ok(block.mapped.as_ref().unwrap().pointer())
+    }
+
+    // Runs `f` on the mapping of the block `memory` belongs to, if it has
+    // one. A block that was never mapped has no host writes to flush and no
+    // host reads to invalidate.
+    fn with_mapping<F: FnOnce(&MappedMemory<'d>) -> Result<(), Error>>(&self, memory: DeviceMemory, f: F) -> Result<(), Error> {
+        let blocks = self.blocks.lock().unwrap();
+        match blocks.iter().find(|block| block.memory == memory).and_then(|block| block.mapped.as_ref()) {
+            Some(mapped) => f(mapped),
+            None         => Ok(())
+        }
+    }
+}
+
+impl<'d> Drop for Allocator<'d> {
+    fn drop(&mut self) {
+        for mut block in self.blocks.get_mut().unwrap().drain(..) {
+            block.mapped = None;
+            self.device.free_memory(block.memory, None);
+        }
+    }
+}
+
+// A range of device memory handed out by an Allocator, which gets it back
+// when the Allocation is dropped. The buffer or image bound to it must be
+// destroyed first.
+pub struct Allocation<'a, 'd: 'a> {
+    allocator: &'a Allocator<'d>,
+    memory: DeviceMemory,
+    memory_type_index: u32,
+    offset: DeviceSize,
+    size: DeviceSize
+}
+
+impl<'a, 'd> Allocation<'a, 'd> {
+    fn new(allocator: &'a Allocator<'d>, memory: DeviceMemory, memory_type_index: u32, offset: DeviceSize, size: DeviceSize) -> Allocation<'a, 'd> {
+        Allocation { allocator: allocator, memory: memory, memory_type_index: memory_type_index, offset: offset, size: size }
+    }
+
+    pub fn memory(&self) -> DeviceMemory {
+        self.memory
+    }
+
+    pub fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+
+    pub fn offset(&self) -> DeviceSize {
+        self.offset
+    }
+
+    pub fn size(&self) -> DeviceSize {
+        self.size
+    }
+
+    // The allocation's bytes, through its block's persistent mapping, which
+    // is made the first time any allocation in the block asks. Fails with
+    // MemoryMapFailed if the memory type is not HostVisible, or if the block
+    // was mapped some other way.
+    pub fn mapped(&mut self) -> Result<&mut [u8], Error> {
+        let pointer = self.allocator.map_block(self.memory)?;
+        // Allocations in a block never overlap, and the block stays mapped
+        // until every allocation in it has been dropped, so the slice is this
+        // allocation's alone for as long as it is borrowed.
+        Ok(unsafe { slice::from_raw_parts_mut(pointer.add(self.offset as usize), self.size as usize) })
+    }
+
+    // Makes host writes to the allocation visible to the device. Only needed
+    // for memory types that are not HostCoherent.
+    pub fn flush(&self) -> Result<(), Error> {
+        self.allocator.with_mapping(self.memory, |mapped| mapped.flush_range(self.offset as usize, self.size as usize))
+    }
+
+    // Makes device writes to the allocation visible to the host. Only needed
+    // for memory types that are not HostCoherent.
+    pub fn invalidate(&self) -> Result<(), Error> {
+        self.allocator.with_mapping(self.memory, |mapped| mapped.invalidate_range(self.offset as usize, self.size as usize))
+    }
+}
+
+impl<'a, 'd> Drop for Allocation<'a, 'd> {
+    fn drop(&mut self) {
+        self.allocator.free(self.memory, self.offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Block;
+    use types::*;
+
+    fn block(size: DeviceSize) -> Block<'static> {
+        Block { memory: DeviceMemory::null(), memory_type_index: 0, size: size, dedicated: false, ranges: Vec::new(), mapped: None }
+    }
+
+    #[test]
+    fn find_takes_the_first_aligned_gap() {
+        let mut block = block(256);
+        assert_eq!(block.find(10, 1, ImageTiling::Linear, 1), Some(0));
+        block.insert(0, 10, ImageTiling::Linear);
+        assert_eq!(block.find(16, 32, ImageTiling::Linear, 1), Some(32));
+        block.insert(32, 16, ImageTiling::Linear);
+        assert_eq!(block.find(8, 1, ImageTiling::Linear, 1), Some(10));
+        assert_eq!(block.find(256, 1, ImageTiling::Linear, 1), None);
+    }
+
+    #[test]
+    fn find_rejects_empty_and_oversized_ranges() {
+        let block = block(256);
+        assert_eq!(block.find(0, 1, ImageTiling::Linear, 1), None);
+        assert_eq!(block.find(257, 1, ImageTiling::Linear, 1), None);
+        assert_eq!(block.find(DeviceSize::MAX, 1, ImageTiling::Linear, 1), None);
+    }
+
+    #[test]
+    fn find_keeps_other_tilings_a_page_apart() {
+        let mut block = block(256);
+        block.insert(0, 100, ImageTiling::Linear);
+        assert_eq!(block.find(16, 1, ImageTiling::Linear, 64), Some(100));
+        assert_eq!(block.find(16, 1, ImageTiling::Optimal, 64), Some(128));
+
+        block.insert(160, 64, ImageTiling::Optimal);
+        assert_eq!(block.find(16, 1, ImageTiling::Linear, 64), Some(100));
+        assert_eq!(block.find(50, 1, ImageTiling::Linear, 64), None);
+    }
+
+    #[test]
+    fn freed_ranges_merge_with_their_neighbours() {
+        let mut block = block(256);
+        block.insert(0, 64, ImageTiling::Linear);
+        block.insert(64, 64, ImageTiling::Linear);
+        block.insert(128, 64, ImageTiling::Linear);
+        assert_eq!(block.find(128, 1, ImageTiling::Linear, 1), None);
+
+        block.remove(64);
+        block.remove(128);
+        assert_eq!(block.find(192, 1, ImageTiling::Linear, 1), Some(64));
+        assert_eq!(block.find(256, 1, ImageTiling::Linear, 1), None);
+
+        block.remove(0);
+        assert_eq!(block.find(256, 1, ImageTiling::Linear, 1), Some(0));
+    }
+}
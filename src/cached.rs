@@ -0,0 +1,141 @@
+// Handles bundled with the functions of the instance or device they belong
+// to. A raw PhysicalDevice, Queue or CommandBuffer finds those functions in
+// vk's registry on every call, taking its lock and hashing its dispatch key
+// each time; these find them once, when they are made, and implement the
+// Spock* traits through what they found. That matters most for command
+// buffers, where it takes the lookup out of every recorded command.
+//
+// The wrappers deref to the raw handle, and own nothing: dropping one leaves
+// its handle alone.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use types::*;
+use vk::{device_state, instance_fns, CommandBufferDispatch, DeviceState, InstanceFns, PhysicalDeviceDispatch, QueueDispatch};
+
+#[derive(Clone)]
+pub struct CachedPhysicalDevice {
+    handle: PhysicalDevice,
+    fns: Arc<InstanceFns>
+}
+
+impl CachedPhysicalDevice {
+    // None if `handle` does not belong to an instance created through spock.
+    pub fn new(handle: PhysicalDevice) -> Option<CachedPhysicalDevice> {
+        unsafe { instance_fns(handle.as_raw()) }.map(|fns| CachedPhysicalDevice { handle: handle, fns: fns })
+    }
+
+    // `fns` must be the functions loaded for the instance `handle` belongs
+    // to.
+    pub unsafe fn with_fns(handle: PhysicalDevice, fns: Arc<InstanceFns>) -> CachedPhysicalDevice {
+        CachedPhysicalDevice { handle: handle, fns: fns }
+    }
+
+    pub fn handle(&self) -> PhysicalDevice {
+        self.handle
+    }
+}
+
+impl PhysicalDeviceDispatch for CachedPhysicalDevice {
+    type Fns<'a> = &'a InstanceFns;
+
+    fn physical_device(&self) -> &PhysicalDevice {
+        &self.handle
+    }
+
+    fn fns(&self) -> &InstanceFns {
+        &self.fns
+    }
+}
+
+impl Deref for CachedPhysicalDevice {
+    type Target = PhysicalDevice;
+
+    fn deref(&self) -> &PhysicalDevice {
+        &self.handle
+    }
+}
+
+pub struct CachedQueue {
+    handle: Queue,
+    state: Arc<DeviceState>
+}
+
+impl CachedQueue {
+    // None if `handle` does not belong to a device created through spock.
+    pub fn new(handle: Queue) -> Option<CachedQueue> {
+        unsafe { device_state(handle.as_raw()) }.map(|state| CachedQueue { handle: handle, state: state })
+    }
+
+    // `state` must be what is kept for the device `handle` belongs to.
+    pub unsafe fn with_state(handle: Queue, state: Arc<DeviceState>) -> CachedQueue {
+        CachedQueue { handle: handle, state: state }
+    }
+
+    pub fn handle(&self) -> Queue {
+        self.handle
+    }
+}
+
+impl QueueDispatch for CachedQueue {
+    type State<'a> = &'a DeviceState;
+
+    fn queue(&self) -> &Queue {
+        &self.handle
+    }
+
+    fn state(&self) -> &DeviceState {
+        &self.state
+    }
+}
+
+impl Deref for CachedQueue {
+    type Target = Queue;
+
+    fn deref(&self) -> &Queue {
+        &self.handle
+    }
+}
+
+pub struct CachedCommandBuffer {
+    handle: CommandBuffer,
+    state: Arc<DeviceState>
+}
+
+impl CachedCommandBuffer {
+    // None if `handle` was not allocated from a device created through spock.
+    pub fn new(handle: CommandBuffer) -> Option<CachedCommandBuffer> {
+        unsafe { device_state(handle.as_raw()) }.map(|state| CachedCommandBuffer { handle: handle, state: state })
+    }
+
+    // `state` must be what is kept for the device `handle` was allocated
+    // from.
+    pub unsafe fn with_state(handle: CommandBuffer, state: Arc<DeviceState>) -> CachedCommandBuffer {
+        CachedCommandBuffer { handle: handle, state: state }
+    }
+
+    pub fn handle(&self) -> CommandBuffer {
+        self.handle
+    }
+}
+
+impl CommandBufferDispatch for CachedCommandBuffer {
+    type State<'a> = &'a DeviceState;
+
+    fn command_buffer(&self) -> &CommandBuffer {
+        &self.handle
+    }
+
+    fn state(&self) -> &DeviceState {
+        &self.state
+    }
+}
+
+impl Deref for CachedCommandBuffer {
+    type Target = CommandBuffer;
+
+    fn deref(&self) -> &CommandBuffer {
+        &self.handle
+    }
+}
@@ -0,0 +1,379 @@
+// Messages from the validation layers, the loader and the driver, passed to
+// a Rust closure. VK_EXT_debug_utils is used if the instance enabled it, and
+// the older VK_EXT_debug_report otherwise; either way the closure gets a
+// DebugMessage, though one from debug_report carries no labels and at most a
+// single unnamed object.
+//
+// Messengers are created through OwnedInstance::create_debug_messenger.
+// Naming objects and labelling command buffers, so that messages say which
+// object or which pass they are about, are on SpockDevice and
+// SpockCommandBuffer.
+
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use libc::{c_char, c_void};
+
+use owned::OwnedInstance;
+use types::*;
+use vk::*;
+use SpockCommandBuffer;
+
+pub const UTILS_EXTENSIONS: &[&str] = &[VK_EXT_DEBUG_UTILS_EXTENSION_NAME];
+pub const REPORT_EXTENSIONS: &[&str] = &[VK_EXT_DEBUG_REPORT_EXTENSION_NAME];
+
+pub type DebugCallback = Box<dyn Fn(&DebugMessage) + Send + Sync>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Verbose,
+    Info,
+    Warning,
+    Error
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugLabel {
+    pub name: String,
+    pub color: [f32; 4]
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugObject {
+    pub object_type: ObjectType,
+    pub handle: u64,
+    pub name: Option<String>
+}
+
+#[derive(Clone)]
+pub struct DebugMessage {
+    pub severity: Severity,
+    pub types: DebugUtilsMessageTypeFlagsEXT,
+    // The validation layers' VUID, or debug_report's layer prefix.
+    pub id_name: Option<String>,
+    pub id_number: i32,
+    pub message: String,
+    pub queue_labels: Vec<DebugLabel>,
+    pub command_buffer_labels: Vec<DebugLabel>,
+    pub objects: Vec<DebugObject>
+}
+
+impl fmt::Display for DebugMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = if self.types.contains(DebugUtilsMessageTypeFlagsEXT::Validation) {
+            "validation"
+        } else if self.types.contains(DebugUtilsMessageTypeFlagsEXT::Performance) {
+            "performance"
+        } else {
+            "general"
+        };
+        write!(f, "[{:?} {}]", self.severity, kind)?;
+        if let Some(ref id_name) = self.id_name {
+            write!(f, " {}", id_name)?;
+        }
+        write!(f, ": {}", self.message)?;
+
+        for object in &self.objects {
+            write!(f, "\n    object {:?} 0x{:x}", object.object_type, object.handle)?;
+            if let Some(ref name) = object.name {
+                write!(f, " \"{}\"", name)?;
+            }
+        }
+        for label in &self.queue_labels {
+            write!(f, "\n    queue label \"{}\"", label.name)?;
+        }
+        for label in &self.command_buffer_labels {
+            write!(f, "\n    command buffer label \"{}\"", label.name)?;
+        }
+        Ok(())
+    }
+}
+
+// The default sink.
+pub fn print_to_stderr(message: &DebugMessage) {
+    eprintln!("{}", message);
+}
+
+enum Messenger {
+    Utils(DebugUtilsMessengerEXT),
+    Report(DebugReportCallbackEXT)
+}
+
+// Unregisters the callback when dropped.
+pub struct DebugMessenger<'i> {
+    messenger: Messenger,
+    instance: &'i OwnedInstance,
+    allocator: Option<AllocationCallbacks>,
+    // Boxed again so that the pointer handed to Vulkan as user data is thin,
+    // and stays put however the messenger itself moves.
+    _callback: Box<DebugCallback>
+}
+
+impl<'i> DebugMessenger<'i> {
+    // Messages less severe than `min_severity` are not passed on.
+    pub fn new(instance: &'i OwnedInstance, min_severity: Severity, callback: DebugCallback, allocator_opt: Option<AllocationCallbacks>) -> Result<DebugMessenger<'i>, Error> {
+        let table = instance.fns();
+        let callback = Box::new(callback);
+        let user_data = &*callback as *const DebugCallback as *mut c_void;
+        let allocator = pointer_of_option!(allocator_opt);
+
+        let messenger = if let (Some(create), Some(_)) = (table.vkCreateDebugUtilsMessengerEXT, table.vkDestroyDebugUtilsMessengerEXT) {
+            let create_info = DebugUtilsMessengerCreateInfoEXT {
+                messageSeverity: utils_severities(min_severity),
+                messageType: DebugUtilsMessageTypeFlagsEXT::all(),
+                pfnUserCallback: Some(utils_callback),
+                pUserData: user_data,
+                ..Default::default()
+            };
+            let mut handle = DebugUtilsMessengerEXT::null();
+            unsafe { create(instance.handle(), &create_info, allocator, &mut handle).result()?; }
+            Messenger::Utils(handle)
+        } else if let (Some(create), Some(_)) = (table.vkCreateDebugReportCallbackEXT, table.vkDestroyDebugReportCallbackEXT) {
+            let create_info = DebugReportCallbackCreateInfoEXT {
+                flags: report_flags(min_severity),
+                // report_callback reads the object type as an integer, which
+                // the enum is passed as.
+                pfnCallback: Some(unsafe { mem::transmute::<RawReportCallback, DebugReportCallbackFunctionEXT>(report_callback) }),
+                pUserData: user_data,
+                ..Default::default()
+            };
+            let mut handle = DebugReportCallbackEXT::null();
+            unsafe { create(instance.handle(), &create_info, allocator, &mut handle).result()?; }
+            Messenger::Report(handle)
+        } else {
+            return Err(Error::ExtensionNotPresent);
+        };
+
+        Ok(DebugMessenger {
+            messenger: messenger,
+            instance: instance,
+            allocator: allocator_opt,
+            _callback: callback
+        })
+    }
+
+    pub fn instance(&self) -> &'i OwnedInstance {
+        self.instance
+    }
+
+    // Whether messages come through debug_utils rather than debug_report.
+    pub fn is_debug_utils(&self) -> bool {
+        match self.messenger {
+            Messenger::Utils(_)  => true,
+            Messenger::Report(_) => false
+        }
+    }
+}
+
+impl<'i> Drop for DebugMessenger<'i> {
+    fn drop(&mut self) {
+        let fns = self.instance.fns();
+        unsafe {
+            match self.messenger {
+                Messenger::Utils(handle)  => fns.vkDestroyDebugUtilsMessengerEXT(self.instance.handle(), handle, pointer_of_option!(self.allocator)),
+                Messenger::Report(handle) => fns.vkDestroyDebugReportCallbackEXT(self.instance.handle(), handle, pointer_of_option!(self.allocator))
+            }
+        }
+    }
+}
+
+// A label begun by SpockCommandBuffer::cmd_label_scope, ended when dropped.
+// It derefs to the command buffer it was begun on, raw or cached.
+pub struct LabelScope<'c, C: SpockCommandBuffer + 'c = CommandBuffer> {
+    command_buffer: &'c mut C
+}
+
+impl<'c, C: SpockCommandBuffer> LabelScope<'c, C> {
+    // Takes over a label already begun on `command_buffer`.
+    pub fn new(command_buffer: &'c mut C) -> LabelScope<'c, C> {
+        LabelScope { command_buffer: command_buffer }
+    }
+}
+
+impl<'c, C: SpockCommandBuffer> Deref for LabelScope<'c, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        self.command_buffer
+    }
+}
+
+impl<'c, C: SpockCommandBuffer> DerefMut for LabelScope<'c, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.command_buffer
+    }
+}
+
+impl<'c, C: SpockCommandBuffer> Drop for LabelScope<'c, C> {
+    fn drop(&mut self) {
+        self.command_buffer.cmd_end_label();
+    }
+}
+
+fn utils_severities(min_severity: Severity) -> DebugUtilsMessageSeverityFlagsEXT {
+    let mut severities = DebugUtilsMessageSeverityFlagsEXT::Error;
+    if min_severity <= Severity::Warning {
+        severities |= DebugUtilsMessageSeverityFlagsEXT::Warning;
+    }
+    if min_severity <= Severity::Info {
+        severities |= DebugUtilsMessageSeverityFlagsEXT::Info;
+    }
+    if min_severity <= Severity::Verbose {
+        severities |= DebugUtilsMessageSeverityFlagsEXT::Verbose;
+    }
+    severities
+}
+
+fn report_flags(min_severity: Severity) -> DebugReportFlagsEXT {
+    let mut flags = DebugReportFlagsEXT::Error;
+    if min_severity <= Severity::Warning {
+        flags |= DebugReportFlagsEXT::Warning | DebugReportFlagsEXT::PerformanceWarning;
+    }
+    if min_severity <= Severity::Info {
+        flags |= DebugReportFlagsEXT::Information;
+    }
+    if min_severity <= Severity::Verbose {
+        flags |= DebugReportFlagsEXT::Debug;
+    }
+    flags
+}
+
+// Runs the closure behind `user_data`. Unwinding into the caller, which is C,
+// would be undefined behaviour, so a panic is stopped here; the panic hook
+// has already reported it by then. Returning false lets the command that
+// raised the message carry on, as the extensions require.
+unsafe fn dispatch<F: FnOnce() -> DebugMessage>(user_data: *mut c_void, decode: F) -> Bool32 {
+    let callback = &*(user_data as *const DebugCallback);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(&decode())));
+    VK_FALSE
+}
+
+unsafe extern "system" fn utils_callback(severity: DebugUtilsMessageSeverityFlagsEXT, types: DebugUtilsMessageTypeFlagsEXT,
+                                         data: *const DebugUtilsMessengerCallbackDataEXT, user_data: *mut c_void) -> Bool32 {
+    dispatch(user_data, || {
+        let data = &*data;
+        DebugMessage {
+            severity: if severity.contains(DebugUtilsMessageSeverityFlagsEXT::Error) {
+                Severity::Error
+            } else if severity.contains(DebugUtilsMessageSeverityFlagsEXT::Warning) {
+                Severity::Warning
+            } else if severity.contains(DebugUtilsMessageSeverityFlagsEXT::Info) {
+                Severity::Info
+            } else {
+                Severity::Verbose
+            },
+            types: types,
+            id_name: string_of(data.pMessageIdName),
+            id_number: data.messageIdNumber,
+            message: string_of(data.pMessage).unwrap_or_default(),
+            queue_labels: slice_of(data.pQueueLabels, data.queueLabelCount).iter().map(label_of).collect(),
+            command_buffer_labels: slice_of(data.pCmdBufLabels, data.cmdBufLabelCount).iter().map(label_of).collect(),
+            objects: (0..data.objectCount as usize).map(|index| object_of(data.pObjects.add(index))).collect()
+        }
+    })
+}
+
+type RawReportCallback = unsafe extern "system" fn(DebugReportFlagsEXT, i32, u64, usize, i32, *const c_char, *const c_char, *mut c_void) -> Bool32;
+
+// Takes the object type as an integer, as layers may report types newer than
+// DebugReportObjectTypeEXT, and an enum holding any other value would be
+// undefined behaviour.
+unsafe extern "system" fn report_callback(flags: DebugReportFlagsEXT, object_type: i32, object: u64, _location: usize,
+                                          message_code: i32, layer_prefix: *const c_char, message: *const c_char, user_data: *mut c_void) -> Bool32 {
+    dispatch(user_data, || {
+        let object_type = report_object_type(object_type);
+        DebugMessage {
+            severity: if flags.contains(DebugReportFlagsEXT::Error) {
+                Severity::Error
+            } else if flags.intersects(DebugReportFlagsEXT::Warning | DebugReportFlagsEXT::PerformanceWarning) {
+                Severity::Warning
+            } else if flags.contains(DebugReportFlagsEXT::Information) {
+                Severity::Info
+            } else {
+                Severity::Verbose
+            },
+            types: if flags.contains(DebugReportFlagsEXT::PerformanceWarning) {
+                DebugUtilsMessageTypeFlagsEXT::Performance
+            } else if flags.intersects(DebugReportFlagsEXT::Error | DebugReportFlagsEXT::Warning) {
+                DebugUtilsMessageTypeFlagsEXT::Validation
+            } else {
+                DebugUtilsMessageTypeFlagsEXT::General
+            },
+            id_name: string_of(layer_prefix),
+            id_number: message_code,
+            message: string_of(message).unwrap_or_default(),
+            queue_labels: Vec::new(),
+            command_buffer_labels: Vec::new(),
+            objects: if object_type == ObjectType::Unknown && object == 0 {
+                Vec::new()
+            } else {
+                vec![DebugObject { object_type: object_type, handle: object, name: None }]
+            }
+        }
+    })
+}
+
+unsafe fn string_of(pointer: *const c_char) -> Option<String> {
+    if pointer.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(pointer).to_string_lossy().into_owned())
+    }
+}
+
+unsafe fn slice_of<'a, T>(pointer: *const T, count: u32) -> &'a [T] {
+    if pointer.is_null() || count == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(pointer, count as usize)
+    }
+}
+
+fn label_of(label: &DebugUtilsLabelEXT) -> DebugLabel {
+    DebugLabel {
+        name: unsafe { string_of(label.pLabelName) }.unwrap_or_default(),
+        color: label.color
+    }
+}
+
+// Layers report objects of every type they know of, which may be newer than
+// our registry, so the type is read as an integer before it becomes an
+// ObjectType.
+unsafe fn object_of(object: *const DebugUtilsObjectNameInfoEXT) -> DebugObject {
+    let raw_type = ptr::read(ptr::addr_of!((*object).objectType) as *const i32);
+    DebugObject {
+        object_type: object_type_of_raw(raw_type),
+        handle: ptr::read(ptr::addr_of!((*object).objectHandle)),
+        name: string_of(ptr::read(ptr::addr_of!((*object).pObjectName)))
+    }
+}
+
+fn object_type_of_raw(raw: i32) -> ObjectType {
+    match raw {
+        // The core object types are numbered without gaps.
+        0..=25        => unsafe { mem::transmute::<i32, ObjectType>(raw) },
+        1_000_000_000 => ObjectType::SurfaceKHR,
+        1_000_001_000 => ObjectType::SwapchainKHR,
+        1_000_011_000 => ObjectType::DebugReportCallbackExt,
+        1_000_128_000 => ObjectType::DebugUtilsMessengerExt,
+        _             => ObjectType::Unknown
+    }
+}
+
+// debug_report's object types agree with ObjectType up to CommandPool, and
+// number the extensions' own types after it.
+fn report_object_type(raw: i32) -> ObjectType {
+    match raw {
+        0..=25 => object_type_of_raw(raw),
+        26     => ObjectType::SurfaceKHR,
+        27     => ObjectType::SwapchainKHR,
+        28     => ObjectType::DebugReportCallbackExt,
+        _      => ObjectType::Unknown
+    }
+}
+
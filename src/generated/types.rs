@@ -0,0 +1,5255 @@
+// Generated from generator/vk.xml by `cargo run -p spock-generator`. Edit the
+// registry or the generator and regenerate rather than changing this file.
+
+pub const VK_MAX_PHYSICAL_DEVICE_NAME_SIZE: usize = 256;
+pub const VK_UUID_SIZE: usize = 16;
+pub const VK_MAX_EXTENSION_NAME_SIZE: usize = 256;
+pub const VK_MAX_DESCRIPTION_SIZE: usize = 256;
+pub const VK_MAX_MEMORY_TYPES: usize = 32;
+pub const VK_MAX_MEMORY_HEAPS: usize = 16;
+pub const VK_LOD_CLAMP_NONE: c_float = 1000.0;
+pub const VK_REMAINING_MIP_LEVELS: uint32_t = !0;
+pub const VK_REMAINING_ARRAY_LAYERS: uint32_t = !0;
+pub const VK_WHOLE_SIZE: uint64_t = !0;
+pub const VK_ATTACHMENT_UNUSED: uint32_t = !0;
+pub const VK_TRUE: uint32_t = 1;
+pub const VK_FALSE: uint32_t = 0;
+pub const VK_QUEUE_FAMILY_IGNORED: uint32_t = !0;
+pub const VK_SUBPASS_EXTERNAL: uint32_t = !0;
+pub const VK_KHR_SURFACE_SPEC_VERSION: uint32_t = 25;
+pub const VK_KHR_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_surface";
+pub const VK_KHR_SWAPCHAIN_SPEC_VERSION: uint32_t = 70;
+pub const VK_KHR_SWAPCHAIN_EXTENSION_NAME: &'static str = "VK_KHR_swapchain";
+pub const VK_KHR_XLIB_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_KHR_XLIB_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_xlib_surface";
+pub const VK_KHR_XCB_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_KHR_XCB_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_xcb_surface";
+pub const VK_KHR_WAYLAND_SURFACE_SPEC_VERSION: uint32_t = 6;
+pub const VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME: &'static str = "VK_KHR_wayland_surface";
+pub const VK_EXT_HEADLESS_SURFACE_SPEC_VERSION: uint32_t = 1;
+pub const VK_EXT_HEADLESS_SURFACE_EXTENSION_NAME: &'static str = "VK_EXT_headless_surface";
+pub const VK_EXT_DEBUG_REPORT_SPEC_VERSION: uint32_t = 10;
+pub const VK_EXT_DEBUG_REPORT_EXTENSION_NAME: &'static str = "VK_EXT_debug_report";
+pub const VK_EXT_DEBUG_UTILS_SPEC_VERSION: uint32_t = 2;
+pub const VK_EXT_DEBUG_UTILS_EXTENSION_NAME: &'static str = "VK_EXT_debug_utils";
+
+pub type SampleMask = uint32_t;
+pub type Bool32 = uint32_t;
+pub type Flags = uint32_t;
+pub type DeviceSize = uint64_t;
+
+handle!(Instance, *mut c_void, ptr::null_mut(), Instance);
+handle!(PhysicalDevice, *mut c_void, ptr::null_mut(), PhysicalDevice);
+handle!(Device, *mut c_void, ptr::null_mut(), Device);
+handle!(Queue, *mut c_void, ptr::null_mut(), Queue);
+handle!(CommandBuffer, *mut c_void, ptr::null_mut(), CommandBuffer);
+handle!(DeviceMemory, uint64_t, 0, DeviceMemory);
+handle!(CommandPool, uint64_t, 0, CommandPool);
+handle!(Buffer, uint64_t, 0, Buffer);
+handle!(BufferView, uint64_t, 0, BufferView);
+handle!(Image, uint64_t, 0, Image);
+handle!(ImageView, uint64_t, 0, ImageView);
+handle!(ShaderModule, uint64_t, 0, ShaderModule);
+handle!(Pipeline, uint64_t, 0, Pipeline);
+handle!(PipelineLayout, uint64_t, 0, PipelineLayout);
+handle!(Sampler, uint64_t, 0, Sampler);
+handle!(DescriptorSet, uint64_t, 0, DescriptorSet);
+handle!(DescriptorSetLayout, uint64_t, 0, DescriptorSetLayout);
+handle!(DescriptorPool, uint64_t, 0, DescriptorPool);
+handle!(Fence, uint64_t, 0, Fence);
+handle!(Semaphore, uint64_t, 0, Semaphore);
+handle!(Event, uint64_t, 0, Event);
+handle!(QueryPool, uint64_t, 0, QueryPool);
+handle!(Framebuffer, uint64_t, 0, Framebuffer);
+handle!(RenderPass, uint64_t, 0, RenderPass);
+handle!(PipelineCache, uint64_t, 0, PipelineCache);
+handle!(SurfaceKHR, uint64_t, 0, SurfaceKHR);
+handle!(SwapchainKHR, uint64_t, 0, SwapchainKHR);
+handle!(DebugReportCallbackEXT, uint64_t, 0, DebugReportCallbackExt);
+handle!(DebugUtilsMessengerEXT, uint64_t, 0, DebugUtilsMessengerExt);
+
+pub type InternalAllocationNotificationFunction = unsafe extern "system" fn(*mut c_void, size_t, InternalAllocationType, SystemAllocationScope);
+pub type InternalFreeNotificationFunction = unsafe extern "system" fn(*mut c_void, size_t, InternalAllocationType, SystemAllocationScope);
+pub type ReallocationFunction = unsafe extern "system" fn(*mut c_void, *mut c_void, size_t, size_t, SystemAllocationScope) -> *mut c_void;
+pub type AllocationFunction = unsafe extern "system" fn(*mut c_void, size_t, size_t, SystemAllocationScope) -> *mut c_void;
+pub type FreeFunction = unsafe extern "system" fn(*mut c_void, *mut c_void);
+pub type VoidFunction = unsafe extern "system" fn();
+pub type DebugReportCallbackFunctionEXT = unsafe extern "system" fn(DebugReportFlagsEXT, DebugReportObjectTypeEXT, uint64_t, size_t, int32_t, *const c_char, *const c_char, *mut c_void) -> Bool32;
+pub type DebugUtilsMessengerCallbackFunctionEXT = unsafe extern "system" fn(DebugUtilsMessageSeverityFlagsEXT, DebugUtilsMessageTypeFlagsEXT, *const DebugUtilsMessengerCallbackDataEXT, *mut c_void) -> Bool32;
+
+result_codes! {
+    success {
+        Success = 0,
+        NotReady = 1,
+        Timeout = 2,
+        EventSet = 3,
+        EventReset = 4,
+        Incomplete = 5,
+        PipelineCompileRequired = 1_000_297_000,
+        SuboptimalKHR = 1_000_001_003
+    }
+    error {
+        OutOfHostMemory = -1,
+        OutOfDeviceMemory = -2,
+        InitializationFailed = -3,
+        DeviceLost = -4,
+        MemoryMapFailed = -5,
+        LayerNotPresent = -6,
+        ExtensionNotPresent = -7,
+        FeatureNotPresent = -8,
+        IncompatibleDriver = -9,
+        TooManyObjects = -10,
+        FormatNotSupported = -11,
+        FragmentedPool = -12,
+        Unknown = -13,
+        OutOfPoolMemory = -1_000_069_000,
+        InvalidExternalHandle = -1_000_072_003,
+        Fragmentation = -1_000_161_000,
+        InvalidOpaqueCaptureAddress = -1_000_257_000,
+        SurfaceLostKHR = -1_000_000_000,
+        NativeWindowInUseKHR = -1_000_000_001,
+        OutOfDateKHR = -1_000_001_004,
+        IncompatibleDisplayKHR = -1_000_003_001,
+        ValidationFailedExt = -1_000_011_001
+    }
+}
+
+open_enum!(StructureType {
+    ApplicationInfo = 0,
+    InstanceCreateInfo = 1,
+    DeviceQueueCreateInfo = 2,
+    DeviceCreateInfo = 3,
+    SubmitInfo = 4,
+    MemoryAllocateInfo = 5,
+    MappedMemoryRange = 6,
+    BindSparseInfo = 7,
+    FenceCreateInfo = 8,
+    SemaphoreCreateInfo = 9,
+    EventCreateInfo = 10,
+    QueryPoolCreateInfo = 11,
+    BufferCreateInfo = 12,
+    BufferViewCreateInfo = 13,
+    ImageCreateInfo = 14,
+    ImageViewCreateInfo = 15,
+    ShaderModuleCreateInfo = 16,
+    PipelineCacheCreateInfo = 17,
+    PipelineShaderStageCreateInfo = 18,
+    PipelineVertexInputStateCreateInfo = 19,
+    PipelineInputAssemblyStateCreateInfo = 20,
+    PipelineTessellationStateCreateInfo = 21,
+    PipelineViewportStateCreateInfo = 22,
+    PipelineRasterizationStateCreateInfo = 23,
+    PipelineMultisampleStateCreateInfo = 24,
+    PipelineDepthStencilStateCreateInfo = 25,
+    PipelineColorBlendStateCreateInfo = 26,
+    PipelineDynamicStateCreateInfo = 27,
+    GraphicsPipelineCreateInfo = 28,
+    ComputePipelineCreateInfo = 29,
+    PipelineLayoutCreateInfo = 30,
+    SamplerCreateInfo = 31,
+    DescriptorSetLayoutCreateInfo = 32,
+    DescriptorPoolCreateInfo = 33,
+    DescriptorSetAllocateInfo = 34,
+    WriteDescriptorSet = 35,
+    CopyDescriptorSet = 36,
+    FramebufferCreateInfo = 37,
+    RenderPassCreateInfo = 38,
+    CommandPoolCreateInfo = 39,
+    CommandBufferAllocateInfo = 40,
+    CommandBufferInheritanceInfo = 41,
+    CommandBufferBeginInfo = 42,
+    RenderPassBeginInfo = 43,
+    BufferMemoryBarrier = 44,
+    ImageMemoryBarrier = 45,
+    MemoryBarrier = 46,
+    LoaderInstanceCreateInfo = 47,
+    LoaderDeviceCreateInfo = 48,
+    PhysicalDeviceSubgroupProperties = 1_000_094_000,
+    BindBufferMemoryInfo = 1_000_157_000,
+    BindImageMemoryInfo = 1_000_157_001,
+    PhysicalDevice16BitStorageFeatures = 1_000_083_000,
+    MemoryDedicatedRequirements = 1_000_127_000,
+    MemoryDedicatedAllocateInfo = 1_000_127_001,
+    MemoryAllocateFlagsInfo = 1_000_060_000,
+    DeviceGroupRenderPassBeginInfo = 1_000_060_003,
+    DeviceGroupCommandBufferBeginInfo = 1_000_060_004,
+    DeviceGroupSubmitInfo = 1_000_060_005,
+    DeviceGroupBindSparseInfo = 1_000_060_006,
+    BindBufferMemoryDeviceGroupInfo = 1_000_060_013,
+    BindImageMemoryDeviceGroupInfo = 1_000_060_014,
+    PhysicalDeviceGroupProperties = 1_000_070_000,
+    DeviceGroupDeviceCreateInfo = 1_000_070_001,
+    BufferMemoryRequirementsInfo2 = 1_000_146_000,
+    ImageMemoryRequirementsInfo2 = 1_000_146_001,
+    ImageSparseMemoryRequirementsInfo2 = 1_000_146_002,
+    MemoryRequirements2 = 1_000_146_003,
+    SparseImageMemoryRequirements2 = 1_000_146_004,
+    PhysicalDeviceFeatures2 = 1_000_059_000,
+    PhysicalDeviceProperties2 = 1_000_059_001,
+    FormatProperties2 = 1_000_059_002,
+    ImageFormatProperties2 = 1_000_059_003,
+    PhysicalDeviceImageFormatInfo2 = 1_000_059_004,
+    QueueFamilyProperties2 = 1_000_059_005,
+    PhysicalDeviceMemoryProperties2 = 1_000_059_006,
+    SparseImageFormatProperties2 = 1_000_059_007,
+    PhysicalDeviceSparseImageFormatInfo2 = 1_000_059_008,
+    PhysicalDevicePointClippingProperties = 1_000_117_000,
+    RenderPassInputAttachmentAspectCreateInfo = 1_000_117_001,
+    ImageViewUsageCreateInfo = 1_000_117_002,
+    PipelineTessellationDomainOriginStateCreateInfo = 1_000_117_003,
+    RenderPassMultiviewCreateInfo = 1_000_053_000,
+    PhysicalDeviceMultiviewFeatures = 1_000_053_001,
+    PhysicalDeviceMultiviewProperties = 1_000_053_002,
+    PhysicalDeviceVariablePointersFeatures = 1_000_120_000,
+    ProtectedSubmitInfo = 1_000_145_000,
+    PhysicalDeviceProtectedMemoryFeatures = 1_000_145_001,
+    PhysicalDeviceProtectedMemoryProperties = 1_000_145_002,
+    DeviceQueueInfo2 = 1_000_145_003,
+    SamplerYcbcrConversionCreateInfo = 1_000_156_000,
+    SamplerYcbcrConversionInfo = 1_000_156_001,
+    BindImagePlaneMemoryInfo = 1_000_156_002,
+    ImagePlaneMemoryRequirementsInfo = 1_000_156_003,
+    PhysicalDeviceSamplerYcbcrConversionFeatures = 1_000_156_004,
+    SamplerYcbcrConversionImageFormatProperties = 1_000_156_005,
+    DescriptorUpdateTemplateCreateInfo = 1_000_085_000,
+    PhysicalDeviceExternalImageFormatInfo = 1_000_071_000,
+    ExternalImageFormatProperties = 1_000_071_001,
+    PhysicalDeviceExternalBufferInfo = 1_000_071_002,
+    ExternalBufferProperties = 1_000_071_003,
+    PhysicalDeviceIdProperties = 1_000_071_004,
+    ExternalMemoryBufferCreateInfo = 1_000_072_000,
+    ExternalMemoryImageCreateInfo = 1_000_072_001,
+    ExportMemoryAllocateInfo = 1_000_072_002,
+    PhysicalDeviceExternalFenceInfo = 1_000_112_000,
+    ExternalFenceProperties = 1_000_112_001,
+    ExportFenceCreateInfo = 1_000_113_000,
+    ExportSemaphoreCreateInfo = 1_000_077_000,
+    PhysicalDeviceExternalSemaphoreInfo = 1_000_076_000,
+    ExternalSemaphoreProperties = 1_000_076_001,
+    PhysicalDeviceMaintenance3Properties = 1_000_168_000,
+    DescriptorSetLayoutSupport = 1_000_168_001,
+    PhysicalDeviceShaderDrawParametersFeatures = 1_000_063_000,
+    PhysicalDeviceVulkan11Features = 49,
+    PhysicalDeviceVulkan11Properties = 50,
+    PhysicalDeviceVulkan12Features = 51,
+    PhysicalDeviceVulkan12Properties = 52,
+    ImageFormatListCreateInfo = 1_000_147_000,
+    AttachmentDescription2 = 1_000_109_000,
+    AttachmentReference2 = 1_000_109_001,
+    SubpassDescription2 = 1_000_109_002,
+    SubpassDependency2 = 1_000_109_003,
+    RenderPassCreateInfo2 = 1_000_109_004,
+    SubpassBeginInfo = 1_000_109_005,
+    SubpassEndInfo = 1_000_109_006,
+    PhysicalDevice8BitStorageFeatures = 1_000_177_000,
+    PhysicalDeviceDriverProperties = 1_000_196_000,
+    PhysicalDeviceShaderAtomicInt64Features = 1_000_180_000,
+    PhysicalDeviceShaderFloat16Int8Features = 1_000_082_000,
+    PhysicalDeviceFloatControlsProperties = 1_000_197_000,
+    DescriptorSetLayoutBindingFlagsCreateInfo = 1_000_161_000,
+    PhysicalDeviceDescriptorIndexingFeatures = 1_000_161_001,
+    PhysicalDeviceDescriptorIndexingProperties = 1_000_161_002,
+    DescriptorSetVariableDescriptorCountAllocateInfo = 1_000_161_003,
+    DescriptorSetVariableDescriptorCountLayoutSupport = 1_000_161_004,
+    PhysicalDeviceDepthStencilResolveProperties = 1_000_199_000,
+    SubpassDescriptionDepthStencilResolve = 1_000_199_001,
+    PhysicalDeviceScalarBlockLayoutFeatures = 1_000_221_000,
+    ImageStencilUsageCreateInfo = 1_000_246_000,
+    PhysicalDeviceSamplerFilterMinmaxProperties = 1_000_130_000,
+    SamplerReductionModeCreateInfo = 1_000_130_001,
+    PhysicalDeviceVulkanMemoryModelFeatures = 1_000_211_000,
+    PhysicalDeviceImagelessFramebufferFeatures = 1_000_108_000,
+    FramebufferAttachmentsCreateInfo = 1_000_108_001,
+    FramebufferAttachmentImageInfo = 1_000_108_002,
+    RenderPassAttachmentBeginInfo = 1_000_108_003,
+    PhysicalDeviceUniformBufferStandardLayoutFeatures = 1_000_253_000,
+    PhysicalDeviceShaderSubgroupExtendedTypesFeatures = 1_000_175_000,
+    PhysicalDeviceSeparateDepthStencilLayoutsFeatures = 1_000_241_000,
+    AttachmentReferenceStencilLayout = 1_000_241_001,
+    AttachmentDescriptionStencilLayout = 1_000_241_002,
+    PhysicalDeviceHostQueryResetFeatures = 1_000_261_000,
+    PhysicalDeviceTimelineSemaphoreFeatures = 1_000_207_000,
+    PhysicalDeviceTimelineSemaphoreProperties = 1_000_207_001,
+    SemaphoreTypeCreateInfo = 1_000_207_002,
+    TimelineSemaphoreSubmitInfo = 1_000_207_003,
+    SemaphoreWaitInfo = 1_000_207_004,
+    SemaphoreSignalInfo = 1_000_207_005,
+    PhysicalDeviceBufferDeviceAddressFeatures = 1_000_257_000,
+    BufferDeviceAddressInfo = 1_000_244_001,
+    BufferOpaqueCaptureAddressCreateInfo = 1_000_257_002,
+    MemoryOpaqueCaptureAddressAllocateInfo = 1_000_257_003,
+    DeviceMemoryOpaqueCaptureAddressInfo = 1_000_257_004,
+    PhysicalDeviceVulkan13Features = 53,
+    PhysicalDeviceVulkan13Properties = 54,
+    PipelineCreationFeedbackCreateInfo = 1_000_192_000,
+    PhysicalDeviceShaderTerminateInvocationFeatures = 1_000_215_000,
+    PhysicalDeviceToolProperties = 1_000_245_000,
+    PhysicalDeviceShaderDemoteToHelperInvocationFeatures = 1_000_276_000,
+    PhysicalDevicePrivateDataFeatures = 1_000_295_000,
+    DevicePrivateDataCreateInfo = 1_000_295_001,
+    PrivateDataSlotCreateInfo = 1_000_295_002,
+    PhysicalDevicePipelineCreationCacheControlFeatures = 1_000_297_000,
+    MemoryBarrier2 = 1_000_314_000,
+    BufferMemoryBarrier2 = 1_000_314_001,
+    ImageMemoryBarrier2 = 1_000_314_002,
+    DependencyInfo = 1_000_314_003,
+    SubmitInfo2 = 1_000_314_004,
+    SemaphoreSubmitInfo = 1_000_314_005,
+    CommandBufferSubmitInfo = 1_000_314_006,
+    PhysicalDeviceSynchronization2Features = 1_000_314_007,
+    PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures = 1_000_325_000,
+    PhysicalDeviceImageRobustnessFeatures = 1_000_335_000,
+    CopyBufferInfo2 = 1_000_337_000,
+    CopyImageInfo2 = 1_000_337_001,
+    CopyBufferToImageInfo2 = 1_000_337_002,
+    CopyImageToBufferInfo2 = 1_000_337_003,
+    BlitImageInfo2 = 1_000_337_004,
+    ResolveImageInfo2 = 1_000_337_005,
+    BufferCopy2 = 1_000_337_006,
+    ImageCopy2 = 1_000_337_007,
+    ImageBlit2 = 1_000_337_008,
+    BufferImageCopy2 = 1_000_337_009,
+    ImageResolve2 = 1_000_337_010,
+    PhysicalDeviceSubgroupSizeControlProperties = 1_000_225_000,
+    PipelineShaderStageRequiredSubgroupSizeCreateInfo = 1_000_225_001,
+    PhysicalDeviceSubgroupSizeControlFeatures = 1_000_225_002,
+    PhysicalDeviceInlineUniformBlockFeatures = 1_000_138_000,
+    PhysicalDeviceInlineUniformBlockProperties = 1_000_138_001,
+    WriteDescriptorSetInlineUniformBlock = 1_000_138_002,
+    DescriptorPoolInlineUniformBlockCreateInfo = 1_000_138_003,
+    PhysicalDeviceTextureCompressionAstcHdrFeatures = 1_000_066_000,
+    RenderingInfo = 1_000_044_000,
+    RenderingAttachmentInfo = 1_000_044_001,
+    PipelineRenderingCreateInfo = 1_000_044_002,
+    PhysicalDeviceDynamicRenderingFeatures = 1_000_044_003,
+    CommandBufferInheritanceRenderingInfo = 1_000_044_004,
+    PhysicalDeviceShaderIntegerDotProductFeatures = 1_000_280_000,
+    PhysicalDeviceShaderIntegerDotProductProperties = 1_000_280_001,
+    PhysicalDeviceTexelBufferAlignmentProperties = 1_000_281_001,
+    FormatProperties3 = 1_000_360_000,
+    PhysicalDeviceMaintenance4Features = 1_000_413_000,
+    PhysicalDeviceMaintenance4Properties = 1_000_413_001,
+    DeviceBufferMemoryRequirements = 1_000_413_002,
+    DeviceImageMemoryRequirements = 1_000_413_003,
+    SwapchainCreateInfoKHR = 1_000_001_000,
+    PresentInfoKHR = 1_000_001_001,
+    DisplayModeCreateInfoKHR = 1_000_002_000,
+    DisplaySurfaceCreateInfoKHR = 1_000_002_001,
+    DisplayPresentInfoKHR = 1_000_003_000,
+    XlibSurfaceCreateInfoKHR = 1_000_004_000,
+    XcbSurfaceCreateInfoKHR = 1_000_005_000,
+    WaylandSurfaceCreateInfoKHR = 1_000_006_000,
+    MirSurfaceCreateInfoKHR = 1_000_007_000,
+    AndroidSurfaceCreateInfoKHR = 1_000_008_000,
+    Win32SurfaceCreateInfoKHR = 1_000_009_000,
+    DebugReportCreateInfoExt = 1_000_011_000,
+    DebugMarkerObjectNameInfoExt = 1_000_022_000,
+    DebugMarkerObjectTagInfoExt = 1_000_022_001,
+    DebugMarkerMarkerInfoExt = 1_000_022_002,
+    PhysicalDeviceTransformFeedbackFeaturesExt = 1_000_028_000,
+    PhysicalDeviceTransformFeedbackPropertiesExt = 1_000_028_001,
+    PipelineRasterizationStateStreamCreateInfoExt = 1_000_028_002,
+    DeviceGroupPresentCapabilitiesKHR = 1_000_060_007,
+    ImageSwapchainCreateInfoKHR = 1_000_060_008,
+    BindImageMemorySwapchainInfoKHR = 1_000_060_009,
+    AcquireNextImageInfoKHR = 1_000_060_010,
+    DeviceGroupPresentInfoKHR = 1_000_060_011,
+    DeviceGroupSwapchainCreateInfoKHR = 1_000_060_012,
+    ValidationFlagsExt = 1_000_061_000,
+    ImageViewAstcDecodeModeExt = 1_000_067_000,
+    PhysicalDeviceAstcDecodeFeaturesExt = 1_000_067_001,
+    PhysicalDevicePipelineRobustnessFeaturesExt = 1_000_068_000,
+    PipelineRobustnessCreateInfoExt = 1_000_068_001,
+    PhysicalDevicePipelineRobustnessPropertiesExt = 1_000_068_002,
+    ImportMemoryWin32HandleInfoKHR = 1_000_073_000,
+    ExportMemoryWin32HandleInfoKHR = 1_000_073_001,
+    MemoryWin32HandlePropertiesKHR = 1_000_073_002,
+    MemoryGetWin32HandleInfoKHR = 1_000_073_003,
+    ImportMemoryFdInfoKHR = 1_000_074_000,
+    MemoryFdPropertiesKHR = 1_000_074_001,
+    MemoryGetFdInfoKHR = 1_000_074_002,
+    Win32KeyedMutexAcquireReleaseInfoKHR = 1_000_075_000,
+    ImportSemaphoreWin32HandleInfoKHR = 1_000_078_000,
+    ExportSemaphoreWin32HandleInfoKHR = 1_000_078_001,
+    D3D12FenceSubmitInfoKHR = 1_000_078_002,
+    SemaphoreGetWin32HandleInfoKHR = 1_000_078_003,
+    ImportSemaphoreFdInfoKHR = 1_000_079_000,
+    SemaphoreGetFdInfoKHR = 1_000_079_001,
+    PhysicalDevicePushDescriptorPropertiesKHR = 1_000_080_000,
+    CommandBufferInheritanceConditionalRenderingInfoExt = 1_000_081_000,
+    PhysicalDeviceConditionalRenderingFeaturesExt = 1_000_081_001,
+    ConditionalRenderingBeginInfoExt = 1_000_081_002,
+    PresentRegionsKHR = 1_000_084_000,
+    SurfaceCapabilities2Ext = 1_000_090_000,
+    DisplayPowerInfoExt = 1_000_091_000,
+    DeviceEventInfoExt = 1_000_091_001,
+    DisplayEventInfoExt = 1_000_091_002,
+    SwapchainCounterCreateInfoExt = 1_000_091_003,
+    PhysicalDeviceDiscardRectanglePropertiesExt = 1_000_099_000,
+    PipelineDiscardRectangleStateCreateInfoExt = 1_000_099_001,
+    PhysicalDeviceConservativeRasterizationPropertiesExt = 1_000_101_000,
+    PipelineRasterizationConservativeStateCreateInfoExt = 1_000_101_001,
+    PhysicalDeviceDepthClipEnableFeaturesExt = 1_000_102_000,
+    PipelineRasterizationDepthClipStateCreateInfoExt = 1_000_102_001,
+    HdrMetadataExt = 1_000_105_000,
+    SharedPresentSurfaceCapabilitiesKHR = 1_000_111_000,
+    ImportFenceWin32HandleInfoKHR = 1_000_114_000,
+    ExportFenceWin32HandleInfoKHR = 1_000_114_001,
+    FenceGetWin32HandleInfoKHR = 1_000_114_002,
+    ImportFenceFdInfoKHR = 1_000_115_000,
+    FenceGetFdInfoKHR = 1_000_115_001,
+    PhysicalDevicePerformanceQueryFeaturesKHR = 1_000_116_000,
+    PhysicalDevicePerformanceQueryPropertiesKHR = 1_000_116_001,
+    QueryPoolPerformanceCreateInfoKHR = 1_000_116_002,
+    PerformanceQuerySubmitInfoKHR = 1_000_116_003,
+    AcquireProfilingLockInfoKHR = 1_000_116_004,
+    PerformanceCounterKHR = 1_000_116_005,
+    PerformanceCounterDescriptionKHR = 1_000_116_006,
+    PhysicalDeviceSurfaceInfo2KHR = 1_000_119_000,
+    SurfaceCapabilities2KHR = 1_000_119_001,
+    SurfaceFormat2KHR = 1_000_119_002,
+    DisplayProperties2KHR = 1_000_121_000,
+    DisplayPlaneProperties2KHR = 1_000_121_001,
+    DisplayModeProperties2KHR = 1_000_121_002,
+    DisplayPlaneInfo2KHR = 1_000_121_003,
+    DisplayPlaneCapabilities2KHR = 1_000_121_004,
+    DebugUtilsObjectNameInfoExt = 1_000_128_000,
+    DebugUtilsObjectTagInfoExt = 1_000_128_001,
+    DebugUtilsLabelExt = 1_000_128_002,
+    DebugUtilsMessengerCallbackDataExt = 1_000_128_003,
+    DebugUtilsMessengerCreateInfoExt = 1_000_128_004,
+    SampleLocationsInfoExt = 1_000_143_000,
+    RenderPassSampleLocationsBeginInfoExt = 1_000_143_001,
+    PipelineSampleLocationsStateCreateInfoExt = 1_000_143_002,
+    PhysicalDeviceSampleLocationsPropertiesExt = 1_000_143_003,
+    MultisamplePropertiesExt = 1_000_143_004,
+    PhysicalDeviceBlendOperationAdvancedFeaturesExt = 1_000_148_000,
+    PhysicalDeviceBlendOperationAdvancedPropertiesExt = 1_000_148_001,
+    PipelineColorBlendAdvancedStateCreateInfoExt = 1_000_148_002,
+    AccelerationStructureBuildGeometryInfoKHR = 1_000_150_000,
+    AccelerationStructureDeviceAddressInfoKHR = 1_000_150_002,
+    AccelerationStructureGeometryAabbsDataKHR = 1_000_150_003,
+    AccelerationStructureGeometryInstancesDataKHR = 1_000_150_004,
+    AccelerationStructureGeometryTrianglesDataKHR = 1_000_150_005,
+    AccelerationStructureGeometryKHR = 1_000_150_006,
+    WriteDescriptorSetAccelerationStructureKHR = 1_000_150_007,
+    AccelerationStructureVersionInfoKHR = 1_000_150_009,
+    CopyAccelerationStructureInfoKHR = 1_000_150_010,
+    CopyAccelerationStructureToMemoryInfoKHR = 1_000_150_011,
+    CopyMemoryToAccelerationStructureInfoKHR = 1_000_150_012,
+    PhysicalDeviceAccelerationStructureFeaturesKHR = 1_000_150_013,
+    PhysicalDeviceAccelerationStructurePropertiesKHR = 1_000_150_014,
+    RayTracingPipelineCreateInfoKHR = 1_000_150_015,
+    RayTracingShaderGroupCreateInfoKHR = 1_000_150_016,
+    AccelerationStructureCreateInfoKHR = 1_000_150_017,
+    RayTracingPipelineInterfaceCreateInfoKHR = 1_000_150_018,
+    AccelerationStructureBuildSizesInfoKHR = 1_000_150_020,
+    DrmFormatModifierPropertiesListExt = 1_000_158_000,
+    PhysicalDeviceImageDrmFormatModifierInfoExt = 1_000_158_002,
+    ImageDrmFormatModifierListCreateInfoExt = 1_000_158_003,
+    ImageDrmFormatModifierExplicitCreateInfoExt = 1_000_158_004,
+    ImageDrmFormatModifierPropertiesExt = 1_000_158_005,
+    DrmFormatModifierPropertiesList2Ext = 1_000_158_006,
+    ValidationCacheCreateInfoExt = 1_000_160_000,
+    ShaderModuleValidationCacheCreateInfoExt = 1_000_160_001,
+    PhysicalDeviceImageViewImageFormatInfoExt = 1_000_170_000,
+    FilterCubicImageViewImageFormatPropertiesExt = 1_000_170_001,
+    DeviceQueueGlobalPriorityCreateInfoKHR = 1_000_174_000,
+    ImportMemoryHostPointerInfoExt = 1_000_178_000,
+    MemoryHostPointerPropertiesExt = 1_000_178_001,
+    PhysicalDeviceExternalMemoryHostPropertiesExt = 1_000_178_002,
+    PhysicalDeviceShaderClockFeaturesKHR = 1_000_181_000,
+    CalibratedTimestampInfoExt = 1_000_184_000,
+    PhysicalDeviceVertexAttributeDivisorPropertiesExt = 1_000_190_000,
+    PipelineVertexInputDivisorStateCreateInfoExt = 1_000_190_001,
+    PhysicalDeviceVertexAttributeDivisorFeaturesExt = 1_000_190_002,
+    PhysicalDevicePciBusInfoPropertiesExt = 1_000_212_000,
+    MetalSurfaceCreateInfoExt = 1_000_217_000,
+    PhysicalDeviceFragmentDensityMapFeaturesExt = 1_000_218_000,
+    PhysicalDeviceFragmentDensityMapPropertiesExt = 1_000_218_001,
+    RenderPassFragmentDensityMapCreateInfoExt = 1_000_218_002,
+    FragmentShadingRateAttachmentInfoKHR = 1_000_226_000,
+    PipelineFragmentShadingRateStateCreateInfoKHR = 1_000_226_001,
+    PhysicalDeviceFragmentShadingRatePropertiesKHR = 1_000_226_002,
+    PhysicalDeviceFragmentShadingRateFeaturesKHR = 1_000_226_003,
+    PhysicalDeviceFragmentShadingRateKHR = 1_000_226_004,
+    PhysicalDeviceShaderImageAtomicInt64FeaturesExt = 1_000_234_000,
+    PhysicalDeviceMemoryBudgetPropertiesExt = 1_000_237_000,
+    PhysicalDeviceMemoryPriorityFeaturesExt = 1_000_238_000,
+    MemoryPriorityAllocateInfoExt = 1_000_238_001,
+    SurfaceProtectedCapabilitiesKHR = 1_000_239_000,
+    PhysicalDeviceBufferDeviceAddressFeaturesExt = 1_000_244_000,
+    BufferDeviceAddressCreateInfoExt = 1_000_244_002,
+    ValidationFeaturesExt = 1_000_247_000,
+    PhysicalDevicePresentWaitFeaturesKHR = 1_000_248_000,
+    PhysicalDeviceFragmentShaderInterlockFeaturesExt = 1_000_251_000,
+    PhysicalDeviceYcbcrImageArraysFeaturesExt = 1_000_252_000,
+    PhysicalDeviceProvokingVertexFeaturesExt = 1_000_254_000,
+    PipelineRasterizationProvokingVertexStateCreateInfoExt = 1_000_254_001,
+    PhysicalDeviceProvokingVertexPropertiesExt = 1_000_254_002,
+    SurfaceFullScreenExclusiveInfoExt = 1_000_255_000,
+    SurfaceFullScreenExclusiveWin32InfoExt = 1_000_255_001,
+    SurfaceCapabilitiesFullScreenExclusiveExt = 1_000_255_002,
+    HeadlessSurfaceCreateInfoExt = 1_000_256_000,
+    PhysicalDeviceLineRasterizationFeaturesExt = 1_000_259_000,
+    PipelineRasterizationLineStateCreateInfoExt = 1_000_259_001,
+    PhysicalDeviceLineRasterizationPropertiesExt = 1_000_259_002,
+    PhysicalDeviceShaderAtomicFloatFeaturesExt = 1_000_260_000,
+    PhysicalDeviceIndexTypeUint8FeaturesExt = 1_000_265_000,
+    PhysicalDeviceExtendedDynamicStateFeaturesExt = 1_000_267_000,
+    PhysicalDevicePipelineExecutablePropertiesFeaturesKHR = 1_000_269_000,
+    PipelineInfoKHR = 1_000_269_001,
+    PipelineExecutablePropertiesKHR = 1_000_269_002,
+    PipelineExecutableInfoKHR = 1_000_269_003,
+    PipelineExecutableStatisticKHR = 1_000_269_004,
+    PipelineExecutableInternalRepresentationKHR = 1_000_269_005,
+    PhysicalDeviceShaderAtomicFloat2FeaturesExt = 1_000_273_000,
+    SurfacePresentModeExt = 1_000_274_000,
+    SurfacePresentScalingCapabilitiesExt = 1_000_274_001,
+    SurfacePresentModeCompatibilityExt = 1_000_274_002,
+    PhysicalDeviceSwapchainMaintenance1FeaturesExt = 1_000_275_000,
+    SwapchainPresentFenceInfoExt = 1_000_275_001,
+    SwapchainPresentModesCreateInfoExt = 1_000_275_002,
+    SwapchainPresentModeInfoExt = 1_000_275_003,
+    SwapchainPresentScalingCreateInfoExt = 1_000_275_004,
+    ReleaseSwapchainImagesInfoExt = 1_000_275_005,
+    PhysicalDeviceTexelBufferAlignmentFeaturesExt = 1_000_281_000,
+    PhysicalDeviceDeviceMemoryReportFeaturesExt = 1_000_284_000,
+    DeviceDeviceMemoryReportCreateInfoExt = 1_000_284_001,
+    DeviceMemoryReportCallbackDataExt = 1_000_284_002,
+    PhysicalDeviceRobustness2FeaturesExt = 1_000_286_000,
+    PhysicalDeviceRobustness2PropertiesExt = 1_000_286_001,
+    SamplerCustomBorderColorCreateInfoExt = 1_000_287_000,
+    PhysicalDeviceCustomBorderColorPropertiesExt = 1_000_287_001,
+    PhysicalDeviceCustomBorderColorFeaturesExt = 1_000_287_002,
+    PipelineLibraryCreateInfoKHR = 1_000_290_000,
+    PresentIdKHR = 1_000_294_000,
+    PhysicalDevicePresentIdFeaturesKHR = 1_000_294_001,
+    PhysicalDeviceGraphicsPipelineLibraryFeaturesExt = 1_000_320_000,
+    PhysicalDeviceGraphicsPipelineLibraryPropertiesExt = 1_000_320_001,
+    GraphicsPipelineLibraryCreateInfoExt = 1_000_320_002,
+    PhysicalDeviceFragmentShaderBarycentricFeaturesKHR = 1_000_203_000,
+    PhysicalDeviceFragmentShaderBarycentricPropertiesKHR = 1_000_322_000,
+    PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR = 1_000_323_000,
+    PhysicalDeviceMeshShaderFeaturesExt = 1_000_328_000,
+    PhysicalDeviceMeshShaderPropertiesExt = 1_000_328_001,
+    PhysicalDeviceYcbcr2Plane444FormatsFeaturesExt = 1_000_330_000,
+    PhysicalDeviceFragmentDensityMap2FeaturesExt = 1_000_332_000,
+    PhysicalDeviceFragmentDensityMap2PropertiesExt = 1_000_332_001,
+    PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR = 1_000_336_000,
+    PhysicalDevice4444FormatsFeaturesExt = 1_000_340_000,
+    PhysicalDeviceFaultFeaturesExt = 1_000_341_000,
+    DeviceFaultCountsExt = 1_000_341_001,
+    DeviceFaultInfoExt = 1_000_341_002,
+    PhysicalDeviceRgba10X6FormatsFeaturesExt = 1_000_344_000,
+    DirectfbSurfaceCreateInfoExt = 1_000_346_000,
+    PhysicalDeviceRayTracingPipelineFeaturesKHR = 1_000_347_000,
+    PhysicalDeviceRayTracingPipelinePropertiesKHR = 1_000_347_001,
+    PhysicalDeviceRayQueryFeaturesKHR = 1_000_348_013,
+    PhysicalDeviceMutableDescriptorTypeFeaturesExt = 1_000_351_000,
+    MutableDescriptorTypeCreateInfoExt = 1_000_351_002,
+    PhysicalDeviceVertexInputDynamicStateFeaturesExt = 1_000_352_000,
+    VertexInputBindingDescription2Ext = 1_000_352_001,
+    VertexInputAttributeDescription2Ext = 1_000_352_002,
+    PhysicalDeviceDrmPropertiesExt = 1_000_353_000,
+    PhysicalDeviceAddressBindingReportFeaturesExt = 1_000_354_000,
+    DeviceAddressBindingCallbackDataExt = 1_000_354_001,
+    PhysicalDeviceDepthClipControlFeaturesExt = 1_000_355_000,
+    PipelineViewportDepthClipControlCreateInfoExt = 1_000_355_001,
+    PhysicalDevicePrimitiveTopologyListRestartFeaturesExt = 1_000_356_000,
+    PhysicalDeviceExtendedDynamicState2FeaturesExt = 1_000_377_000,
+    PhysicalDeviceColorWriteEnableFeaturesExt = 1_000_381_000,
+    PipelineColorWriteCreateInfoExt = 1_000_381_001,
+    PhysicalDevicePrimitivesGeneratedQueryFeaturesExt = 1_000_382_000,
+    PhysicalDeviceRayTracingMaintenance1FeaturesKHR = 1_000_386_000,
+    PhysicalDeviceGlobalPriorityQueryFeaturesKHR = 1_000_388_000,
+    QueueFamilyGlobalPriorityPropertiesKHR = 1_000_388_001,
+    PhysicalDeviceImageViewMinLodFeaturesExt = 1_000_391_000,
+    ImageViewMinLodCreateInfoExt = 1_000_391_001,
+    PhysicalDeviceMultiDrawFeaturesExt = 1_000_392_000,
+    PhysicalDeviceMultiDrawPropertiesExt = 1_000_392_001,
+    PhysicalDeviceImage2DViewOf3DFeaturesExt = 1_000_393_000,
+    PhysicalDeviceBorderColorSwizzleFeaturesExt = 1_000_411_000,
+    SamplerBorderColorComponentMappingCreateInfoExt = 1_000_411_001,
+    PhysicalDevicePageableDeviceLocalMemoryFeaturesExt = 1_000_412_000,
+    PhysicalDeviceDepthClampZeroOneFeaturesExt = 1_000_421_000,
+    PhysicalDeviceNonSeamlessCubeMapFeaturesExt = 1_000_422_000,
+    PhysicalDeviceExtendedDynamicState3FeaturesExt = 1_000_455_000,
+    PhysicalDeviceExtendedDynamicState3PropertiesExt = 1_000_455_001,
+    PhysicalDevicePipelineProtectedAccessFeaturesExt = 1_000_466_000
+});
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PipelineCacheHeaderVersion {
+    One = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SystemAllocationScope {
+    Command = 0,
+    Object = 1,
+    Cache = 2,
+    Device = 3,
+    Instance = 4
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InternalAllocationType {
+    Executable = 0
+}
+
+open_enum!(Format {
+    Undefined = 0,
+    R4G4UnormPack8 = 1,
+    R4G4B4A4UnormPack16 = 2,
+    B4G4R4A4UnormPack16 = 3,
+    R5G6B5UnormPack16 = 4,
+    B5G6R5UnormPack16 = 5,
+    R5G5B5A1UnormPack16 = 6,
+    B5G5R5A1UnormPack16 = 7,
+    A1R5G5B5UnormPack16 = 8,
+    R8Unorm = 9,
+    R8Snorm = 10,
+    R8Uscaled = 11,
+    R8Sscaled = 12,
+    R8Uint = 13,
+    R8Sint = 14,
+    R8Srgb = 15,
+    R8G8Unorm = 16,
+    R8G8Snorm = 17,
+    R8G8Uscaled = 18,
+    R8G8Sscaled = 19,
+    R8G8Uint = 20,
+    R8G8Sint = 21,
+    R8G8Srgb = 22,
+    R8G8B8Unorm = 23,
+    R8G8B8Snorm = 24,
+    R8G8B8Uscaled = 25,
+    R8G8B8Sscaled = 26,
+    R8G8B8Uint = 27,
+    R8G8B8Sint = 28,
+    R8G8B8Srgb = 29,
+    B8G8R8Unorm = 30,
+    B8G8R8Snorm = 31,
+    B8G8R8Uscaled = 32,
+    B8G8R8Sscaled = 33,
+    B8G8R8Uint = 34,
+    B8G8R8Sint = 35,
+    B8G8R8Srgb = 36,
+    R8G8B8A8Unorm = 37,
+    R8G8B8A8Snorm = 38,
+    R8G8B8A8Uscaled = 39,
+    R8G8B8A8Sscaled = 40,
+    R8G8B8A8Uint = 41,
+    R8G8B8A8Sint = 42,
+    R8G8B8A8Srgb = 43,
+    B8G8R8A8Unorm = 44,
+    B8G8R8A8Snorm = 45,
+    B8G8R8A8Uscaled = 46,
+    B8G8R8A8Sscaled = 47,
+    B8G8R8A8Uint = 48,
+    B8G8R8A8Sint = 49,
+    B8G8R8A8Srgb = 50,
+    A8B8G8R8UnormPack32 = 51,
+    A8B8G8R8SnormPack32 = 52,
+    A8B8G8R8UscaledPack32 = 53,
+    A8B8G8R8SscaledPack32 = 54,
+    A8B8G8R8UintPack32 = 55,
+    A8B8G8R8SintPack32 = 56,
+    A8B8G8R8SrgbPack32 = 57,
+    A2R10G10B10UnormPack32 = 58,
+    A2R10G10B10SnormPack32 = 59,
+    A2R10G10B10UscaledPack32 = 60,
+    A2R10G10B10SscaledPack32 = 61,
+    A2R10G10B10UintPack32 = 62,
+    A2R10G10B10SintPack32 = 63,
+    A2B10G10R10UnormPack32 = 64,
+    A2B10G10R10SnormPack32 = 65,
+    A2B10G10R10UscaledPack32 = 66,
+    A2B10G10R10SscaledPack32 = 67,
+    A2B10G10R10UintPack32 = 68,
+    A2B10G10R10SintPack32 = 69,
+    R16Unorm = 70,
+    R16Snorm = 71,
+    R16Uscaled = 72,
+    R16Sscaled = 73,
+    R16Uint = 74,
+    R16Sint = 75,
+    R16Sfloat = 76,
+    R16G16Unorm = 77,
+    R16G16Snorm = 78,
+    R16G16Uscaled = 79,
+    R16G16Sscaled = 80,
+    R16G16Uint = 81,
+    R16G16Sint = 82,
+    R16G16Sfloat = 83,
+    R16G16B16Unorm = 84,
+    R16G16B16Snorm = 85,
+    R16G16B16Uscaled = 86,
+    R16G16B16Sscaled = 87,
+    R16G16B16Uint = 88,
+    R16G16B16Sint = 89,
+    R16G16B16Sfloat = 90,
+    R16G16B16A16Unorm = 91,
+    R16G16B16A16Snorm = 92,
+    R16G16B16A16Uscaled = 93,
+    R16G16B16A16Sscaled = 94,
+    R16G16B16A16Uint = 95,
+    R16G16B16A16Sint = 96,
+    R16G16B16A16Sfloat = 97,
+    R32Uint = 98,
+    R32Sint = 99,
+    R32Sfloat = 100,
+    R32G32Uint = 101,
+    R32G32Sint = 102,
+    R32G32Sfloat = 103,
+    R32G32B32Uint = 104,
+    R32G32B32Sint = 105,
+    R32G32B32Sfloat = 106,
+    R32G32B32A32Uint = 107,
+    R32G32B32A32Sint = 108,
+    R32G32B32A32Sfloat = 109,
+    R64Uint = 110,
+    R64Sint = 111,
+    R64Sfloat = 112,
+    R64G64Uint = 113,
+    R64G64Sint = 114,
+    R64G64Sfloat = 115,
+    R64G64B64Uint = 116,
+    R64G64B64Sint = 117,
+    R64G64B64Sfloat = 118,
+    R64G64B64A64Uint = 119,
+    R64G64B64A64Sint = 120,
+    R64G64B64A64Sfloat = 121,
+    B10G11R11UfloatPack32 = 122,
+    E5B9G9R9UfloatPack32 = 123,
+    D16Unorm = 124,
+    X8D24UnormPack32 = 125,
+    D32Sfloat = 126,
+    S8Uint = 127,
+    D16UnormS8Uint = 128,
+    D24UnormS8Uint = 129,
+    D32SfloatS8Uint = 130,
+    Bc1RgbUnormBlock = 131,
+    Bc1RgbSrgbBlock = 132,
+    Bc1RgbaUnormBlock = 133,
+    Bc1RgbaSrgbBlock = 134,
+    Bc2UnormBlock = 135,
+    Bc2SrgbBlock = 136,
+    Bc3UnormBlock = 137,
+    Bc3SrgbBlock = 138,
+    Bc4UnormBlock = 139,
+    Bc4SnormBlock = 140,
+    Bc5UnormBlock = 141,
+    Bc5SnormBlock = 142,
+    Bc6HUfloatBlock = 143,
+    Bc6HSfloatBlock = 144,
+    Bc7UnormBlock = 145,
+    Bc7SrgbBlock = 146,
+    Etc2R8G8B8UnormBlock = 147,
+    Etc2R8G8B8SrgbBlock = 148,
+    Etc2R8G8B8A1UnormBlock = 149,
+    Etc2R8G8B8A1SrgbBlock = 150,
+    Etc2R8G8B8A8UnormBlock = 151,
+    Etc2R8G8B8A8SrgbBlock = 152,
+    EacR11UnormBlock = 153,
+    EacR11SnormBlock = 154,
+    EacR11G11UnormBlock = 155,
+    EacR11G11SnormBlock = 156,
+    Astc4x4UnormBlock = 157,
+    Astc4x4SrgbBlock = 158,
+    Astc5x4UnormBlock = 159,
+    Astc5x4SrgbBlock = 160,
+    Astc5x5UnormBlock = 161,
+    Astc5x5SrgbBlock = 162,
+    Astc6x5UnormBlock = 163,
+    Astc6x5SrgbBlock = 164,
+    Astc6x6UnormBlock = 165,
+    Astc6x6SrgbBlock = 166,
+    Astc8x5UnormBlock = 167,
+    Astc8x5SrgbBlock = 168,
+    Astc8x6UnormBlock = 169,
+    Astc8x6SrgbBlock = 170,
+    Astc8x8UnormBlock = 171,
+    Astc8x8SrgbBlock = 172,
+    Astc10x5UnormBlock = 173,
+    Astc10x5SrgbBlock = 174,
+    Astc10x6UnormBlock = 175,
+    Astc10x6SrgbBlock = 176,
+    Astc10x8UnormBlock = 177,
+    Astc10x8SrgbBlock = 178,
+    Astc10x10UnormBlock = 179,
+    Astc10x10SrgbBlock = 180,
+    Astc12x10UnormBlock = 181,
+    Astc12x10SrgbBlock = 182,
+    Astc12x12UnormBlock = 183,
+    Astc12x12SrgbBlock = 184
+});
+
+impl Format {
+    // The size in bytes of one texel block.
+    pub fn block_size(self) -> u32 {
+        match self {
+            Format::R4G4UnormPack8 => 1,
+            Format::R4G4B4A4UnormPack16 => 2,
+            Format::B4G4R4A4UnormPack16 => 2,
+            Format::R5G6B5UnormPack16 => 2,
+            Format::B5G6R5UnormPack16 => 2,
+            Format::R5G5B5A1UnormPack16 => 2,
+            Format::B5G5R5A1UnormPack16 => 2,
+            Format::A1R5G5B5UnormPack16 => 2,
+            Format::R8Unorm => 1,
+            Format::R8Snorm => 1,
+            Format::R8Uscaled => 1,
+            Format::R8Sscaled => 1,
+            Format::R8Uint => 1,
+            Format::R8Sint => 1,
+            Format::R8Srgb => 1,
+            Format::R8G8Unorm => 2,
+            Format::R8G8Snorm => 2,
+            Format::R8G8Uscaled => 2,
+            Format::R8G8Sscaled => 2,
+            Format::R8G8Uint => 2,
+            Format::R8G8Sint => 2,
+            Format::R8G8Srgb => 2,
+            Format::R8G8B8Unorm => 3,
+            Format::R8G8B8Snorm => 3,
+            Format::R8G8B8Uscaled => 3,
+            Format::R8G8B8Sscaled => 3,
+            Format::R8G8B8Uint => 3,
+            Format::R8G8B8Sint => 3,
+            Format::R8G8B8Srgb => 3,
+            Format::B8G8R8Unorm => 3,
+            Format::B8G8R8Snorm => 3,
+            Format::B8G8R8Uscaled => 3,
+            Format::B8G8R8Sscaled => 3,
+            Format::B8G8R8Uint => 3,
+            Format::B8G8R8Sint => 3,
+            Format::B8G8R8Srgb => 3,
+            Format::R8G8B8A8Unorm => 4,
+            Format::R8G8B8A8Snorm => 4,
+            Format::R8G8B8A8Uscaled => 4,
+            Format::R8G8B8A8Sscaled => 4,
+            Format::R8G8B8A8Uint => 4,
+            Format::R8G8B8A8Sint => 4,
+            Format::R8G8B8A8Srgb => 4,
+            Format::B8G8R8A8Unorm => 4,
+            Format::B8G8R8A8Snorm => 4,
+            Format::B8G8R8A8Uscaled => 4,
+            Format::B8G8R8A8Sscaled => 4,
+            Format::B8G8R8A8Uint => 4,
+            Format::B8G8R8A8Sint => 4,
+            Format::B8G8R8A8Srgb => 4,
+            Format::A8B8G8R8UnormPack32 => 4,
+            Format::A8B8G8R8SnormPack32 => 4,
+            Format::A8B8G8R8UscaledPack32 => 4,
+            Format::A8B8G8R8SscaledPack32 => 4,
+            Format::A8B8G8R8UintPack32 => 4,
+            Format::A8B8G8R8SintPack32 => 4,
+            Format::A8B8G8R8SrgbPack32 => 4,
+            Format::A2R10G10B10UnormPack32 => 4,
+            Format::A2R10G10B10SnormPack32 => 4,
+            Format::A2R10G10B10UscaledPack32 => 4,
+            Format::A2R10G10B10SscaledPack32 => 4,
+            Format::A2R10G10B10UintPack32 => 4,
+            Format::A2R10G10B10SintPack32 => 4,
+            Format::A2B10G10R10UnormPack32 => 4,
+            Format::A2B10G10R10SnormPack32 => 4,
+            Format::A2B10G10R10UscaledPack32 => 4,
+            Format::A2B10G10R10SscaledPack32 => 4,
+            Format::A2B10G10R10UintPack32 => 4,
+            Format::A2B10G10R10SintPack32 => 4,
+            Format::R16Unorm => 2,
+            Format::R16Snorm => 2,
+            Format::R16Uscaled => 2,
+            Format::R16Sscaled => 2,
+            Format::R16Uint => 2,
+            Format::R16Sint => 2,
+            Format::R16Sfloat => 2,
+            Format::R16G16Unorm => 4,
+            Format::R16G16Snorm => 4,
+            Format::R16G16Uscaled => 4,
+            Format::R16G16Sscaled => 4,
+            Format::R16G16Uint => 4,
+            Format::R16G16Sint => 4,
+            Format::R16G16Sfloat => 4,
+            Format::R16G16B16Unorm => 6,
+            Format::R16G16B16Snorm => 6,
+            Format::R16G16B16Uscaled => 6,
+            Format::R16G16B16Sscaled => 6,
+            Format::R16G16B16Uint => 6,
+            Format::R16G16B16Sint => 6,
+            Format::R16G16B16Sfloat => 6,
+            Format::R16G16B16A16Unorm => 8,
+            Format::R16G16B16A16Snorm => 8,
+            Format::R16G16B16A16Uscaled => 8,
+            Format::R16G16B16A16Sscaled => 8,
+            Format::R16G16B16A16Uint => 8,
+            Format::R16G16B16A16Sint => 8,
+            Format::R16G16B16A16Sfloat => 8,
+            Format::R32Uint => 4,
+            Format::R32Sint => 4,
+            Format::R32Sfloat => 4,
+            Format::R32G32Uint => 8,
+            Format::R32G32Sint => 8,
+            Format::R32G32Sfloat => 8,
+            Format::R32G32B32Uint => 12,
+            Format::R32G32B32Sint => 12,
+            Format::R32G32B32Sfloat => 12,
+            Format::R32G32B32A32Uint => 16,
+            Format::R32G32B32A32Sint => 16,
+            Format::R32G32B32A32Sfloat => 16,
+            Format::R64Uint => 8,
+            Format::R64Sint => 8,
+            Format::R64Sfloat => 8,
+            Format::R64G64Uint => 16,
+            Format::R64G64Sint => 16,
+            Format::R64G64Sfloat => 16,
+            Format::R64G64B64Uint => 24,
+            Format::R64G64B64Sint => 24,
+            Format::R64G64B64Sfloat => 24,
+            Format::R64G64B64A64Uint => 32,
+            Format::R64G64B64A64Sint => 32,
+            Format::R64G64B64A64Sfloat => 32,
+            Format::B10G11R11UfloatPack32 => 4,
+            Format::E5B9G9R9UfloatPack32 => 4,
+            Format::D16Unorm => 2,
+            Format::X8D24UnormPack32 => 4,
+            Format::D32Sfloat => 4,
+            Format::S8Uint => 1,
+            Format::D16UnormS8Uint => 3,
+            Format::D24UnormS8Uint => 4,
+            Format::D32SfloatS8Uint => 5,
+            Format::Bc1RgbUnormBlock => 8,
+            Format::Bc1RgbSrgbBlock => 8,
+            Format::Bc1RgbaUnormBlock => 8,
+            Format::Bc1RgbaSrgbBlock => 8,
+            Format::Bc2UnormBlock => 16,
+            Format::Bc2SrgbBlock => 16,
+            Format::Bc3UnormBlock => 16,
+            Format::Bc3SrgbBlock => 16,
+            Format::Bc4UnormBlock => 8,
+            Format::Bc4SnormBlock => 8,
+            Format::Bc5UnormBlock => 16,
+            Format::Bc5SnormBlock => 16,
+            Format::Bc6HUfloatBlock => 16,
+            Format::Bc6HSfloatBlock => 16,
+            Format::Bc7UnormBlock => 16,
+            Format::Bc7SrgbBlock => 16,
+            Format::Etc2R8G8B8UnormBlock => 8,
+            Format::Etc2R8G8B8SrgbBlock => 8,
+            Format::Etc2R8G8B8A1UnormBlock => 8,
+            Format::Etc2R8G8B8A1SrgbBlock => 8,
+            Format::Etc2R8G8B8A8UnormBlock => 16,
+            Format::Etc2R8G8B8A8SrgbBlock => 16,
+            Format::EacR11UnormBlock => 8,
+            Format::EacR11SnormBlock => 8,
+            Format::EacR11G11UnormBlock => 16,
+            Format::EacR11G11SnormBlock => 16,
+            Format::Astc4x4UnormBlock => 16,
+            Format::Astc4x4SrgbBlock => 16,
+            Format::Astc5x4UnormBlock => 16,
+            Format::Astc5x4SrgbBlock => 16,
+            Format::Astc5x5UnormBlock => 16,
+            Format::Astc5x5SrgbBlock => 16,
+            Format::Astc6x5UnormBlock => 16,
+            Format::Astc6x5SrgbBlock => 16,
+            Format::Astc6x6UnormBlock => 16,
+            Format::Astc6x6SrgbBlock => 16,
+            Format::Astc8x5UnormBlock => 16,
+            Format::Astc8x5SrgbBlock => 16,
+            Format::Astc8x6UnormBlock => 16,
+            Format::Astc8x6SrgbBlock => 16,
+            Format::Astc8x8UnormBlock => 16,
+            Format::Astc8x8SrgbBlock => 16,
+            Format::Astc10x5UnormBlock => 16,
+            Format::Astc10x5SrgbBlock => 16,
+            Format::Astc10x6UnormBlock => 16,
+            Format::Astc10x6SrgbBlock => 16,
+            Format::Astc10x8UnormBlock => 16,
+            Format::Astc10x8SrgbBlock => 16,
+            Format::Astc10x10UnormBlock => 16,
+            Format::Astc10x10SrgbBlock => 16,
+            Format::Astc12x10UnormBlock => 16,
+            Format::Astc12x10SrgbBlock => 16,
+            Format::Astc12x12UnormBlock => 16,
+            Format::Astc12x12SrgbBlock => 16,
+            _ => 0
+        }
+    }
+
+    // The width, height and depth of one texel block, in texels.
+    pub fn block_extent(self) -> (u32, u32, u32) {
+        match self {
+            Format::Bc1RgbUnormBlock => (4, 4, 1),
+            Format::Bc1RgbSrgbBlock => (4, 4, 1),
+            Format::Bc1RgbaUnormBlock => (4, 4, 1),
+            Format::Bc1RgbaSrgbBlock => (4, 4, 1),
+            Format::Bc2UnormBlock => (4, 4, 1),
+            Format::Bc2SrgbBlock => (4, 4, 1),
+            Format::Bc3UnormBlock => (4, 4, 1),
+            Format::Bc3SrgbBlock => (4, 4, 1),
+            Format::Bc4UnormBlock => (4, 4, 1),
+            Format::Bc4SnormBlock => (4, 4, 1),
+            Format::Bc5UnormBlock => (4, 4, 1),
+            Format::Bc5SnormBlock => (4, 4, 1),
+            Format::Bc6HUfloatBlock => (4, 4, 1),
+            Format::Bc6HSfloatBlock => (4, 4, 1),
+            Format::Bc7UnormBlock => (4, 4, 1),
+            Format::Bc7SrgbBlock => (4, 4, 1),
+            Format::Etc2R8G8B8UnormBlock => (4, 4, 1),
+            Format::Etc2R8G8B8SrgbBlock => (4, 4, 1),
+            Format::Etc2R8G8B8A1UnormBlock => (4, 4, 1),
+            Format::Etc2R8G8B8A1SrgbBlock => (4, 4, 1),
+            Format::Etc2R8G8B8A8UnormBlock => (4, 4, 1),
+            Format::Etc2R8G8B8A8SrgbBlock => (4, 4, 1),
+            Format::EacR11UnormBlock => (4, 4, 1),
+            Format::EacR11SnormBlock => (4, 4, 1),
+            Format::EacR11G11UnormBlock => (4, 4, 1),
+            Format::EacR11G11SnormBlock => (4, 4, 1),
+            Format::Astc4x4UnormBlock => (4, 4, 1),
+            Format::Astc4x4SrgbBlock => (4, 4, 1),
+            Format::Astc5x4UnormBlock => (5, 4, 1),
+            Format::Astc5x4SrgbBlock => (5, 4, 1),
+            Format::Astc5x5UnormBlock => (5, 5, 1),
+            Format::Astc5x5SrgbBlock => (5, 5, 1),
+            Format::Astc6x5UnormBlock => (6, 5, 1),
+            Format::Astc6x5SrgbBlock => (6, 5, 1),
+            Format::Astc6x6UnormBlock => (6, 6, 1),
+            Format::Astc6x6SrgbBlock => (6, 6, 1),
+            Format::Astc8x5UnormBlock => (8, 5, 1),
+            Format::Astc8x5SrgbBlock => (8, 5, 1),
+            Format::Astc8x6UnormBlock => (8, 6, 1),
+            Format::Astc8x6SrgbBlock => (8, 6, 1),
+            Format::Astc8x8UnormBlock => (8, 8, 1),
+            Format::Astc8x8SrgbBlock => (8, 8, 1),
+            Format::Astc10x5UnormBlock => (10, 5, 1),
+            Format::Astc10x5SrgbBlock => (10, 5, 1),
+            Format::Astc10x6UnormBlock => (10, 6, 1),
+            Format::Astc10x6SrgbBlock => (10, 6, 1),
+            Format::Astc10x8UnormBlock => (10, 8, 1),
+            Format::Astc10x8SrgbBlock => (10, 8, 1),
+            Format::Astc10x10UnormBlock => (10, 10, 1),
+            Format::Astc10x10SrgbBlock => (10, 10, 1),
+            Format::Astc12x10UnormBlock => (12, 10, 1),
+            Format::Astc12x10SrgbBlock => (12, 10, 1),
+            Format::Astc12x12UnormBlock => (12, 12, 1),
+            Format::Astc12x12SrgbBlock => (12, 12, 1),
+            _ => (1, 1, 1)
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageType {
+    Type1D = 0,
+    Type2D = 1,
+    Type3D = 2
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageTiling {
+    Optimal = 0,
+    Linear = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhysicalDeviceType {
+    Other = 0,
+    IntegratedGpu = 1,
+    DiscreteGpu = 2,
+    VirtualGpu = 3,
+    Cpu = 4
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QueryType {
+    Occlusion = 0,
+    PipelineStatistics = 1,
+    Timestamp = 2
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SharingMode {
+    Exclusive = 0,
+    Concurrent = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageLayout {
+    Undefined = 0,
+    General = 1,
+    ColorAttachmentOptimal = 2,
+    DepthStencilAttachmentOptimal = 3,
+    DepthStencilReadOnlyOptimal = 4,
+    ShaderReadOnlyOptimal = 5,
+    TransferSrcOptimal = 6,
+    TransferDstOptimal = 7,
+    Preinitialized = 8,
+    PresentSrcKHR = 1_000_001_002
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageViewType {
+    Type1D = 0,
+    Type2D = 1,
+    Type3D = 2,
+    Cube = 3,
+    Type1DArray = 4,
+    Type2DArray = 5,
+    CubeArray = 6
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComponentSwizzle {
+    Identity = 0,
+    Zero = 1,
+    One = 2,
+    R = 3,
+    G = 4,
+    B = 5,
+    A = 6
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VertexInputRate {
+    Vertex = 0,
+    Instance = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrimitiveTopology {
+    PointList = 0,
+    LineList = 1,
+    LineStrip = 2,
+    TriangleList = 3,
+    TriangleStrip = 4,
+    TriangleFan = 5,
+    LineListWithAdjacency = 6,
+    LineStripWithAdjacency = 7,
+    TriangleListWithAdjacency = 8,
+    TriangleStripWithAdjacency = 9,
+    PatchList = 10
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolygonMode {
+    Fill = 0,
+    Line = 1,
+    Point = 2
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrontFace {
+    CounterClockwise = 0,
+    Clockwise = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Never = 0,
+    Less = 1,
+    Equal = 2,
+    LessOrEqual = 3,
+    Greater = 4,
+    NotEqual = 5,
+    GreaterOrEqual = 6,
+    Always = 7
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StencilOp {
+    Keep = 0,
+    Zero = 1,
+    Replace = 2,
+    IncrementAndClamp = 3,
+    DecrementAndClamp = 4,
+    Invert = 5,
+    IncrementAndWrap = 6,
+    DecrementAndWrap = 7
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogicOp {
+    Clear = 0,
+    And = 1,
+    AndReverse = 2,
+    Copy = 3,
+    AndInverted = 4,
+    NoOp = 5,
+    Xor = 6,
+    Or = 7,
+    Nor = 8,
+    Equivalent = 9,
+    Invert = 10,
+    OrReverse = 11,
+    CopyInverted = 12,
+    OrInverted = 13,
+    Nand = 14,
+    Set = 15
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlendFactor {
+    Zero = 0,
+    One = 1,
+    SrcColor = 2,
+    OneMinusSrcColor = 3,
+    DstColor = 4,
+    OneMinusDstColor = 5,
+    SrcAlpha = 6,
+    OneMinusSrcAlpha = 7,
+    DstAlpha = 8,
+    OneMinusDstAlpha = 9,
+    ConstantColor = 10,
+    OneMinusConstantColor = 11,
+    ConstantAlpha = 12,
+    OneMinusConstantAlpha = 13,
+    SrcAlphaSaturate = 14,
+    Src1Color = 15,
+    OneMinusSrc1Color = 16,
+    Src1Alpha = 17,
+    OneMinusSrc1Alpha = 18
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlendOp {
+    Add = 0,
+    Subtract = 1,
+    ReverseSubtract = 2,
+    Min = 3,
+    Max = 4
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DynamicState {
+    Viewport = 0,
+    Scissor = 1,
+    LineWidth = 2,
+    DepthBias = 3,
+    BlendConstants = 4,
+    DepthBounds = 5,
+    StencilCompareMask = 6,
+    StencilWriteMask = 7,
+    StencilReference = 8
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Filter {
+    Nearest = 0,
+    Linear = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SamplerMipmapMode {
+    Nearest = 0,
+    Linear = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SamplerAddressMode {
+    Repeat = 0,
+    MirroredRepeat = 1,
+    ClampToEdge = 2,
+    ClampToBorder = 3,
+    MirrorClampToEdge = 4
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BorderColor {
+    FloatTransparentBlack = 0,
+    IntTransparentBlack = 1,
+    FloatOpaqueBlack = 2,
+    IntOpaqueBlack = 3,
+    FloatOpaqueWhite = 4,
+    IntOpaqueWhite = 5
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DescriptorType {
+    Sampler = 0,
+    CombinedImageSampler = 1,
+    SampledImage = 2,
+    StorageImage = 3,
+    UniformTexelBuffer = 4,
+    StorageTexelBuffer = 5,
+    UniformBuffer = 6,
+    StorageBuffer = 7,
+    UniformBufferDynamic = 8,
+    StorageBufferDynamic = 9,
+    InputAttachment = 10
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttachmentLoadOp {
+    Load = 0,
+    Clear = 1,
+    DontCare = 2
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttachmentStoreOp {
+    Store = 0,
+    DontCare = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PipelineBindPoint {
+    Graphics = 0,
+    Compute = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CommandBufferLevel {
+    Primary = 0,
+    Secondary = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IndexType {
+    Uint16 = 0,
+    Uint32 = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SubpassContents {
+    Inline = 0,
+    SecondaryCommandBuffers = 1
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ObjectType {
+    Unknown = 0,
+    Instance = 1,
+    PhysicalDevice = 2,
+    Device = 3,
+    Queue = 4,
+    Semaphore = 5,
+    CommandBuffer = 6,
+    Fence = 7,
+    DeviceMemory = 8,
+    Buffer = 9,
+    Image = 10,
+    Event = 11,
+    QueryPool = 12,
+    BufferView = 13,
+    ImageView = 14,
+    ShaderModule = 15,
+    PipelineCache = 16,
+    PipelineLayout = 17,
+    RenderPass = 18,
+    Pipeline = 19,
+    DescriptorSetLayout = 20,
+    Sampler = 21,
+    DescriptorPool = 22,
+    DescriptorSet = 23,
+    Framebuffer = 24,
+    CommandPool = 25,
+    SurfaceKHR = 1_000_000_000,
+    SwapchainKHR = 1_000_001_000,
+    DebugReportCallbackExt = 1_000_011_000,
+    DebugUtilsMessengerExt = 1_000_128_000
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PresentModeKHR {
+    Immediate = 0,
+    Mailbox = 1,
+    Fifo = 2,
+    FifoRelaxed = 3,
+    SharedDemandRefresh = 1_000_111_000,
+    SharedContinuousRefresh = 1_000_111_001
+}
+
+open_enum!(ColorSpaceKHR {
+    SrgbNonlinear = 0
+});
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DebugReportObjectTypeEXT {
+    Unknown = 0,
+    Instance = 1,
+    PhysicalDevice = 2,
+    Device = 3,
+    Queue = 4,
+    Semaphore = 5,
+    CommandBuffer = 6,
+    Fence = 7,
+    DeviceMemory = 8,
+    Buffer = 9,
+    Image = 10,
+    Event = 11,
+    QueryPool = 12,
+    BufferView = 13,
+    ImageView = 14,
+    ShaderModule = 15,
+    PipelineCache = 16,
+    PipelineLayout = 17,
+    RenderPass = 18,
+    Pipeline = 19,
+    DescriptorSetLayout = 20,
+    Sampler = 21,
+    DescriptorPool = 22,
+    DescriptorSet = 23,
+    Framebuffer = 24,
+    CommandPool = 25,
+    SurfaceKHR = 26,
+    SwapchainKHR = 27,
+    DebugReportCallbackExt = 28,
+    DisplayKHR = 29,
+    DisplayModeKHR = 30,
+    ValidationCacheExt = 33
+}
+
+bitmask!(InstanceCreateFlags {});
+
+bitmask!(FormatFeatureFlags {
+    SampledImage = 0x00000001,
+    StorageImage = 0x00000002,
+    StorageImageAtomic = 0x00000004,
+    UniformTexelBuffer = 0x00000008,
+    StorageTexelBuffer = 0x00000010,
+    StorageTexelBufferAtomic = 0x00000020,
+    VertexBuffer = 0x00000040,
+    ColorAttachment = 0x00000080,
+    ColorAttachmentBlend = 0x00000100,
+    DepthStencilAttachment = 0x00000200,
+    BlitSrc = 0x00000400,
+    BlitDst = 0x00000800,
+    SampledImageFilterLinear = 0x00001000
+});
+
+bitmask!(ImageCreateFlags {
+    SparseBinding = 0x01,
+    SparseResidency = 0x02,
+    SparseAliased = 0x04,
+    MutableFormat = 0x08,
+    CubeCompatible = 0x10
+});
+
+bitmask!(ImageUsageFlags {
+    TransferSrc = 0x01,
+    TransferDst = 0x02,
+    Sampled = 0x04,
+    Storage = 0x08,
+    ColorAttachment = 0x10,
+    DepthStencilAttachment = 0x20,
+    TransientAttachment = 0x40,
+    InputAttachment = 0x80
+});
+
+bitmask!(SampleCountFlags {
+    Count1 = 0x01,
+    Count2 = 0x02,
+    Count4 = 0x04,
+    Count8 = 0x08,
+    Count16 = 0x10,
+    Count32 = 0x20,
+    Count64 = 0x40
+});
+
+bitmask!(QueueFlags {
+    Graphics = 0x01,
+    Compute = 0x02,
+    Transfer = 0x04,
+    SparseBinding = 0x08
+});
+
+bitmask!(MemoryPropertyFlags {
+    DeviceLocal = 0x01,
+    HostVisible = 0x02,
+    HostCoherent = 0x04,
+    HostCached = 0x08,
+    LazilyAllocated = 0x10
+});
+
+bitmask!(MemoryHeapFlags {
+    DeviceLocal = 0x01
+});
+
+bitmask!(PipelineStageFlags {
+    TopOfPipe = 0x00000001,
+    DrawIndirect = 0x00000002,
+    VertexInput = 0x00000004,
+    VertexShader = 0x00000008,
+    TessellationControlShader = 0x00000010,
+    TessellationEvaluationShader = 0x00000020,
+    GeometryShader = 0x00000040,
+    FragmentShader = 0x00000080,
+    EarlyFragmentTests = 0x00000100,
+    LateFragmentTests = 0x00000200,
+    ColorAttachmentOutput = 0x00000400,
+    ComputeShader = 0x00000800,
+    Transfer = 0x00001000,
+    BottomOfPipe = 0x00002000,
+    Host = 0x00004000,
+    AllGraphics = 0x00008000,
+    AllCommands = 0x00010000
+});
+
+bitmask!(ImageAspectFlags {
+    Color = 0x01,
+    Depth = 0x02,
+    Stencil = 0x04,
+    Metadata = 0x08
+});
+
+bitmask!(SparseImageFormatFlags {
+    SingleMiptail = 0x01,
+    AlignedMipSize = 0x02,
+    NonstandardBlockSize = 0x04
+});
+
+bitmask!(SparseMemoryBindFlags {
+    Metadata = 0x01
+});
+
+bitmask!(FenceCreateFlags {
+    Signaled = 0x01
+});
+
+bitmask!(QueryPipelineStatisticFlags {
+    InputAssemblyVertices = 0x00000001,
+    InputAssemblyPrimitives = 0x00000002,
+    VertexShaderInvocations = 0x00000004,
+    GeometryShaderInvocations = 0x00000008,
+    GeometryShaderPrimitives = 0x00000010,
+    ClippingInvocations = 0x00000020,
+    ClippingPrimitives = 0x00000040,
+    FragmentShaderInvocations = 0x00000080,
+    TessellationControlShaderPatches = 0x00000100,
+    TessellationEvaluationShaderInvocations = 0x00000200,
+    ComputeShaderInvocations = 0x00000400
+});
+
+bitmask!(QueryResultFlags {
+    Result64 = 0x01,
+    Wait = 0x02,
+    WithAvailability = 0x04,
+    Partial = 0x08
+});
+
+bitmask!(BufferCreateFlags {
+    SparseBinding = 0x01,
+    SparseResidency = 0x02,
+    SparseAliased = 0x04
+});
+
+bitmask!(BufferUsageFlags {
+    TransferSrc = 0x00000001,
+    TransferDst = 0x00000002,
+    UniformTexelBuffer = 0x00000004,
+    StorageTexelBuffer = 0x00000008,
+    UniformBuffer = 0x00000010,
+    StorageBuffer = 0x00000020,
+    IndexBuffer = 0x00000040,
+    VertexBuffer = 0x00000080,
+    IndirectBuffer = 0x00000100
+});
+
+bitmask!(PipelineCreateFlags {
+    DisableOptimization = 0x01,
+    AllowDerivatives = 0x02,
+    Derivative = 0x04
+});
+
+bitmask!(ShaderStageFlags {
+    Vertex = 0x00000001,
+    TessellationControl = 0x00000002,
+    TessellationEvaluation = 0x00000004,
+    Geometry = 0x00000008,
+    Fragment = 0x00000010,
+    Compute = 0x00000020,
+    AllGraphics = 0x0000001F,
+    All = 0x7FFFFFFF
+});
+
+bitmask!(CullModeFlags {
+    Front = 0x01,
+    Back = 0x02,
+    FrontAndBack = 0x03
+});
+
+bitmask!(ColorComponentFlags {
+    R = 0x01,
+    G = 0x02,
+    B = 0x04,
+    A = 0x08
+});
+
+bitmask!(DescriptorPoolCreateFlags {
+    FreeDescriptorSet = 0x01
+});
+
+bitmask!(AttachmentDescriptionFlags {
+    MayAlias = 0x01
+});
+
+bitmask!(AccessFlags {
+    IndirectCommandRead = 0x00000001,
+    IndexRead = 0x00000002,
+    VertexAttributeRead = 0x00000004,
+    UniformRead = 0x00000008,
+    InputAttachmentRead = 0x00000010,
+    ShaderRead = 0x00000020,
+    ShaderWrite = 0x00000040,
+    ColorAttachmentRead = 0x00000080,
+    ColorAttachmentWrite = 0x00000100,
+    DepthStencilAttachmentRead = 0x00000200,
+    DepthStencilAttachmentWrite = 0x00000400,
+    TransferRead = 0x00000800,
+    TransferWrite = 0x00001000,
+    HostRead = 0x00002000,
+    HostWrite = 0x00004000,
+    MemoryRead = 0x00008000,
+    MemoryWrite = 0x00010000
+});
+
+bitmask!(DependencyFlags {
+    ByRegion = 0x01
+});
+
+bitmask!(CommandPoolCreateFlags {
+    Transient = 0x01,
+    ResetCommandBuffer = 0x02
+});
+
+bitmask!(CommandPoolResetFlags {
+    ReleaseResources = 0x01
+});
+
+bitmask!(CommandBufferUsageFlags {
+    OneTimeSubmit = 0x01,
+    RenderPassContinue = 0x02,
+    SimultaneousUse = 0x04
+});
+
+bitmask!(QueryControlFlags {
+    Precise = 0x01
+});
+
+bitmask!(CommandBufferResetFlags {
+    ReleaseResources = 0x01
+});
+
+bitmask!(StencilFaceFlags {
+    Front = 0x01,
+    Back = 0x02,
+    FrontAndBack = 0x03
+});
+
+bitmask!(DescriptorPoolResetFlags {});
+
+bitmask!(RenderPassCreateFlags {});
+
+bitmask!(SubpassDescriptionFlags {});
+
+bitmask!(FramebufferCreateFlags {});
+
+bitmask!(DeviceCreateFlags {});
+
+bitmask!(DeviceQueueCreateFlags {});
+
+bitmask!(MemoryMapFlags {});
+
+bitmask!(SemaphoreCreateFlags {});
+
+bitmask!(EventCreateFlags {});
+
+bitmask!(QueryPoolCreateFlags {});
+
+bitmask!(BufferViewCreateFlags {});
+
+bitmask!(ImageViewCreateFlags {});
+
+bitmask!(ShaderModuleCreateFlags {});
+
+bitmask!(PipelineCacheCreateFlags {});
+
+bitmask!(PipelineShaderStageCreateFlags {});
+
+bitmask!(PipelineVertexInputStateCreateFlags {});
+
+bitmask!(PipelineInputAssemblyStateCreateFlags {});
+
+bitmask!(PipelineTessellationStateCreateFlags {});
+
+bitmask!(PipelineViewportStateCreateFlags {});
+
+bitmask!(PipelineRasterizationStateCreateFlags {});
+
+bitmask!(PipelineMultisampleStateCreateFlags {});
+
+bitmask!(PipelineDepthStencilStateCreateFlags {});
+
+bitmask!(PipelineColorBlendStateCreateFlags {});
+
+bitmask!(PipelineDynamicStateCreateFlags {});
+
+bitmask!(PipelineLayoutCreateFlags {});
+
+bitmask!(SamplerCreateFlags {});
+
+bitmask!(DescriptorSetLayoutCreateFlags {});
+
+bitmask!(SurfaceTransformFlagsKHR {
+    Identity = 0x00000001,
+    Rotate90 = 0x00000002,
+    Rotate180 = 0x00000004,
+    Rotate270 = 0x00000008,
+    HorizontalMirror = 0x00000010,
+    HorizontalMirrorRotate90 = 0x00000020,
+    HorizontalMirrorRotate180 = 0x00000040,
+    HorizontalMirrorRotate270 = 0x00000080,
+    Inherit = 0x00000100
+});
+
+bitmask!(CompositeAlphaFlagsKHR {
+    Opaque = 0x01,
+    PreMultiplied = 0x02,
+    PostMultiplied = 0x04,
+    Inherit = 0x08
+});
+
+bitmask!(XlibSurfaceCreateFlagsKHR {});
+
+bitmask!(XcbSurfaceCreateFlagsKHR {});
+
+bitmask!(WaylandSurfaceCreateFlagsKHR {});
+
+bitmask!(HeadlessSurfaceCreateFlagsEXT {});
+
+bitmask!(SwapchainCreateFlagsKHR {});
+
+bitmask!(DebugReportFlagsEXT {
+    Information = 0x01,
+    Warning = 0x02,
+    PerformanceWarning = 0x04,
+    Error = 0x08,
+    Debug = 0x10
+});
+
+bitmask!(DebugUtilsMessageSeverityFlagsEXT {
+    Verbose = 0x00000001,
+    Info = 0x00000010,
+    Warning = 0x00000100,
+    Error = 0x00001000
+});
+
+bitmask!(DebugUtilsMessageTypeFlagsEXT {
+    General = 0x01,
+    Validation = 0x02,
+    Performance = 0x04
+});
+
+bitmask!(DebugUtilsMessengerCreateFlagsEXT {});
+
+bitmask!(DebugUtilsMessengerCallbackDataFlagsEXT {});
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ApplicationInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub pApplicationName: *const c_char,
+    pub applicationVersion: uint32_t,
+    pub pEngineName: *const c_char,
+    pub engineVersion: uint32_t,
+    pub apiVersion: uint32_t
+}
+
+unsafe impl VkStruct for ApplicationInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::ApplicationInfo;
+}
+
+impl Default for ApplicationInfo {
+    fn default() -> ApplicationInfo {
+        ApplicationInfo {
+            sType: ApplicationInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            pApplicationName: ptr::null(),
+            applicationVersion: 0,
+            pEngineName: ptr::null(),
+            engineVersion: 0,
+            apiVersion: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct InstanceCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: InstanceCreateFlags,
+    pub pApplicationInfo: *const ApplicationInfo,
+    pub enabledLayerCount: uint32_t,
+    pub ppEnabledLayerNames: *const *const c_char,
+    pub enabledExtensionCount: uint32_t,
+    pub ppEnabledExtensionNames: *const *const c_char
+}
+
+unsafe impl VkStruct for InstanceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::InstanceCreateInfo;
+}
+
+impl Default for InstanceCreateInfo {
+    fn default() -> InstanceCreateInfo {
+        InstanceCreateInfo {
+            sType: InstanceCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: InstanceCreateFlags::empty(),
+            pApplicationInfo: ptr::null(),
+            enabledLayerCount: 0,
+            ppEnabledLayerNames: ptr::null(),
+            enabledExtensionCount: 0,
+            ppEnabledExtensionNames: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct AllocationCallbacks {
+    pub pUserData: *mut c_void,
+    pub pfnAllocation: Option<AllocationFunction>,
+    pub pfnReallocation: Option<ReallocationFunction>,
+    pub pfnFree: Option<FreeFunction>,
+    pub pfnInternalAllocation: Option<InternalAllocationNotificationFunction>,
+    pub pfnInternalFree: Option<InternalFreeNotificationFunction>
+}
+
+impl Default for AllocationCallbacks {
+    fn default() -> AllocationCallbacks {
+        AllocationCallbacks {
+            pUserData: ptr::null_mut(),
+            pfnAllocation: None,
+            pfnReallocation: None,
+            pfnFree: None,
+            pfnInternalAllocation: None,
+            pfnInternalFree: None
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceFeatures {
+    pub robustBufferAccess: Bool32,
+    pub fullDrawIndexUint32: Bool32,
+    pub imageCubeArray: Bool32,
+    pub independentBlend: Bool32,
+    pub geometryShader: Bool32,
+    pub tessellationShader: Bool32,
+    pub sampleRateShading: Bool32,
+    pub dualSrcBlend: Bool32,
+    pub logicOp: Bool32,
+    pub multiDrawIndirect: Bool32,
+    pub drawIndirectFirstInstance: Bool32,
+    pub depthClamp: Bool32,
+    pub depthBiasClamp: Bool32,
+    pub fillModeNonSolid: Bool32,
+    pub depthBounds: Bool32,
+    pub wideLines: Bool32,
+    pub largePoints: Bool32,
+    pub alphaToOne: Bool32,
+    pub multiViewport: Bool32,
+    pub samplerAnisotropy: Bool32,
+    pub textureCompressionETC2: Bool32,
+    pub textureCompressionASTC_LDR: Bool32,
+    pub textureCompressionBC: Bool32,
+    pub occlusionQueryPrecise: Bool32,
+    pub pipelineStatisticsQuery: Bool32,
+    pub vertexPipelineStoresAndAtomics: Bool32,
+    pub fragmentStoresAndAtomics: Bool32,
+    pub shaderTessellationAndGeometryPointSize: Bool32,
+    pub shaderImageGatherExtended: Bool32,
+    pub shaderStorageImageExtendedFormats: Bool32,
+    pub shaderStorageImageMultisample: Bool32,
+    pub shaderStorageImageReadWithoutFormat: Bool32,
+    pub shaderStorageImageWriteWithoutFormat: Bool32,
+    pub shaderUniformBufferArrayDynamicIndexing: Bool32,
+    pub shaderSampledImageArrayDynamicIndexing: Bool32,
+    pub shaderStorageBufferArrayDynamicIndexing: Bool32,
+    pub shaderStorageImageArrayDynamicIndexing: Bool32,
+    pub shaderClipDistance: Bool32,
+    pub shaderCullDistance: Bool32,
+    pub shaderFloat64: Bool32,
+    pub shaderInt64: Bool32,
+    pub shaderInt16: Bool32,
+    pub shaderResourceResidency: Bool32,
+    pub shaderResourceMinLod: Bool32,
+    pub sparseBinding: Bool32,
+    pub sparseResidencyBuffer: Bool32,
+    pub sparseResidencyImage2D: Bool32,
+    pub sparseResidencyImage3D: Bool32,
+    pub sparseResidency2Samples: Bool32,
+    pub sparseResidency4Samples: Bool32,
+    pub sparseResidency8Samples: Bool32,
+    pub sparseResidency16Samples: Bool32,
+    pub sparseResidencyAliased: Bool32,
+    pub variableMultisampleRate: Bool32,
+    pub inheritedQueries: Bool32
+}
+
+impl Default for PhysicalDeviceFeatures {
+    fn default() -> PhysicalDeviceFeatures {
+        PhysicalDeviceFeatures {
+            robustBufferAccess: 0,
+            fullDrawIndexUint32: 0,
+            imageCubeArray: 0,
+            independentBlend: 0,
+            geometryShader: 0,
+            tessellationShader: 0,
+            sampleRateShading: 0,
+            dualSrcBlend: 0,
+            logicOp: 0,
+            multiDrawIndirect: 0,
+            drawIndirectFirstInstance: 0,
+            depthClamp: 0,
+            depthBiasClamp: 0,
+            fillModeNonSolid: 0,
+            depthBounds: 0,
+            wideLines: 0,
+            largePoints: 0,
+            alphaToOne: 0,
+            multiViewport: 0,
+            samplerAnisotropy: 0,
+            textureCompressionETC2: 0,
+            textureCompressionASTC_LDR: 0,
+            textureCompressionBC: 0,
+            occlusionQueryPrecise: 0,
+            pipelineStatisticsQuery: 0,
+            vertexPipelineStoresAndAtomics: 0,
+            fragmentStoresAndAtomics: 0,
+            shaderTessellationAndGeometryPointSize: 0,
+            shaderImageGatherExtended: 0,
+            shaderStorageImageExtendedFormats: 0,
+            shaderStorageImageMultisample: 0,
+            shaderStorageImageReadWithoutFormat: 0,
+            shaderStorageImageWriteWithoutFormat: 0,
+            shaderUniformBufferArrayDynamicIndexing: 0,
+            shaderSampledImageArrayDynamicIndexing: 0,
+            shaderStorageBufferArrayDynamicIndexing: 0,
+            shaderStorageImageArrayDynamicIndexing: 0,
+            shaderClipDistance: 0,
+            shaderCullDistance: 0,
+            shaderFloat64: 0,
+            shaderInt64: 0,
+            shaderInt16: 0,
+            shaderResourceResidency: 0,
+            shaderResourceMinLod: 0,
+            sparseBinding: 0,
+            sparseResidencyBuffer: 0,
+            sparseResidencyImage2D: 0,
+            sparseResidencyImage3D: 0,
+            sparseResidency2Samples: 0,
+            sparseResidency4Samples: 0,
+            sparseResidency8Samples: 0,
+            sparseResidency16Samples: 0,
+            sparseResidencyAliased: 0,
+            variableMultisampleRate: 0,
+            inheritedQueries: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct FormatProperties {
+    pub linearTilingFeatures: FormatFeatureFlags,
+    pub optimalTilingFeatures: FormatFeatureFlags,
+    pub bufferFeatures: FormatFeatureFlags
+}
+
+impl Default for FormatProperties {
+    fn default() -> FormatProperties {
+        FormatProperties {
+            linearTilingFeatures: FormatFeatureFlags::empty(),
+            optimalTilingFeatures: FormatFeatureFlags::empty(),
+            bufferFeatures: FormatFeatureFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Extent3D {
+    pub width: uint32_t,
+    pub height: uint32_t,
+    pub depth: uint32_t
+}
+
+impl Default for Extent3D {
+    fn default() -> Extent3D {
+        Extent3D {
+            width: 0,
+            height: 0,
+            depth: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageFormatProperties {
+    pub maxExtent: Extent3D,
+    pub maxMipLevels: uint32_t,
+    pub maxArrayLayers: uint32_t,
+    pub sampleCounts: SampleCountFlags,
+    pub maxResourceSize: DeviceSize
+}
+
+impl Default for ImageFormatProperties {
+    fn default() -> ImageFormatProperties {
+        ImageFormatProperties {
+            maxExtent: Extent3D{..Default::default()},
+            maxMipLevels: 0,
+            maxArrayLayers: 0,
+            sampleCounts: SampleCountFlags::empty(),
+            maxResourceSize: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceLimits {
+    pub maxImageDimension1D: uint32_t,
+    pub maxImageDimension2D: uint32_t,
+    pub maxImageDimension3D: uint32_t,
+    pub maxImageDimensionCube: uint32_t,
+    pub maxImageArrayLayers: uint32_t,
+    pub maxTexelBufferElements: uint32_t,
+    pub maxUniformBufferRange: uint32_t,
+    pub maxStorageBufferRange: uint32_t,
+    pub maxPushConstantsSize: uint32_t,
+    pub maxMemoryAllocationCount: uint32_t,
+    pub maxSamplerAllocationCount: uint32_t,
+    pub bufferImageGranularity: DeviceSize,
+    pub sparseAddressSpaceSize: DeviceSize,
+    pub maxBoundDescriptorSets: uint32_t,
+    pub maxPerStageDescriptorSamplers: uint32_t,
+    pub maxPerStageDescriptorUniformBuffers: uint32_t,
+    pub maxPerStageDescriptorStorageBuffers: uint32_t,
+    pub maxPerStageDescriptorSampledImages: uint32_t,
+    pub maxPerStageDescriptorStorageImages: uint32_t,
+    pub maxPerStageDescriptorInputAttachments: uint32_t,
+    pub maxPerStageResources: uint32_t,
+    pub maxDescriptorSetSamplers: uint32_t,
+    pub maxDescriptorSetUniformBuffers: uint32_t,
+    pub maxDescriptorSetUniformBuffersDynamic: uint32_t,
+    pub maxDescriptorSetStorageBuffers: uint32_t,
+    pub maxDescriptorSetStorageBuffersDynamic: uint32_t,
+    pub maxDescriptorSetSampledImages: uint32_t,
+    pub maxDescriptorSetStorageImages: uint32_t,
+    pub maxDescriptorSetInputAttachments: uint32_t,
+    pub maxVertexInputAttributes: uint32_t,
+    pub maxVertexInputBindings: uint32_t,
+    pub maxVertexInputAttributeOffset: uint32_t,
+    pub maxVertexInputBindingStride: uint32_t,
+    pub maxVertexOutputComponents: uint32_t,
+    pub maxTessellationGenerationLevel: uint32_t,
+    pub maxTessellationPatchSize: uint32_t,
+    pub maxTessellationControlPerVertexInputComponents: uint32_t,
+    pub maxTessellationControlPerVertexOutputComponents: uint32_t,
+    pub maxTessellationControlPerPatchOutputComponents: uint32_t,
+    pub maxTessellationControlTotalOutputComponents: uint32_t,
+    pub maxTessellationEvaluationInputComponents: uint32_t,
+    pub maxTessellationEvaluationOutputComponents: uint32_t,
+    pub maxGeometryShaderInvocations: uint32_t,
+    pub maxGeometryInputComponents: uint32_t,
+    pub maxGeometryOutputComponents: uint32_t,
+    pub maxGeometryOutputVertices: uint32_t,
+    pub maxGeometryTotalOutputComponents: uint32_t,
+    pub maxFragmentInputComponents: uint32_t,
+    pub maxFragmentOutputAttachments: uint32_t,
+    pub maxFragmentDualSrcAttachments: uint32_t,
+    pub maxFragmentCombinedOutputResources: uint32_t,
+    pub maxComputeSharedMemorySize: uint32_t,
+    pub maxComputeWorkGroupCount: [uint32_t; 3],
+    pub maxComputeWorkGroupInvocations: uint32_t,
+    pub maxComputeWorkGroupSize: [uint32_t; 3],
+    pub subPixelPrecisionBits: uint32_t,
+    pub subTexelPrecisionBits: uint32_t,
+    pub mipmapPrecisionBits: uint32_t,
+    pub maxDrawIndexedIndexValue: uint32_t,
+    pub maxDrawIndirectCount: uint32_t,
+    pub maxSamplerLodBias: c_float,
+    pub maxSamplerAnisotropy: c_float,
+    pub maxViewports: uint32_t,
+    pub maxViewportDimensions: [uint32_t; 2],
+    pub viewportBoundsRange: [c_float; 2],
+    pub viewportSubPixelBits: uint32_t,
+    pub minMemoryMapAlignment: size_t,
+    pub minTexelBufferOffsetAlignment: DeviceSize,
+    pub minUniformBufferOffsetAlignment: DeviceSize,
+    pub minStorageBufferOffsetAlignment: DeviceSize,
+    pub minTexelOffset: int32_t,
+    pub maxTexelOffset: uint32_t,
+    pub minTexelGatherOffset: int32_t,
+    pub maxTexelGatherOffset: uint32_t,
+    pub minInterpolationOffset: c_float,
+    pub maxInterpolationOffset: c_float,
+    pub subPixelInterpolationOffsetBits: uint32_t,
+    pub maxFramebufferWidth: uint32_t,
+    pub maxFramebufferHeight: uint32_t,
+    pub maxFramebufferLayers: uint32_t,
+    pub framebufferColorSampleCounts: SampleCountFlags,
+    pub framebufferDepthSampleCounts: SampleCountFlags,
+    pub framebufferStencilSampleCounts: SampleCountFlags,
+    pub framebufferNoAttachmentsSampleCounts: SampleCountFlags,
+    pub maxColorAttachments: uint32_t,
+    pub sampledImageColorSampleCounts: SampleCountFlags,
+    pub sampledImageIntegerSampleCounts: SampleCountFlags,
+    pub sampledImageDepthSampleCounts: SampleCountFlags,
+    pub sampledImageStencilSampleCounts: SampleCountFlags,
+    pub storageImageSampleCounts: SampleCountFlags,
+    pub maxSampleMaskWords: uint32_t,
+    pub timestampComputeAndGraphics: Bool32,
+    pub timestampPeriod: c_float,
+    pub maxClipDistances: uint32_t,
+    pub maxCullDistances: uint32_t,
+    pub maxCombinedClipAndCullDistances: uint32_t,
+    pub discreteQueuePriorities: uint32_t,
+    pub pointSizeRange: [c_float; 2],
+    pub lineWidthRange: [c_float; 2],
+    pub pointSizeGranularity: c_float,
+    pub lineWidthGranularity: c_float,
+    pub strictLines: Bool32,
+    pub standardSampleLocations: Bool32,
+    pub optimalBufferCopyOffsetAlignment: DeviceSize,
+    pub optimalBufferCopyRowPitchAlignment: DeviceSize,
+    pub nonCoherentAtomSize: DeviceSize
+}
+
+impl Default for PhysicalDeviceLimits {
+    fn default() -> PhysicalDeviceLimits {
+        PhysicalDeviceLimits {
+            maxImageDimension1D: 0,
+            maxImageDimension2D: 0,
+            maxImageDimension3D: 0,
+            maxImageDimensionCube: 0,
+            maxImageArrayLayers: 0,
+            maxTexelBufferElements: 0,
+            maxUniformBufferRange: 0,
+            maxStorageBufferRange: 0,
+            maxPushConstantsSize: 0,
+            maxMemoryAllocationCount: 0,
+            maxSamplerAllocationCount: 0,
+            bufferImageGranularity: 0,
+            sparseAddressSpaceSize: 0,
+            maxBoundDescriptorSets: 0,
+            maxPerStageDescriptorSamplers: 0,
+            maxPerStageDescriptorUniformBuffers: 0,
+            maxPerStageDescriptorStorageBuffers: 0,
+            maxPerStageDescriptorSampledImages: 0,
+            maxPerStageDescriptorStorageImages: 0,
+            maxPerStageDescriptorInputAttachments: 0,
+            maxPerStageResources: 0,
+            maxDescriptorSetSamplers: 0,
+            maxDescriptorSetUniformBuffers: 0,
+            maxDescriptorSetUniformBuffersDynamic: 0,
+            maxDescriptorSetStorageBuffers: 0,
+            maxDescriptorSetStorageBuffersDynamic: 0,
+            maxDescriptorSetSampledImages: 0,
+            maxDescriptorSetStorageImages: 0,
+            maxDescriptorSetInputAttachments: 0,
+            maxVertexInputAttributes: 0,
+            maxVertexInputBindings: 0,
+            maxVertexInputAttributeOffset: 0,
+            maxVertexInputBindingStride: 0,
+            maxVertexOutputComponents: 0,
+            maxTessellationGenerationLevel: 0,
+            maxTessellationPatchSize: 0,
+            maxTessellationControlPerVertexInputComponents: 0,
+            maxTessellationControlPerVertexOutputComponents: 0,
+            maxTessellationControlPerPatchOutputComponents: 0,
+            maxTessellationControlTotalOutputComponents: 0,
+            maxTessellationEvaluationInputComponents: 0,
+            maxTessellationEvaluationOutputComponents: 0,
+            maxGeometryShaderInvocations: 0,
+            maxGeometryInputComponents: 0,
+            maxGeometryOutputComponents: 0,
+            maxGeometryOutputVertices: 0,
+            maxGeometryTotalOutputComponents: 0,
+            maxFragmentInputComponents: 0,
+            maxFragmentOutputAttachments: 0,
+            maxFragmentDualSrcAttachments: 0,
+            maxFragmentCombinedOutputResources: 0,
+            maxComputeSharedMemorySize: 0,
+            maxComputeWorkGroupCount: [0; 3],
+            maxComputeWorkGroupInvocations: 0,
+            maxComputeWorkGroupSize: [0; 3],
+            subPixelPrecisionBits: 0,
+            subTexelPrecisionBits: 0,
+            mipmapPrecisionBits: 0,
+            maxDrawIndexedIndexValue: 0,
+            maxDrawIndirectCount: 0,
+            maxSamplerLodBias: 0.0,
+            maxSamplerAnisotropy: 0.0,
+            maxViewports: 0,
+            maxViewportDimensions: [0; 2],
+            viewportBoundsRange: [0.0; 2],
+            viewportSubPixelBits: 0,
+            minMemoryMapAlignment: 0,
+            minTexelBufferOffsetAlignment: 0,
+            minUniformBufferOffsetAlignment: 0,
+            minStorageBufferOffsetAlignment: 0,
+            minTexelOffset: 0,
+            maxTexelOffset: 0,
+            minTexelGatherOffset: 0,
+            maxTexelGatherOffset: 0,
+            minInterpolationOffset: 0.0,
+            maxInterpolationOffset: 0.0,
+            subPixelInterpolationOffsetBits: 0,
+            maxFramebufferWidth: 0,
+            maxFramebufferHeight: 0,
+            maxFramebufferLayers: 0,
+            framebufferColorSampleCounts: SampleCountFlags::empty(),
+            framebufferDepthSampleCounts: SampleCountFlags::empty(),
+            framebufferStencilSampleCounts: SampleCountFlags::empty(),
+            framebufferNoAttachmentsSampleCounts: SampleCountFlags::empty(),
+            maxColorAttachments: 0,
+            sampledImageColorSampleCounts: SampleCountFlags::empty(),
+            sampledImageIntegerSampleCounts: SampleCountFlags::empty(),
+            sampledImageDepthSampleCounts: SampleCountFlags::empty(),
+            sampledImageStencilSampleCounts: SampleCountFlags::empty(),
+            storageImageSampleCounts: SampleCountFlags::empty(),
+            maxSampleMaskWords: 0,
+            timestampComputeAndGraphics: 0,
+            timestampPeriod: 0.0,
+            maxClipDistances: 0,
+            maxCullDistances: 0,
+            maxCombinedClipAndCullDistances: 0,
+            discreteQueuePriorities: 0,
+            pointSizeRange: [0.0; 2],
+            lineWidthRange: [0.0; 2],
+            pointSizeGranularity: 0.0,
+            lineWidthGranularity: 0.0,
+            strictLines: 0,
+            standardSampleLocations: 0,
+            optimalBufferCopyOffsetAlignment: 0,
+            optimalBufferCopyRowPitchAlignment: 0,
+            nonCoherentAtomSize: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSparseProperties {
+    pub residencyStandard2DBlockShape: Bool32,
+    pub residencyStandard2DMultisampleBlockShape: Bool32,
+    pub residencyStandard3DBlockShape: Bool32,
+    pub residencyAlignedMipSize: Bool32,
+    pub residencyNonResidentStrict: Bool32
+}
+
+impl Default for PhysicalDeviceSparseProperties {
+    fn default() -> PhysicalDeviceSparseProperties {
+        PhysicalDeviceSparseProperties {
+            residencyStandard2DBlockShape: 0,
+            residencyStandard2DMultisampleBlockShape: 0,
+            residencyStandard3DBlockShape: 0,
+            residencyAlignedMipSize: 0,
+            residencyNonResidentStrict: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceProperties {
+    pub apiVersion: uint32_t,
+    pub driverVersion: uint32_t,
+    pub vendorID: uint32_t,
+    pub deviceID: uint32_t,
+    pub deviceType: PhysicalDeviceType,
+    pub deviceName: [c_char; VK_MAX_PHYSICAL_DEVICE_NAME_SIZE],
+    pub pipelineCacheUUID: [uint8_t; VK_UUID_SIZE],
+    pub limits: PhysicalDeviceLimits,
+    pub sparseProperties: PhysicalDeviceSparseProperties
+}
+
+impl Default for PhysicalDeviceProperties {
+    fn default() -> PhysicalDeviceProperties {
+        PhysicalDeviceProperties {
+            apiVersion: 0,
+            driverVersion: 0,
+            vendorID: 0,
+            deviceID: 0,
+            deviceType: PhysicalDeviceType::Other,
+            deviceName: [0; VK_MAX_PHYSICAL_DEVICE_NAME_SIZE],
+            pipelineCacheUUID: [0; VK_UUID_SIZE],
+            limits: PhysicalDeviceLimits{..Default::default()},
+            sparseProperties: PhysicalDeviceSparseProperties{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct QueueFamilyProperties {
+    pub queueFlags: QueueFlags,
+    pub queueCount: uint32_t,
+    pub timestampValidBits: uint32_t,
+    pub minImageTransferGranularity: Extent3D
+}
+
+impl Default for QueueFamilyProperties {
+    fn default() -> QueueFamilyProperties {
+        QueueFamilyProperties {
+            queueFlags: QueueFlags::empty(),
+            queueCount: 0,
+            timestampValidBits: 0,
+            minImageTransferGranularity: Extent3D{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MemoryType {
+    pub propertyFlags: MemoryPropertyFlags,
+    pub heapIndex: uint32_t
+}
+
+impl Default for MemoryType {
+    fn default() -> MemoryType {
+        MemoryType {
+            propertyFlags: MemoryPropertyFlags::empty(),
+            heapIndex: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MemoryHeap {
+    pub size: DeviceSize,
+    pub flags: MemoryHeapFlags
+}
+
+impl Default for MemoryHeap {
+    fn default() -> MemoryHeap {
+        MemoryHeap {
+            size: 0,
+            flags: MemoryHeapFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMemoryProperties {
+    pub memoryTypeCount: uint32_t,
+    pub memoryTypes: [MemoryType; VK_MAX_MEMORY_TYPES],
+    pub memoryHeapCount: uint32_t,
+    pub memoryHeaps: [MemoryHeap; VK_MAX_MEMORY_HEAPS]
+}
+
+impl Default for PhysicalDeviceMemoryProperties {
+    fn default() -> PhysicalDeviceMemoryProperties {
+        PhysicalDeviceMemoryProperties {
+            memoryTypeCount: 0,
+            memoryTypes: [MemoryType{..Default::default()}; VK_MAX_MEMORY_TYPES],
+            memoryHeapCount: 0,
+            memoryHeaps: [MemoryHeap{..Default::default()}; VK_MAX_MEMORY_HEAPS]
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DeviceQueueCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DeviceQueueCreateFlags,
+    pub queueFamilyIndex: uint32_t,
+    pub queueCount: uint32_t,
+    pub pQueuePriorities: *const c_float
+}
+
+unsafe impl VkStruct for DeviceQueueCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DeviceQueueCreateInfo;
+}
+
+impl Default for DeviceQueueCreateInfo {
+    fn default() -> DeviceQueueCreateInfo {
+        DeviceQueueCreateInfo {
+            sType: DeviceQueueCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DeviceQueueCreateFlags::empty(),
+            queueFamilyIndex: 0,
+            queueCount: 0,
+            pQueuePriorities: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DeviceCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DeviceCreateFlags,
+    pub queueCreateInfoCount: uint32_t,
+    pub pQueueCreateInfos: *const DeviceQueueCreateInfo,
+    pub enabledLayerCount: uint32_t,
+    pub ppEnabledLayerNames: *const *const c_char,
+    pub enabledExtensionCount: uint32_t,
+    pub ppEnabledExtensionNames: *const *const c_char,
+    pub pEnabledFeatures: *const PhysicalDeviceFeatures
+}
+
+unsafe impl VkStruct for DeviceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DeviceCreateInfo;
+}
+
+impl Default for DeviceCreateInfo {
+    fn default() -> DeviceCreateInfo {
+        DeviceCreateInfo {
+            sType: DeviceCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DeviceCreateFlags::empty(),
+            queueCreateInfoCount: 0,
+            pQueueCreateInfos: ptr::null(),
+            enabledLayerCount: 0,
+            ppEnabledLayerNames: ptr::null(),
+            enabledExtensionCount: 0,
+            ppEnabledExtensionNames: ptr::null(),
+            pEnabledFeatures: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ExtensionProperties {
+    pub extensionName: [c_char; VK_MAX_EXTENSION_NAME_SIZE],
+    pub specVersion: uint32_t
+}
+
+impl Default for ExtensionProperties {
+    fn default() -> ExtensionProperties {
+        ExtensionProperties {
+            extensionName: [0; VK_MAX_EXTENSION_NAME_SIZE],
+            specVersion: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct LayerProperties {
+    pub layerName: [c_char; VK_MAX_EXTENSION_NAME_SIZE],
+    pub specVersion: uint32_t,
+    pub implementationVersion: uint32_t,
+    pub description: [c_char; VK_MAX_DESCRIPTION_SIZE]
+}
+
+impl Default for LayerProperties {
+    fn default() -> LayerProperties {
+        LayerProperties {
+            layerName: [0; VK_MAX_EXTENSION_NAME_SIZE],
+            specVersion: 0,
+            implementationVersion: 0,
+            description: [0; VK_MAX_DESCRIPTION_SIZE]
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub waitSemaphoreCount: uint32_t,
+    pub pWaitSemaphores: *const Semaphore,
+    pub pWaitDstStageMask: *const PipelineStageFlags,
+    pub commandBufferCount: uint32_t,
+    pub pCommandBuffers: *const CommandBuffer,
+    pub signalSemaphoreCount: uint32_t,
+    pub pSignalSemaphores: *const Semaphore
+}
+
+unsafe impl VkStruct for SubmitInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SubmitInfo;
+}
+
+impl Default for SubmitInfo {
+    fn default() -> SubmitInfo {
+        SubmitInfo {
+            sType: SubmitInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            waitSemaphoreCount: 0,
+            pWaitSemaphores: ptr::null(),
+            pWaitDstStageMask: ptr::null(),
+            commandBufferCount: 0,
+            pCommandBuffers: ptr::null(),
+            signalSemaphoreCount: 0,
+            pSignalSemaphores: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MemoryAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub allocationSize: DeviceSize,
+    pub memoryTypeIndex: uint32_t
+}
+
+unsafe impl VkStruct for MemoryAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::MemoryAllocateInfo;
+}
+
+impl Default for MemoryAllocateInfo {
+    fn default() -> MemoryAllocateInfo {
+        MemoryAllocateInfo {
+            sType: MemoryAllocateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            allocationSize: 0,
+            memoryTypeIndex: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MappedMemoryRange {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub memory: DeviceMemory,
+    pub offset: DeviceSize,
+    pub size: DeviceSize
+}
+
+unsafe impl VkStruct for MappedMemoryRange {
+    const STRUCTURE_TYPE: StructureType = StructureType::MappedMemoryRange;
+}
+
+impl Default for MappedMemoryRange {
+    fn default() -> MappedMemoryRange {
+        MappedMemoryRange {
+            sType: MappedMemoryRange::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            memory: DeviceMemory::null(),
+            offset: 0,
+            size: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MemoryRequirements {
+    pub size: DeviceSize,
+    pub alignment: DeviceSize,
+    pub memoryTypeBits: uint32_t
+}
+
+impl Default for MemoryRequirements {
+    fn default() -> MemoryRequirements {
+        MemoryRequirements {
+            size: 0,
+            alignment: 0,
+            memoryTypeBits: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseImageFormatProperties {
+    pub aspectMask: ImageAspectFlags,
+    pub imageGranularity: Extent3D,
+    pub flags: SparseImageFormatFlags
+}
+
+impl Default for SparseImageFormatProperties {
+    fn default() -> SparseImageFormatProperties {
+        SparseImageFormatProperties {
+            aspectMask: ImageAspectFlags::empty(),
+            imageGranularity: Extent3D{..Default::default()},
+            flags: SparseImageFormatFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseImageMemoryRequirements {
+    pub formatProperties: SparseImageFormatProperties,
+    pub imageMipTailFirstLod: uint32_t,
+    pub imageMipTailSize: DeviceSize,
+    pub imageMipTailOffset: DeviceSize,
+    pub imageMipTailStride: DeviceSize
+}
+
+impl Default for SparseImageMemoryRequirements {
+    fn default() -> SparseImageMemoryRequirements {
+        SparseImageMemoryRequirements {
+            formatProperties: SparseImageFormatProperties{..Default::default()},
+            imageMipTailFirstLod: 0,
+            imageMipTailSize: 0,
+            imageMipTailOffset: 0,
+            imageMipTailStride: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseMemoryBind {
+    pub resourceOffset: DeviceSize,
+    pub size: DeviceSize,
+    pub memory: DeviceMemory,
+    pub memoryOffset: DeviceSize,
+    pub flags: SparseMemoryBindFlags
+}
+
+impl Default for SparseMemoryBind {
+    fn default() -> SparseMemoryBind {
+        SparseMemoryBind {
+            resourceOffset: 0,
+            size: 0,
+            memory: DeviceMemory::null(),
+            memoryOffset: 0,
+            flags: SparseMemoryBindFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseBufferMemoryBindInfo {
+    pub buffer: Buffer,
+    pub bindCount: uint32_t,
+    pub pBinds: *const SparseMemoryBind
+}
+
+impl Default for SparseBufferMemoryBindInfo {
+    fn default() -> SparseBufferMemoryBindInfo {
+        SparseBufferMemoryBindInfo {
+            buffer: Buffer::null(),
+            bindCount: 0,
+            pBinds: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseImageOpaqueMemoryBindInfo {
+    pub image: Image,
+    pub bindCount: uint32_t,
+    pub pBinds: *const SparseMemoryBind
+}
+
+impl Default for SparseImageOpaqueMemoryBindInfo {
+    fn default() -> SparseImageOpaqueMemoryBindInfo {
+        SparseImageOpaqueMemoryBindInfo {
+            image: Image::null(),
+            bindCount: 0,
+            pBinds: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageSubresource {
+    pub aspectMask: ImageAspectFlags,
+    pub mipLevel: uint32_t,
+    pub arrayLayer: uint32_t
+}
+
+impl Default for ImageSubresource {
+    fn default() -> ImageSubresource {
+        ImageSubresource {
+            aspectMask: ImageAspectFlags::empty(),
+            mipLevel: 0,
+            arrayLayer: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Offset3D {
+    pub x: int32_t,
+    pub y: int32_t,
+    pub z: int32_t
+}
+
+impl Default for Offset3D {
+    fn default() -> Offset3D {
+        Offset3D {
+            x: 0,
+            y: 0,
+            z: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseImageMemoryBind {
+    pub subresource: ImageSubresource,
+    pub offset: Offset3D,
+    pub extent: Extent3D,
+    pub memory: DeviceMemory,
+    pub memoryOffset: DeviceSize,
+    pub flags: SparseMemoryBindFlags
+}
+
+impl Default for SparseImageMemoryBind {
+    fn default() -> SparseImageMemoryBind {
+        SparseImageMemoryBind {
+            subresource: ImageSubresource{..Default::default()},
+            offset: Offset3D{..Default::default()},
+            extent: Extent3D{..Default::default()},
+            memory: DeviceMemory::null(),
+            memoryOffset: 0,
+            flags: SparseMemoryBindFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SparseImageMemoryBindInfo {
+    pub image: Image,
+    pub bindCount: uint32_t,
+    pub pBinds: *const SparseImageMemoryBind
+}
+
+impl Default for SparseImageMemoryBindInfo {
+    fn default() -> SparseImageMemoryBindInfo {
+        SparseImageMemoryBindInfo {
+            image: Image::null(),
+            bindCount: 0,
+            pBinds: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BindSparseInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub waitSemaphoreCount: uint32_t,
+    pub pWaitSemaphores: *const Semaphore,
+    pub bufferBindCount: uint32_t,
+    pub pBufferBinds: *const SparseBufferMemoryBindInfo,
+    pub imageOpaqueBindCount: uint32_t,
+    pub pImageOpaqueBinds: *const SparseImageOpaqueMemoryBindInfo,
+    pub imageBindCount: uint32_t,
+    pub pImageBinds: *const SparseImageMemoryBindInfo,
+    pub signalSemaphoreCount: uint32_t,
+    pub pSignalSemaphores: *const Semaphore
+}
+
+unsafe impl VkStruct for BindSparseInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BindSparseInfo;
+}
+
+impl Default for BindSparseInfo {
+    fn default() -> BindSparseInfo {
+        BindSparseInfo {
+            sType: BindSparseInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            waitSemaphoreCount: 0,
+            pWaitSemaphores: ptr::null(),
+            bufferBindCount: 0,
+            pBufferBinds: ptr::null(),
+            imageOpaqueBindCount: 0,
+            pImageOpaqueBinds: ptr::null(),
+            imageBindCount: 0,
+            pImageBinds: ptr::null(),
+            signalSemaphoreCount: 0,
+            pSignalSemaphores: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct FenceCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: FenceCreateFlags
+}
+
+unsafe impl VkStruct for FenceCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FenceCreateInfo;
+}
+
+impl Default for FenceCreateInfo {
+    fn default() -> FenceCreateInfo {
+        FenceCreateInfo {
+            sType: FenceCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: FenceCreateFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SemaphoreCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: SemaphoreCreateFlags
+}
+
+unsafe impl VkStruct for SemaphoreCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SemaphoreCreateInfo;
+}
+
+impl Default for SemaphoreCreateInfo {
+    fn default() -> SemaphoreCreateInfo {
+        SemaphoreCreateInfo {
+            sType: SemaphoreCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: SemaphoreCreateFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct EventCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: EventCreateFlags
+}
+
+unsafe impl VkStruct for EventCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::EventCreateInfo;
+}
+
+impl Default for EventCreateInfo {
+    fn default() -> EventCreateInfo {
+        EventCreateInfo {
+            sType: EventCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: EventCreateFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct QueryPoolCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: QueryPoolCreateFlags,
+    pub queryType: QueryType,
+    pub queryCount: uint32_t,
+    pub pipelineStatistics: QueryPipelineStatisticFlags
+}
+
+unsafe impl VkStruct for QueryPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::QueryPoolCreateInfo;
+}
+
+impl Default for QueryPoolCreateInfo {
+    fn default() -> QueryPoolCreateInfo {
+        QueryPoolCreateInfo {
+            sType: QueryPoolCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: QueryPoolCreateFlags::empty(),
+            queryType: QueryType::Occlusion,
+            queryCount: 0,
+            pipelineStatistics: QueryPipelineStatisticFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BufferCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: BufferCreateFlags,
+    pub size: DeviceSize,
+    pub usage: BufferUsageFlags,
+    pub sharingMode: SharingMode,
+    pub queueFamilyIndexCount: uint32_t,
+    pub pQueueFamilyIndices: *const uint32_t
+}
+
+unsafe impl VkStruct for BufferCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BufferCreateInfo;
+}
+
+impl Default for BufferCreateInfo {
+    fn default() -> BufferCreateInfo {
+        BufferCreateInfo {
+            sType: BufferCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: BufferCreateFlags::empty(),
+            size: 0,
+            usage: BufferUsageFlags::empty(),
+            sharingMode: SharingMode::Exclusive,
+            queueFamilyIndexCount: 0,
+            pQueueFamilyIndices: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BufferViewCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: BufferViewCreateFlags,
+    pub buffer: Buffer,
+    pub format: Format,
+    pub offset: DeviceSize,
+    pub range: DeviceSize
+}
+
+unsafe impl VkStruct for BufferViewCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::BufferViewCreateInfo;
+}
+
+impl Default for BufferViewCreateInfo {
+    fn default() -> BufferViewCreateInfo {
+        BufferViewCreateInfo {
+            sType: BufferViewCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: BufferViewCreateFlags::empty(),
+            buffer: Buffer::null(),
+            format: Format::Undefined,
+            offset: 0,
+            range: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: ImageCreateFlags,
+    pub imageType: ImageType,
+    pub format: Format,
+    pub extent: Extent3D,
+    pub mipLevels: uint32_t,
+    pub arrayLayers: uint32_t,
+    pub samples: SampleCountFlags,
+    pub tiling: ImageTiling,
+    pub usage: ImageUsageFlags,
+    pub sharingMode: SharingMode,
+    pub queueFamilyIndexCount: uint32_t,
+    pub pQueueFamilyIndices: *const uint32_t,
+    pub initialLayout: ImageLayout
+}
+
+unsafe impl VkStruct for ImageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::ImageCreateInfo;
+}
+
+impl Default for ImageCreateInfo {
+    fn default() -> ImageCreateInfo {
+        ImageCreateInfo {
+            sType: ImageCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: ImageCreateFlags::empty(),
+            imageType: ImageType::Type1D,
+            format: Format::Undefined,
+            extent: Extent3D{..Default::default()},
+            mipLevels: 0,
+            arrayLayers: 0,
+            samples: SampleCountFlags::empty(),
+            tiling: ImageTiling::Optimal,
+            usage: ImageUsageFlags::empty(),
+            sharingMode: SharingMode::Exclusive,
+            queueFamilyIndexCount: 0,
+            pQueueFamilyIndices: ptr::null(),
+            initialLayout: ImageLayout::Undefined
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SubresourceLayout {
+    pub offset: DeviceSize,
+    pub size: DeviceSize,
+    pub rowPitch: DeviceSize,
+    pub arrayPitch: DeviceSize,
+    pub depthPitch: DeviceSize
+}
+
+impl Default for SubresourceLayout {
+    fn default() -> SubresourceLayout {
+        SubresourceLayout {
+            offset: 0,
+            size: 0,
+            rowPitch: 0,
+            arrayPitch: 0,
+            depthPitch: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ComponentMapping {
+    pub r: ComponentSwizzle,
+    pub g: ComponentSwizzle,
+    pub b: ComponentSwizzle,
+    pub a: ComponentSwizzle
+}
+
+impl Default for ComponentMapping {
+    fn default() -> ComponentMapping {
+        ComponentMapping {
+            r: ComponentSwizzle::Identity,
+            g: ComponentSwizzle::Identity,
+            b: ComponentSwizzle::Identity,
+            a: ComponentSwizzle::Identity
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageSubresourceRange {
+    pub aspectMask: ImageAspectFlags,
+    pub baseMipLevel: uint32_t,
+    pub levelCount: uint32_t,
+    pub baseArrayLayer: uint32_t,
+    pub layerCount: uint32_t
+}
+
+impl Default for ImageSubresourceRange {
+    fn default() -> ImageSubresourceRange {
+        ImageSubresourceRange {
+            aspectMask: ImageAspectFlags::empty(),
+            baseMipLevel: 0,
+            levelCount: 0,
+            baseArrayLayer: 0,
+            layerCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageViewCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: ImageViewCreateFlags,
+    pub image: Image,
+    pub viewType: ImageViewType,
+    pub format: Format,
+    pub components: ComponentMapping,
+    pub subresourceRange: ImageSubresourceRange
+}
+
+unsafe impl VkStruct for ImageViewCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::ImageViewCreateInfo;
+}
+
+impl Default for ImageViewCreateInfo {
+    fn default() -> ImageViewCreateInfo {
+        ImageViewCreateInfo {
+            sType: ImageViewCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: ImageViewCreateFlags::empty(),
+            image: Image::null(),
+            viewType: ImageViewType::Type1D,
+            format: Format::Undefined,
+            components: ComponentMapping{..Default::default()},
+            subresourceRange: ImageSubresourceRange{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ShaderModuleCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: ShaderModuleCreateFlags,
+    pub codeSize: size_t,
+    pub pCode: *const uint32_t
+}
+
+unsafe impl VkStruct for ShaderModuleCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::ShaderModuleCreateInfo;
+}
+
+impl Default for ShaderModuleCreateInfo {
+    fn default() -> ShaderModuleCreateInfo {
+        ShaderModuleCreateInfo {
+            sType: ShaderModuleCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: ShaderModuleCreateFlags::empty(),
+            codeSize: 0,
+            pCode: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineCacheCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineCacheCreateFlags,
+    pub initialDataSize: size_t,
+    pub pInitialData: *const c_void
+}
+
+unsafe impl VkStruct for PipelineCacheCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineCacheCreateInfo;
+}
+
+impl Default for PipelineCacheCreateInfo {
+    fn default() -> PipelineCacheCreateInfo {
+        PipelineCacheCreateInfo {
+            sType: PipelineCacheCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineCacheCreateFlags::empty(),
+            initialDataSize: 0,
+            pInitialData: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SpecializationMapEntry {
+    pub constantID: uint32_t,
+    pub offset: uint32_t,
+    pub size: size_t
+}
+
+impl Default for SpecializationMapEntry {
+    fn default() -> SpecializationMapEntry {
+        SpecializationMapEntry {
+            constantID: 0,
+            offset: 0,
+            size: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SpecializationInfo {
+    pub mapEntryCount: uint32_t,
+    pub pMapEntries: *const SpecializationMapEntry,
+    pub dataSize: size_t,
+    pub pData: *const c_void
+}
+
+impl Default for SpecializationInfo {
+    fn default() -> SpecializationInfo {
+        SpecializationInfo {
+            mapEntryCount: 0,
+            pMapEntries: ptr::null(),
+            dataSize: 0,
+            pData: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineShaderStageCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineShaderStageCreateFlags,
+    pub stage: ShaderStageFlags,
+    pub module: ShaderModule,
+    pub pName: *const c_char,
+    pub pSpecializationInfo: *const SpecializationInfo
+}
+
+unsafe impl VkStruct for PipelineShaderStageCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineShaderStageCreateInfo;
+}
+
+impl Default for PipelineShaderStageCreateInfo {
+    fn default() -> PipelineShaderStageCreateInfo {
+        PipelineShaderStageCreateInfo {
+            sType: PipelineShaderStageCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineShaderStageCreateFlags::empty(),
+            stage: ShaderStageFlags::empty(),
+            module: ShaderModule::null(),
+            pName: ptr::null(),
+            pSpecializationInfo: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct VertexInputBindingDescription {
+    pub binding: uint32_t,
+    pub stride: uint32_t,
+    pub inputRate: VertexInputRate
+}
+
+impl Default for VertexInputBindingDescription {
+    fn default() -> VertexInputBindingDescription {
+        VertexInputBindingDescription {
+            binding: 0,
+            stride: 0,
+            inputRate: VertexInputRate::Vertex
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct VertexInputAttributeDescription {
+    pub location: uint32_t,
+    pub binding: uint32_t,
+    pub format: Format,
+    pub offset: uint32_t
+}
+
+impl Default for VertexInputAttributeDescription {
+    fn default() -> VertexInputAttributeDescription {
+        VertexInputAttributeDescription {
+            location: 0,
+            binding: 0,
+            format: Format::Undefined,
+            offset: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineVertexInputStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineVertexInputStateCreateFlags,
+    pub vertexBindingDescriptionCount: uint32_t,
+    pub pVertexBindingDescriptions: *const VertexInputBindingDescription,
+    pub vertexAttributeDescriptionCount: uint32_t,
+    pub pVertexAttributeDescriptions: *const VertexInputAttributeDescription
+}
+
+unsafe impl VkStruct for PipelineVertexInputStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineVertexInputStateCreateInfo;
+}
+
+impl Default for PipelineVertexInputStateCreateInfo {
+    fn default() -> PipelineVertexInputStateCreateInfo {
+        PipelineVertexInputStateCreateInfo {
+            sType: PipelineVertexInputStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineVertexInputStateCreateFlags::empty(),
+            vertexBindingDescriptionCount: 0,
+            pVertexBindingDescriptions: ptr::null(),
+            vertexAttributeDescriptionCount: 0,
+            pVertexAttributeDescriptions: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineInputAssemblyStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineInputAssemblyStateCreateFlags,
+    pub topology: PrimitiveTopology,
+    pub primitiveRestartEnable: Bool32
+}
+
+unsafe impl VkStruct for PipelineInputAssemblyStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineInputAssemblyStateCreateInfo;
+}
+
+impl Default for PipelineInputAssemblyStateCreateInfo {
+    fn default() -> PipelineInputAssemblyStateCreateInfo {
+        PipelineInputAssemblyStateCreateInfo {
+            sType: PipelineInputAssemblyStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineInputAssemblyStateCreateFlags::empty(),
+            topology: PrimitiveTopology::PointList,
+            primitiveRestartEnable: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineTessellationStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineTessellationStateCreateFlags,
+    pub patchControlPoints: uint32_t
+}
+
+unsafe impl VkStruct for PipelineTessellationStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineTessellationStateCreateInfo;
+}
+
+impl Default for PipelineTessellationStateCreateInfo {
+    fn default() -> PipelineTessellationStateCreateInfo {
+        PipelineTessellationStateCreateInfo {
+            sType: PipelineTessellationStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineTessellationStateCreateFlags::empty(),
+            patchControlPoints: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Viewport {
+    pub x: c_float,
+    pub y: c_float,
+    pub width: c_float,
+    pub height: c_float,
+    pub minDepth: c_float,
+    pub maxDepth: c_float
+}
+
+impl Default for Viewport {
+    fn default() -> Viewport {
+        Viewport {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+            minDepth: 0.0,
+            maxDepth: 0.0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Offset2D {
+    pub x: int32_t,
+    pub y: int32_t
+}
+
+impl Default for Offset2D {
+    fn default() -> Offset2D {
+        Offset2D {
+            x: 0,
+            y: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Extent2D {
+    pub width: uint32_t,
+    pub height: uint32_t
+}
+
+impl Default for Extent2D {
+    fn default() -> Extent2D {
+        Extent2D {
+            width: 0,
+            height: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct Rect2D {
+    pub offset: Offset2D,
+    pub extent: Extent2D
+}
+
+impl Default for Rect2D {
+    fn default() -> Rect2D {
+        Rect2D {
+            offset: Offset2D{..Default::default()},
+            extent: Extent2D{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineViewportStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineViewportStateCreateFlags,
+    pub viewportCount: uint32_t,
+    pub pViewports: *const Viewport,
+    pub scissorCount: uint32_t,
+    pub pScissors: *const Rect2D
+}
+
+unsafe impl VkStruct for PipelineViewportStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineViewportStateCreateInfo;
+}
+
+impl Default for PipelineViewportStateCreateInfo {
+    fn default() -> PipelineViewportStateCreateInfo {
+        PipelineViewportStateCreateInfo {
+            sType: PipelineViewportStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineViewportStateCreateFlags::empty(),
+            viewportCount: 0,
+            pViewports: ptr::null(),
+            scissorCount: 0,
+            pScissors: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineRasterizationStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineRasterizationStateCreateFlags,
+    pub depthClampEnable: Bool32,
+    pub rasterizerDiscardEnable: Bool32,
+    pub polygonMode: PolygonMode,
+    pub cullMode: CullModeFlags,
+    pub frontFace: FrontFace,
+    pub depthBiasEnable: Bool32,
+    pub depthBiasConstantFactor: c_float,
+    pub depthBiasClamp: c_float,
+    pub depthBiasSlopeFactor: c_float,
+    pub lineWidth: c_float
+}
+
+unsafe impl VkStruct for PipelineRasterizationStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineRasterizationStateCreateInfo;
+}
+
+impl Default for PipelineRasterizationStateCreateInfo {
+    fn default() -> PipelineRasterizationStateCreateInfo {
+        PipelineRasterizationStateCreateInfo {
+            sType: PipelineRasterizationStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineRasterizationStateCreateFlags::empty(),
+            depthClampEnable: 0,
+            rasterizerDiscardEnable: 0,
+            polygonMode: PolygonMode::Fill,
+            cullMode: CullModeFlags::empty(),
+            frontFace: FrontFace::CounterClockwise,
+            depthBiasEnable: 0,
+            depthBiasConstantFactor: 0.0,
+            depthBiasClamp: 0.0,
+            depthBiasSlopeFactor: 0.0,
+            lineWidth: 0.0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineMultisampleStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineMultisampleStateCreateFlags,
+    pub rasterizationSamples: SampleCountFlags,
+    pub sampleShadingEnable: Bool32,
+    pub minSampleShading: c_float,
+    pub pSampleMask: *const SampleMask,
+    pub alphaToCoverageEnable: Bool32,
+    pub alphaToOneEnable: Bool32
+}
+
+unsafe impl VkStruct for PipelineMultisampleStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineMultisampleStateCreateInfo;
+}
+
+impl Default for PipelineMultisampleStateCreateInfo {
+    fn default() -> PipelineMultisampleStateCreateInfo {
+        PipelineMultisampleStateCreateInfo {
+            sType: PipelineMultisampleStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineMultisampleStateCreateFlags::empty(),
+            rasterizationSamples: SampleCountFlags::empty(),
+            sampleShadingEnable: 0,
+            minSampleShading: 0.0,
+            pSampleMask: ptr::null(),
+            alphaToCoverageEnable: 0,
+            alphaToOneEnable: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct StencilOpState {
+    pub failOp: StencilOp,
+    pub passOp: StencilOp,
+    pub depthFailOp: StencilOp,
+    pub compareOp: CompareOp,
+    pub compareMask: uint32_t,
+    pub writeMask: uint32_t,
+    pub reference: uint32_t
+}
+
+impl Default for StencilOpState {
+    fn default() -> StencilOpState {
+        StencilOpState {
+            failOp: StencilOp::Keep,
+            passOp: StencilOp::Keep,
+            depthFailOp: StencilOp::Keep,
+            compareOp: CompareOp::Never,
+            compareMask: 0,
+            writeMask: 0,
+            reference: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineDepthStencilStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineDepthStencilStateCreateFlags,
+    pub depthTestEnable: Bool32,
+    pub depthWriteEnable: Bool32,
+    pub depthCompareOp: CompareOp,
+    pub depthBoundsTestEnable: Bool32,
+    pub stencilTestEnable: Bool32,
+    pub front: StencilOpState,
+    pub back: StencilOpState,
+    pub minDepthBounds: c_float,
+    pub maxDepthBounds: c_float
+}
+
+unsafe impl VkStruct for PipelineDepthStencilStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineDepthStencilStateCreateInfo;
+}
+
+impl Default for PipelineDepthStencilStateCreateInfo {
+    fn default() -> PipelineDepthStencilStateCreateInfo {
+        PipelineDepthStencilStateCreateInfo {
+            sType: PipelineDepthStencilStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineDepthStencilStateCreateFlags::empty(),
+            depthTestEnable: 0,
+            depthWriteEnable: 0,
+            depthCompareOp: CompareOp::Never,
+            depthBoundsTestEnable: 0,
+            stencilTestEnable: 0,
+            front: StencilOpState{..Default::default()},
+            back: StencilOpState{..Default::default()},
+            minDepthBounds: 0.0,
+            maxDepthBounds: 0.0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineColorBlendAttachmentState {
+    pub blendEnable: Bool32,
+    pub srcColorBlendFactor: BlendFactor,
+    pub dstColorBlendFactor: BlendFactor,
+    pub colorBlendOp: BlendOp,
+    pub srcAlphaBlendFactor: BlendFactor,
+    pub dstAlphaBlendFactor: BlendFactor,
+    pub alphaBlendOp: BlendOp,
+    pub colorWriteMask: ColorComponentFlags
+}
+
+impl Default for PipelineColorBlendAttachmentState {
+    fn default() -> PipelineColorBlendAttachmentState {
+        PipelineColorBlendAttachmentState {
+            blendEnable: 0,
+            srcColorBlendFactor: BlendFactor::Zero,
+            dstColorBlendFactor: BlendFactor::Zero,
+            colorBlendOp: BlendOp::Add,
+            srcAlphaBlendFactor: BlendFactor::Zero,
+            dstAlphaBlendFactor: BlendFactor::Zero,
+            alphaBlendOp: BlendOp::Add,
+            colorWriteMask: ColorComponentFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineColorBlendStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineColorBlendStateCreateFlags,
+    pub logicOpEnable: Bool32,
+    pub logicOp: LogicOp,
+    pub attachmentCount: uint32_t,
+    pub pAttachments: *const PipelineColorBlendAttachmentState,
+    pub blendConstants: [c_float; 4]
+}
+
+unsafe impl VkStruct for PipelineColorBlendStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineColorBlendStateCreateInfo;
+}
+
+impl Default for PipelineColorBlendStateCreateInfo {
+    fn default() -> PipelineColorBlendStateCreateInfo {
+        PipelineColorBlendStateCreateInfo {
+            sType: PipelineColorBlendStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineColorBlendStateCreateFlags::empty(),
+            logicOpEnable: 0,
+            logicOp: LogicOp::Clear,
+            attachmentCount: 0,
+            pAttachments: ptr::null(),
+            blendConstants: [0.0; 4]
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineDynamicStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineDynamicStateCreateFlags,
+    pub dynamicStateCount: uint32_t,
+    pub pDynamicStates: *const DynamicState
+}
+
+unsafe impl VkStruct for PipelineDynamicStateCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineDynamicStateCreateInfo;
+}
+
+impl Default for PipelineDynamicStateCreateInfo {
+    fn default() -> PipelineDynamicStateCreateInfo {
+        PipelineDynamicStateCreateInfo {
+            sType: PipelineDynamicStateCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineDynamicStateCreateFlags::empty(),
+            dynamicStateCount: 0,
+            pDynamicStates: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct GraphicsPipelineCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineCreateFlags,
+    pub stageCount: uint32_t,
+    pub pStages: *const PipelineShaderStageCreateInfo,
+    pub pVertexInputState: *const PipelineVertexInputStateCreateInfo,
+    pub pInputAssemblyState: *const PipelineInputAssemblyStateCreateInfo,
+    pub pTessellationState: *const PipelineTessellationStateCreateInfo,
+    pub pViewportState: *const PipelineViewportStateCreateInfo,
+    pub pRasterizationState: *const PipelineRasterizationStateCreateInfo,
+    pub pMultisampleState: *const PipelineMultisampleStateCreateInfo,
+    pub pDepthStencilState: *const PipelineDepthStencilStateCreateInfo,
+    pub pColorBlendState: *const PipelineColorBlendStateCreateInfo,
+    pub pDynamicState: *const PipelineDynamicStateCreateInfo,
+    pub layout: PipelineLayout,
+    pub renderPass: RenderPass,
+    pub subpass: uint32_t,
+    pub basePipelineHandle: Pipeline,
+    pub basePipelineIndex: int32_t
+}
+
+unsafe impl VkStruct for GraphicsPipelineCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::GraphicsPipelineCreateInfo;
+}
+
+impl Default for GraphicsPipelineCreateInfo {
+    fn default() -> GraphicsPipelineCreateInfo {
+        GraphicsPipelineCreateInfo {
+            sType: GraphicsPipelineCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineCreateFlags::empty(),
+            stageCount: 0,
+            pStages: ptr::null(),
+            pVertexInputState: ptr::null(),
+            pInputAssemblyState: ptr::null(),
+            pTessellationState: ptr::null(),
+            pViewportState: ptr::null(),
+            pRasterizationState: ptr::null(),
+            pMultisampleState: ptr::null(),
+            pDepthStencilState: ptr::null(),
+            pColorBlendState: ptr::null(),
+            pDynamicState: ptr::null(),
+            layout: PipelineLayout::null(),
+            renderPass: RenderPass::null(),
+            subpass: 0,
+            basePipelineHandle: Pipeline::null(),
+            basePipelineIndex: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ComputePipelineCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineCreateFlags,
+    pub stage: PipelineShaderStageCreateInfo,
+    pub layout: PipelineLayout,
+    pub basePipelineHandle: Pipeline,
+    pub basePipelineIndex: int32_t
+}
+
+unsafe impl VkStruct for ComputePipelineCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::ComputePipelineCreateInfo;
+}
+
+impl Default for ComputePipelineCreateInfo {
+    fn default() -> ComputePipelineCreateInfo {
+        ComputePipelineCreateInfo {
+            sType: ComputePipelineCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineCreateFlags::empty(),
+            stage: PipelineShaderStageCreateInfo{..Default::default()},
+            layout: PipelineLayout::null(),
+            basePipelineHandle: Pipeline::null(),
+            basePipelineIndex: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PushConstantRange {
+    pub stageFlags: ShaderStageFlags,
+    pub offset: uint32_t,
+    pub size: uint32_t
+}
+
+impl Default for PushConstantRange {
+    fn default() -> PushConstantRange {
+        PushConstantRange {
+            stageFlags: ShaderStageFlags::empty(),
+            offset: 0,
+            size: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PipelineLayoutCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: PipelineLayoutCreateFlags,
+    pub setLayoutCount: uint32_t,
+    pub pSetLayouts: *const DescriptorSetLayout,
+    pub pushConstantRangeCount: uint32_t,
+    pub pPushConstantRanges: *const PushConstantRange
+}
+
+unsafe impl VkStruct for PipelineLayoutCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::PipelineLayoutCreateInfo;
+}
+
+impl Default for PipelineLayoutCreateInfo {
+    fn default() -> PipelineLayoutCreateInfo {
+        PipelineLayoutCreateInfo {
+            sType: PipelineLayoutCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: PipelineLayoutCreateFlags::empty(),
+            setLayoutCount: 0,
+            pSetLayouts: ptr::null(),
+            pushConstantRangeCount: 0,
+            pPushConstantRanges: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SamplerCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: SamplerCreateFlags,
+    pub magFilter: Filter,
+    pub minFilter: Filter,
+    pub mipmapMode: SamplerMipmapMode,
+    pub addressModeU: SamplerAddressMode,
+    pub addressModeV: SamplerAddressMode,
+    pub addressModeW: SamplerAddressMode,
+    pub mipLodBias: c_float,
+    pub anisotropyEnable: Bool32,
+    pub maxAnisotropy: c_float,
+    pub compareEnable: Bool32,
+    pub compareOp: CompareOp,
+    pub minLod: c_float,
+    pub maxLod: c_float,
+    pub borderColor: BorderColor,
+    pub unnormalizedCoordinates: Bool32
+}
+
+unsafe impl VkStruct for SamplerCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::SamplerCreateInfo;
+}
+
+impl Default for SamplerCreateInfo {
+    fn default() -> SamplerCreateInfo {
+        SamplerCreateInfo {
+            sType: SamplerCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: SamplerCreateFlags::empty(),
+            magFilter: Filter::Nearest,
+            minFilter: Filter::Nearest,
+            mipmapMode: SamplerMipmapMode::Nearest,
+            addressModeU: SamplerAddressMode::Repeat,
+            addressModeV: SamplerAddressMode::Repeat,
+            addressModeW: SamplerAddressMode::Repeat,
+            mipLodBias: 0.0,
+            anisotropyEnable: 0,
+            maxAnisotropy: 0.0,
+            compareEnable: 0,
+            compareOp: CompareOp::Never,
+            minLod: 0.0,
+            maxLod: 0.0,
+            borderColor: BorderColor::FloatTransparentBlack,
+            unnormalizedCoordinates: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetLayoutBinding {
+    pub binding: uint32_t,
+    pub descriptorType: DescriptorType,
+    pub descriptorCount: uint32_t,
+    pub stageFlags: ShaderStageFlags,
+    pub pImmutableSamplers: *const Sampler
+}
+
+impl Default for DescriptorSetLayoutBinding {
+    fn default() -> DescriptorSetLayoutBinding {
+        DescriptorSetLayoutBinding {
+            binding: 0,
+            descriptorType: DescriptorType::Sampler,
+            descriptorCount: 0,
+            stageFlags: ShaderStageFlags::empty(),
+            pImmutableSamplers: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetLayoutCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DescriptorSetLayoutCreateFlags,
+    pub bindingCount: uint32_t,
+    pub pBindings: *const DescriptorSetLayoutBinding
+}
+
+unsafe impl VkStruct for DescriptorSetLayoutCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DescriptorSetLayoutCreateInfo;
+}
+
+impl Default for DescriptorSetLayoutCreateInfo {
+    fn default() -> DescriptorSetLayoutCreateInfo {
+        DescriptorSetLayoutCreateInfo {
+            sType: DescriptorSetLayoutCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DescriptorSetLayoutCreateFlags::empty(),
+            bindingCount: 0,
+            pBindings: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorPoolSize {
+    pub descriptorType: DescriptorType,
+    pub descriptorCount: uint32_t
+}
+
+impl Default for DescriptorPoolSize {
+    fn default() -> DescriptorPoolSize {
+        DescriptorPoolSize {
+            descriptorType: DescriptorType::Sampler,
+            descriptorCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorPoolCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DescriptorPoolCreateFlags,
+    pub maxSets: uint32_t,
+    pub poolSizeCount: uint32_t,
+    pub pPoolSizes: *const DescriptorPoolSize
+}
+
+unsafe impl VkStruct for DescriptorPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DescriptorPoolCreateInfo;
+}
+
+impl Default for DescriptorPoolCreateInfo {
+    fn default() -> DescriptorPoolCreateInfo {
+        DescriptorPoolCreateInfo {
+            sType: DescriptorPoolCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DescriptorPoolCreateFlags::empty(),
+            maxSets: 0,
+            poolSizeCount: 0,
+            pPoolSizes: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub descriptorPool: DescriptorPool,
+    pub descriptorSetCount: uint32_t,
+    pub pSetLayouts: *const DescriptorSetLayout
+}
+
+unsafe impl VkStruct for DescriptorSetAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::DescriptorSetAllocateInfo;
+}
+
+impl Default for DescriptorSetAllocateInfo {
+    fn default() -> DescriptorSetAllocateInfo {
+        DescriptorSetAllocateInfo {
+            sType: DescriptorSetAllocateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            descriptorPool: DescriptorPool::null(),
+            descriptorSetCount: 0,
+            pSetLayouts: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorImageInfo {
+    pub sampler: Sampler,
+    pub imageView: ImageView,
+    pub imageLayout: ImageLayout
+}
+
+impl Default for DescriptorImageInfo {
+    fn default() -> DescriptorImageInfo {
+        DescriptorImageInfo {
+            sampler: Sampler::null(),
+            imageView: ImageView::null(),
+            imageLayout: ImageLayout::Undefined
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DescriptorBufferInfo {
+    pub buffer: Buffer,
+    pub offset: DeviceSize,
+    pub range: DeviceSize
+}
+
+impl Default for DescriptorBufferInfo {
+    fn default() -> DescriptorBufferInfo {
+        DescriptorBufferInfo {
+            buffer: Buffer::null(),
+            offset: 0,
+            range: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct WriteDescriptorSet {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub dstSet: DescriptorSet,
+    pub dstBinding: uint32_t,
+    pub dstArrayElement: uint32_t,
+    pub descriptorCount: uint32_t,
+    pub descriptorType: DescriptorType,
+    pub pImageInfo: *const DescriptorImageInfo,
+    pub pBufferInfo: *const DescriptorBufferInfo,
+    pub pTexelBufferView: *const BufferView
+}
+
+unsafe impl VkStruct for WriteDescriptorSet {
+    const STRUCTURE_TYPE: StructureType = StructureType::WriteDescriptorSet;
+}
+
+impl Default for WriteDescriptorSet {
+    fn default() -> WriteDescriptorSet {
+        WriteDescriptorSet {
+            sType: WriteDescriptorSet::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            dstSet: DescriptorSet::null(),
+            dstBinding: 0,
+            dstArrayElement: 0,
+            descriptorCount: 0,
+            descriptorType: DescriptorType::Sampler,
+            pImageInfo: ptr::null(),
+            pBufferInfo: ptr::null(),
+            pTexelBufferView: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct CopyDescriptorSet {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub srcSet: DescriptorSet,
+    pub srcBinding: uint32_t,
+    pub srcArrayElement: uint32_t,
+    pub dstSet: DescriptorSet,
+    pub dstBinding: uint32_t,
+    pub dstArrayElement: uint32_t,
+    pub descriptorCount: uint32_t
+}
+
+unsafe impl VkStruct for CopyDescriptorSet {
+    const STRUCTURE_TYPE: StructureType = StructureType::CopyDescriptorSet;
+}
+
+impl Default for CopyDescriptorSet {
+    fn default() -> CopyDescriptorSet {
+        CopyDescriptorSet {
+            sType: CopyDescriptorSet::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            srcSet: DescriptorSet::null(),
+            srcBinding: 0,
+            srcArrayElement: 0,
+            dstSet: DescriptorSet::null(),
+            dstBinding: 0,
+            dstArrayElement: 0,
+            descriptorCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct FramebufferCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: FramebufferCreateFlags,
+    pub renderPass: RenderPass,
+    pub attachmentCount: uint32_t,
+    pub pAttachments: *const ImageView,
+    pub width: uint32_t,
+    pub height: uint32_t,
+    pub layers: uint32_t
+}
+
+unsafe impl VkStruct for FramebufferCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::FramebufferCreateInfo;
+}
+
+impl Default for FramebufferCreateInfo {
+    fn default() -> FramebufferCreateInfo {
+        FramebufferCreateInfo {
+            sType: FramebufferCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: FramebufferCreateFlags::empty(),
+            renderPass: RenderPass::null(),
+            attachmentCount: 0,
+            pAttachments: ptr::null(),
+            width: 0,
+            height: 0,
+            layers: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct AttachmentDescription {
+    pub flags: AttachmentDescriptionFlags,
+    pub format: Format,
+    pub samples: SampleCountFlags,
+    pub loadOp: AttachmentLoadOp,
+    pub storeOp: AttachmentStoreOp,
+    pub stencilLoadOp: AttachmentLoadOp,
+    pub stencilStoreOp: AttachmentStoreOp,
+    pub initialLayout: ImageLayout,
+    pub finalLayout: ImageLayout
+}
+
+impl Default for AttachmentDescription {
+    fn default() -> AttachmentDescription {
+        AttachmentDescription {
+            flags: AttachmentDescriptionFlags::empty(),
+            format: Format::Undefined,
+            samples: SampleCountFlags::empty(),
+            loadOp: AttachmentLoadOp::Load,
+            storeOp: AttachmentStoreOp::Store,
+            stencilLoadOp: AttachmentLoadOp::Load,
+            stencilStoreOp: AttachmentStoreOp::Store,
+            initialLayout: ImageLayout::Undefined,
+            finalLayout: ImageLayout::Undefined
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct AttachmentReference {
+    pub attachment: uint32_t,
+    pub layout: ImageLayout
+}
+
+impl Default for AttachmentReference {
+    fn default() -> AttachmentReference {
+        AttachmentReference {
+            attachment: 0,
+            layout: ImageLayout::Undefined
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SubpassDescription {
+    pub flags: SubpassDescriptionFlags,
+    pub pipelineBindPoint: PipelineBindPoint,
+    pub inputAttachmentCount: uint32_t,
+    pub pInputAttachments: *const AttachmentReference,
+    pub colorAttachmentCount: uint32_t,
+    pub pColorAttachments: *const AttachmentReference,
+    pub pResolveAttachments: *const AttachmentReference,
+    pub pDepthStencilAttachment: *const AttachmentReference,
+    pub preserveAttachmentCount: uint32_t,
+    pub pPreserveAttachments: *const uint32_t
+}
+
+impl Default for SubpassDescription {
+    fn default() -> SubpassDescription {
+        SubpassDescription {
+            flags: SubpassDescriptionFlags::empty(),
+            pipelineBindPoint: PipelineBindPoint::Graphics,
+            inputAttachmentCount: 0,
+            pInputAttachments: ptr::null(),
+            colorAttachmentCount: 0,
+            pColorAttachments: ptr::null(),
+            pResolveAttachments: ptr::null(),
+            pDepthStencilAttachment: ptr::null(),
+            preserveAttachmentCount: 0,
+            pPreserveAttachments: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SubpassDependency {
+    pub srcSubpass: uint32_t,
+    pub dstSubpass: uint32_t,
+    pub srcStageMask: PipelineStageFlags,
+    pub dstStageMask: PipelineStageFlags,
+    pub srcAccessMask: AccessFlags,
+    pub dstAccessMask: AccessFlags,
+    pub dependencyFlags: DependencyFlags
+}
+
+impl Default for SubpassDependency {
+    fn default() -> SubpassDependency {
+        SubpassDependency {
+            srcSubpass: 0,
+            dstSubpass: 0,
+            srcStageMask: PipelineStageFlags::empty(),
+            dstStageMask: PipelineStageFlags::empty(),
+            srcAccessMask: AccessFlags::empty(),
+            dstAccessMask: AccessFlags::empty(),
+            dependencyFlags: DependencyFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct RenderPassCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: RenderPassCreateFlags,
+    pub attachmentCount: uint32_t,
+    pub pAttachments: *const AttachmentDescription,
+    pub subpassCount: uint32_t,
+    pub pSubpasses: *const SubpassDescription,
+    pub dependencyCount: uint32_t,
+    pub pDependencies: *const SubpassDependency
+}
+
+unsafe impl VkStruct for RenderPassCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RenderPassCreateInfo;
+}
+
+impl Default for RenderPassCreateInfo {
+    fn default() -> RenderPassCreateInfo {
+        RenderPassCreateInfo {
+            sType: RenderPassCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: RenderPassCreateFlags::empty(),
+            attachmentCount: 0,
+            pAttachments: ptr::null(),
+            subpassCount: 0,
+            pSubpasses: ptr::null(),
+            dependencyCount: 0,
+            pDependencies: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct CommandPoolCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: CommandPoolCreateFlags,
+    pub queueFamilyIndex: uint32_t
+}
+
+unsafe impl VkStruct for CommandPoolCreateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::CommandPoolCreateInfo;
+}
+
+impl Default for CommandPoolCreateInfo {
+    fn default() -> CommandPoolCreateInfo {
+        CommandPoolCreateInfo {
+            sType: CommandPoolCreateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: CommandPoolCreateFlags::empty(),
+            queueFamilyIndex: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct CommandBufferAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub commandPool: CommandPool,
+    pub level: CommandBufferLevel,
+    pub commandBufferCount: uint32_t
+}
+
+unsafe impl VkStruct for CommandBufferAllocateInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::CommandBufferAllocateInfo;
+}
+
+impl Default for CommandBufferAllocateInfo {
+    fn default() -> CommandBufferAllocateInfo {
+        CommandBufferAllocateInfo {
+            sType: CommandBufferAllocateInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            commandPool: CommandPool::null(),
+            level: CommandBufferLevel::Primary,
+            commandBufferCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct CommandBufferInheritanceInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub renderPass: RenderPass,
+    pub subpass: uint32_t,
+    pub framebuffer: Framebuffer,
+    pub occlusionQueryEnable: Bool32,
+    pub queryFlags: QueryControlFlags,
+    pub pipelineStatistics: QueryPipelineStatisticFlags
+}
+
+unsafe impl VkStruct for CommandBufferInheritanceInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::CommandBufferInheritanceInfo;
+}
+
+impl Default for CommandBufferInheritanceInfo {
+    fn default() -> CommandBufferInheritanceInfo {
+        CommandBufferInheritanceInfo {
+            sType: CommandBufferInheritanceInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            renderPass: RenderPass::null(),
+            subpass: 0,
+            framebuffer: Framebuffer::null(),
+            occlusionQueryEnable: 0,
+            queryFlags: QueryControlFlags::empty(),
+            pipelineStatistics: QueryPipelineStatisticFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct CommandBufferBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: CommandBufferUsageFlags,
+    pub pInheritanceInfo: *const CommandBufferInheritanceInfo
+}
+
+unsafe impl VkStruct for CommandBufferBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::CommandBufferBeginInfo;
+}
+
+impl Default for CommandBufferBeginInfo {
+    fn default() -> CommandBufferBeginInfo {
+        CommandBufferBeginInfo {
+            sType: CommandBufferBeginInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: CommandBufferUsageFlags::empty(),
+            pInheritanceInfo: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BufferCopy {
+    pub srcOffset: DeviceSize,
+    pub dstOffset: DeviceSize,
+    pub size: DeviceSize
+}
+
+impl Default for BufferCopy {
+    fn default() -> BufferCopy {
+        BufferCopy {
+            srcOffset: 0,
+            dstOffset: 0,
+            size: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageSubresourceLayers {
+    pub aspectMask: ImageAspectFlags,
+    pub mipLevel: uint32_t,
+    pub baseArrayLayer: uint32_t,
+    pub layerCount: uint32_t
+}
+
+impl Default for ImageSubresourceLayers {
+    fn default() -> ImageSubresourceLayers {
+        ImageSubresourceLayers {
+            aspectMask: ImageAspectFlags::empty(),
+            mipLevel: 0,
+            baseArrayLayer: 0,
+            layerCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageCopy {
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffset: Offset3D,
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffset: Offset3D,
+    pub extent: Extent3D
+}
+
+impl Default for ImageCopy {
+    fn default() -> ImageCopy {
+        ImageCopy {
+            srcSubresource: ImageSubresourceLayers{..Default::default()},
+            srcOffset: Offset3D{..Default::default()},
+            dstSubresource: ImageSubresourceLayers{..Default::default()},
+            dstOffset: Offset3D{..Default::default()},
+            extent: Extent3D{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageBlit {
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffsets: [Offset3D; 2],
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffsets: [Offset3D; 2]
+}
+
+impl Default for ImageBlit {
+    fn default() -> ImageBlit {
+        ImageBlit {
+            srcSubresource: ImageSubresourceLayers{..Default::default()},
+            srcOffsets: [Offset3D{..Default::default()}; 2],
+            dstSubresource: ImageSubresourceLayers{..Default::default()},
+            dstOffsets: [Offset3D{..Default::default()}; 2]
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BufferImageCopy {
+    pub bufferOffset: DeviceSize,
+    pub bufferRowLength: uint32_t,
+    pub bufferImageHeight: uint32_t,
+    pub imageSubresource: ImageSubresourceLayers,
+    pub imageOffset: Offset3D,
+    pub imageExtent: Extent3D
+}
+
+impl Default for BufferImageCopy {
+    fn default() -> BufferImageCopy {
+        BufferImageCopy {
+            bufferOffset: 0,
+            bufferRowLength: 0,
+            bufferImageHeight: 0,
+            imageSubresource: ImageSubresourceLayers{..Default::default()},
+            imageOffset: Offset3D{..Default::default()},
+            imageExtent: Extent3D{..Default::default()}
+        }
+    }
+}
+
+// Which member is read depends on the format of the image being cleared.
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub union ClearColorValue {
+    pub float32: [c_float; 4],
+    pub int32: [int32_t; 4],
+    pub uint32: [uint32_t; 4]
+}
+
+impl Default for ClearColorValue {
+    fn default() -> ClearColorValue {
+        ClearColorValue { float32: [0.0; 4] }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ClearDepthStencilValue {
+    pub depth: c_float,
+    pub stencil: uint32_t
+}
+
+impl Default for ClearDepthStencilValue {
+    fn default() -> ClearDepthStencilValue {
+        ClearDepthStencilValue {
+            depth: 0.0,
+            stencil: 0
+        }
+    }
+}
+
+// Color attachments read `color`, depth/stencil attachments read `depthStencil`.
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub union ClearValue {
+    pub color: ClearColorValue,
+    pub depthStencil: ClearDepthStencilValue
+}
+
+impl Default for ClearValue {
+    fn default() -> ClearValue {
+        ClearValue { color: ClearColorValue::default() }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ClearAttachment {
+    pub aspectMask: ImageAspectFlags,
+    pub colorAttachment: uint32_t,
+    pub clearValue: ClearValue
+}
+
+impl Default for ClearAttachment {
+    fn default() -> ClearAttachment {
+        ClearAttachment {
+            aspectMask: ImageAspectFlags::empty(),
+            colorAttachment: 0,
+            clearValue: ClearValue::default()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ClearRect {
+    pub rect: Rect2D,
+    pub baseArrayLayer: uint32_t,
+    pub layerCount: uint32_t
+}
+
+impl Default for ClearRect {
+    fn default() -> ClearRect {
+        ClearRect {
+            rect: Rect2D{..Default::default()},
+            baseArrayLayer: 0,
+            layerCount: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageResolve {
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffset: Offset3D,
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffset: Offset3D,
+    pub extent: Extent3D
+}
+
+impl Default for ImageResolve {
+    fn default() -> ImageResolve {
+        ImageResolve {
+            srcSubresource: ImageSubresourceLayers{..Default::default()},
+            srcOffset: Offset3D{..Default::default()},
+            dstSubresource: ImageSubresourceLayers{..Default::default()},
+            dstOffset: Offset3D{..Default::default()},
+            extent: Extent3D{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct MemoryBarrier {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub srcAccessMask: AccessFlags,
+    pub dstAccessMask: AccessFlags
+}
+
+unsafe impl VkStruct for MemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::MemoryBarrier;
+}
+
+impl Default for MemoryBarrier {
+    fn default() -> MemoryBarrier {
+        MemoryBarrier {
+            sType: MemoryBarrier::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            srcAccessMask: AccessFlags::empty(),
+            dstAccessMask: AccessFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct BufferMemoryBarrier {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub srcAccessMask: AccessFlags,
+    pub dstAccessMask: AccessFlags,
+    pub srcQueueFamilyIndex: uint32_t,
+    pub dstQueueFamilyIndex: uint32_t,
+    pub buffer: Buffer,
+    pub offset: DeviceSize,
+    pub size: DeviceSize
+}
+
+unsafe impl VkStruct for BufferMemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::BufferMemoryBarrier;
+}
+
+impl Default for BufferMemoryBarrier {
+    fn default() -> BufferMemoryBarrier {
+        BufferMemoryBarrier {
+            sType: BufferMemoryBarrier::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            srcAccessMask: AccessFlags::empty(),
+            dstAccessMask: AccessFlags::empty(),
+            srcQueueFamilyIndex: 0,
+            dstQueueFamilyIndex: 0,
+            buffer: Buffer::null(),
+            offset: 0,
+            size: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct ImageMemoryBarrier {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub srcAccessMask: AccessFlags,
+    pub dstAccessMask: AccessFlags,
+    pub oldLayout: ImageLayout,
+    pub newLayout: ImageLayout,
+    pub srcQueueFamilyIndex: uint32_t,
+    pub dstQueueFamilyIndex: uint32_t,
+    pub image: Image,
+    pub subresourceRange: ImageSubresourceRange
+}
+
+unsafe impl VkStruct for ImageMemoryBarrier {
+    const STRUCTURE_TYPE: StructureType = StructureType::ImageMemoryBarrier;
+}
+
+impl Default for ImageMemoryBarrier {
+    fn default() -> ImageMemoryBarrier {
+        ImageMemoryBarrier {
+            sType: ImageMemoryBarrier::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            srcAccessMask: AccessFlags::empty(),
+            dstAccessMask: AccessFlags::empty(),
+            oldLayout: ImageLayout::Undefined,
+            newLayout: ImageLayout::Undefined,
+            srcQueueFamilyIndex: 0,
+            dstQueueFamilyIndex: 0,
+            image: Image::null(),
+            subresourceRange: ImageSubresourceRange{..Default::default()}
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct RenderPassBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub renderPass: RenderPass,
+    pub framebuffer: Framebuffer,
+    pub renderArea: Rect2D,
+    pub clearValueCount: uint32_t,
+    pub pClearValues: *const ClearValue
+}
+
+unsafe impl VkStruct for RenderPassBeginInfo {
+    const STRUCTURE_TYPE: StructureType = StructureType::RenderPassBeginInfo;
+}
+
+impl Default for RenderPassBeginInfo {
+    fn default() -> RenderPassBeginInfo {
+        RenderPassBeginInfo {
+            sType: RenderPassBeginInfo::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            renderPass: RenderPass::null(),
+            framebuffer: Framebuffer::null(),
+            renderArea: Rect2D{..Default::default()},
+            clearValueCount: 0,
+            pClearValues: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DispatchIndirectCommand {
+    pub x: uint32_t,
+    pub y: uint32_t,
+    pub z: uint32_t
+}
+
+impl Default for DispatchIndirectCommand {
+    fn default() -> DispatchIndirectCommand {
+        DispatchIndirectCommand {
+            x: 0,
+            y: 0,
+            z: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DrawIndexedIndirectCommand {
+    pub indexCount: uint32_t,
+    pub instanceCount: uint32_t,
+    pub firstIndex: uint32_t,
+    pub vertexOffset: int32_t,
+    pub firstInstance: uint32_t
+}
+
+impl Default for DrawIndexedIndirectCommand {
+    fn default() -> DrawIndexedIndirectCommand {
+        DrawIndexedIndirectCommand {
+            indexCount: 0,
+            instanceCount: 0,
+            firstIndex: 0,
+            vertexOffset: 0,
+            firstInstance: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DrawIndirectCommand {
+    pub vertexCount: uint32_t,
+    pub instanceCount: uint32_t,
+    pub firstVertex: uint32_t,
+    pub firstInstance: uint32_t
+}
+
+impl Default for DrawIndirectCommand {
+    fn default() -> DrawIndirectCommand {
+        DrawIndirectCommand {
+            vertexCount: 0,
+            instanceCount: 0,
+            firstVertex: 0,
+            firstInstance: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SurfaceCapabilitiesKHR {
+    pub minImageCount: uint32_t,
+    pub maxImageCount: uint32_t,
+    pub currentExtent: Extent2D,
+    pub minImageExtent: Extent2D,
+    pub maxImageExtent: Extent2D,
+    pub maxImageArrayLayers: uint32_t,
+    pub supportedTransforms: SurfaceTransformFlagsKHR,
+    pub currentTransform: SurfaceTransformFlagsKHR,
+    pub supportedCompositeAlpha: CompositeAlphaFlagsKHR,
+    pub supportedUsageFlags: ImageUsageFlags
+}
+
+impl Default for SurfaceCapabilitiesKHR {
+    fn default() -> SurfaceCapabilitiesKHR {
+        SurfaceCapabilitiesKHR {
+            minImageCount: 0,
+            maxImageCount: 0,
+            currentExtent: Extent2D{..Default::default()},
+            minImageExtent: Extent2D{..Default::default()},
+            maxImageExtent: Extent2D{..Default::default()},
+            maxImageArrayLayers: 0,
+            supportedTransforms: SurfaceTransformFlagsKHR::empty(),
+            currentTransform: SurfaceTransformFlagsKHR::empty(),
+            supportedCompositeAlpha: CompositeAlphaFlagsKHR::empty(),
+            supportedUsageFlags: ImageUsageFlags::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SurfaceFormatKHR {
+    pub format: Format,
+    pub colorSpace: ColorSpaceKHR
+}
+
+impl Default for SurfaceFormatKHR {
+    fn default() -> SurfaceFormatKHR {
+        SurfaceFormatKHR {
+            format: Format::Undefined,
+            colorSpace: ColorSpaceKHR::SrgbNonlinear
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct SwapchainCreateInfoKHR {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: SwapchainCreateFlagsKHR,
+    pub surface: SurfaceKHR,
+    pub minImageCount: uint32_t,
+    pub imageFormat: Format,
+    pub imageColorSpace: ColorSpaceKHR,
+    pub imageExtent: Extent2D,
+    pub imageArrayLayers: uint32_t,
+    pub imageUsage: ImageUsageFlags,
+    pub imageSharingMode: SharingMode,
+    pub queueFamilyIndexCount: uint32_t,
+    pub pQueueFamilyIndices: *const uint32_t,
+    pub preTransform: SurfaceTransformFlagsKHR,
+    pub compositeAlpha: CompositeAlphaFlagsKHR,
+    pub presentMode: PresentModeKHR,
+    pub clipped: Bool32,
+    pub oldSwapchain: SwapchainKHR
+}
+
+unsafe impl VkStruct for SwapchainCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::SwapchainCreateInfoKHR;
+}
+
+impl Default for SwapchainCreateInfoKHR {
+    fn default() -> SwapchainCreateInfoKHR {
+        SwapchainCreateInfoKHR {
+            sType: SwapchainCreateInfoKHR::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: SwapchainCreateFlagsKHR::empty(),
+            surface: SurfaceKHR::null(),
+            minImageCount: 0,
+            imageFormat: Format::Undefined,
+            imageColorSpace: ColorSpaceKHR::SrgbNonlinear,
+            imageExtent: Extent2D{..Default::default()},
+            imageArrayLayers: 0,
+            imageUsage: ImageUsageFlags::empty(),
+            imageSharingMode: SharingMode::Exclusive,
+            queueFamilyIndexCount: 0,
+            pQueueFamilyIndices: ptr::null(),
+            preTransform: SurfaceTransformFlagsKHR::empty(),
+            compositeAlpha: CompositeAlphaFlagsKHR::empty(),
+            presentMode: PresentModeKHR::Immediate,
+            clipped: 0,
+            oldSwapchain: SwapchainKHR::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct PresentInfoKHR {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub waitSemaphoreCount: uint32_t,
+    pub pWaitSemaphores: *const Semaphore,
+    pub swapchainCount: uint32_t,
+    pub pSwapchains: *const SwapchainKHR,
+    pub pImageIndices: *const uint32_t,
+    pub pResults: *mut ResultCode
+}
+
+unsafe impl VkStruct for PresentInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::PresentInfoKHR;
+}
+
+impl Default for PresentInfoKHR {
+    fn default() -> PresentInfoKHR {
+        PresentInfoKHR {
+            sType: PresentInfoKHR::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            waitSemaphoreCount: 0,
+            pWaitSemaphores: ptr::null(),
+            swapchainCount: 0,
+            pSwapchains: ptr::null(),
+            pImageIndices: ptr::null(),
+            pResults: ptr::null_mut()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct XlibSurfaceCreateInfoKHR {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: XlibSurfaceCreateFlagsKHR,
+    pub dpy: *mut Display,
+    pub window: Window
+}
+
+unsafe impl VkStruct for XlibSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::XlibSurfaceCreateInfoKHR;
+}
+
+impl Default for XlibSurfaceCreateInfoKHR {
+    fn default() -> XlibSurfaceCreateInfoKHR {
+        XlibSurfaceCreateInfoKHR {
+            sType: XlibSurfaceCreateInfoKHR::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: XlibSurfaceCreateFlagsKHR::empty(),
+            dpy: ptr::null_mut(),
+            window: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct XcbSurfaceCreateInfoKHR {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: XcbSurfaceCreateFlagsKHR,
+    pub connection: *mut xcb_connection_t,
+    pub window: xcb_window_t
+}
+
+unsafe impl VkStruct for XcbSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::XcbSurfaceCreateInfoKHR;
+}
+
+impl Default for XcbSurfaceCreateInfoKHR {
+    fn default() -> XcbSurfaceCreateInfoKHR {
+        XcbSurfaceCreateInfoKHR {
+            sType: XcbSurfaceCreateInfoKHR::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: XcbSurfaceCreateFlagsKHR::empty(),
+            connection: ptr::null_mut(),
+            window: 0
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct WaylandSurfaceCreateInfoKHR {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: WaylandSurfaceCreateFlagsKHR,
+    pub display: *mut wl_display,
+    pub surface: *mut wl_surface
+}
+
+unsafe impl VkStruct for WaylandSurfaceCreateInfoKHR {
+    const STRUCTURE_TYPE: StructureType = StructureType::WaylandSurfaceCreateInfoKHR;
+}
+
+impl Default for WaylandSurfaceCreateInfoKHR {
+    fn default() -> WaylandSurfaceCreateInfoKHR {
+        WaylandSurfaceCreateInfoKHR {
+            sType: WaylandSurfaceCreateInfoKHR::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: WaylandSurfaceCreateFlagsKHR::empty(),
+            display: ptr::null_mut(),
+            surface: ptr::null_mut()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct HeadlessSurfaceCreateInfoEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: HeadlessSurfaceCreateFlagsEXT
+}
+
+unsafe impl VkStruct for HeadlessSurfaceCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::HeadlessSurfaceCreateInfoExt;
+}
+
+impl Default for HeadlessSurfaceCreateInfoEXT {
+    fn default() -> HeadlessSurfaceCreateInfoEXT {
+        HeadlessSurfaceCreateInfoEXT {
+            sType: HeadlessSurfaceCreateInfoEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: HeadlessSurfaceCreateFlagsEXT::empty()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DebugReportCallbackCreateInfoEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DebugReportFlagsEXT,
+    pub pfnCallback: Option<DebugReportCallbackFunctionEXT>,
+    pub pUserData: *mut c_void
+}
+
+unsafe impl VkStruct for DebugReportCallbackCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DebugReportCreateInfoExt;
+}
+
+impl Default for DebugReportCallbackCreateInfoEXT {
+    fn default() -> DebugReportCallbackCreateInfoEXT {
+        DebugReportCallbackCreateInfoEXT {
+            sType: DebugReportCallbackCreateInfoEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DebugReportFlagsEXT::empty(),
+            pfnCallback: None,
+            pUserData: ptr::null_mut()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DebugUtilsObjectNameInfoEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub objectType: ObjectType,
+    pub objectHandle: uint64_t,
+    pub pObjectName: *const c_char
+}
+
+unsafe impl VkStruct for DebugUtilsObjectNameInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DebugUtilsObjectNameInfoExt;
+}
+
+impl Default for DebugUtilsObjectNameInfoEXT {
+    fn default() -> DebugUtilsObjectNameInfoEXT {
+        DebugUtilsObjectNameInfoEXT {
+            sType: DebugUtilsObjectNameInfoEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            objectType: ObjectType::Unknown,
+            objectHandle: 0,
+            pObjectName: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DebugUtilsLabelEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub pLabelName: *const c_char,
+    pub color: [c_float; 4]
+}
+
+unsafe impl VkStruct for DebugUtilsLabelEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DebugUtilsLabelExt;
+}
+
+impl Default for DebugUtilsLabelEXT {
+    fn default() -> DebugUtilsLabelEXT {
+        DebugUtilsLabelEXT {
+            sType: DebugUtilsLabelEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            pLabelName: ptr::null(),
+            color: [0.0; 4]
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DebugUtilsMessengerCallbackDataEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DebugUtilsMessengerCallbackDataFlagsEXT,
+    pub pMessageIdName: *const c_char,
+    pub messageIdNumber: int32_t,
+    pub pMessage: *const c_char,
+    pub queueLabelCount: uint32_t,
+    pub pQueueLabels: *const DebugUtilsLabelEXT,
+    pub cmdBufLabelCount: uint32_t,
+    pub pCmdBufLabels: *const DebugUtilsLabelEXT,
+    pub objectCount: uint32_t,
+    pub pObjects: *const DebugUtilsObjectNameInfoEXT
+}
+
+unsafe impl VkStruct for DebugUtilsMessengerCallbackDataEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DebugUtilsMessengerCallbackDataExt;
+}
+
+impl Default for DebugUtilsMessengerCallbackDataEXT {
+    fn default() -> DebugUtilsMessengerCallbackDataEXT {
+        DebugUtilsMessengerCallbackDataEXT {
+            sType: DebugUtilsMessengerCallbackDataEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DebugUtilsMessengerCallbackDataFlagsEXT::empty(),
+            pMessageIdName: ptr::null(),
+            messageIdNumber: 0,
+            pMessage: ptr::null(),
+            queueLabelCount: 0,
+            pQueueLabels: ptr::null(),
+            cmdBufLabelCount: 0,
+            pCmdBufLabels: ptr::null(),
+            objectCount: 0,
+            pObjects: ptr::null()
+        }
+    }
+}
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Copy, Clone)]
+pub struct DebugUtilsMessengerCreateInfoEXT {
+    pub sType: StructureType,
+    pub pNext: *const c_void,
+    pub flags: DebugUtilsMessengerCreateFlagsEXT,
+    pub messageSeverity: DebugUtilsMessageSeverityFlagsEXT,
+    pub messageType: DebugUtilsMessageTypeFlagsEXT,
+    pub pfnUserCallback: Option<DebugUtilsMessengerCallbackFunctionEXT>,
+    pub pUserData: *mut c_void
+}
+
+unsafe impl VkStruct for DebugUtilsMessengerCreateInfoEXT {
+    const STRUCTURE_TYPE: StructureType = StructureType::DebugUtilsMessengerCreateInfoExt;
+}
+
+impl Default for DebugUtilsMessengerCreateInfoEXT {
+    fn default() -> DebugUtilsMessengerCreateInfoEXT {
+        DebugUtilsMessengerCreateInfoEXT {
+            sType: DebugUtilsMessengerCreateInfoEXT::STRUCTURE_TYPE,
+            pNext: ptr::null(),
+            flags: DebugUtilsMessengerCreateFlagsEXT::empty(),
+            messageSeverity: DebugUtilsMessageSeverityFlagsEXT::empty(),
+            messageType: DebugUtilsMessageTypeFlagsEXT::empty(),
+            pfnUserCallback: None,
+            pUserData: ptr::null_mut()
+        }
+    }
+}
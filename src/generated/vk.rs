@@ -0,0 +1,244 @@
+// Generated from generator/vk.xml by `cargo run -p spock-generator`. Edit the
+// registry or the generator and regenerate rather than changing this file.
+
+dispatch_table! {
+    EntryFns(entry_table) {
+        fn vkCreateInstance(pCreateInfo: *const InstanceCreateInfo, pAllocator: *const AllocationCallbacks, pInstance: *mut Instance) -> ResultCode;
+        fn vkEnumerateInstanceExtensionProperties(pLayerName: *const c_char, pPropertyCount: *mut uint32_t,
+                                                  pProperties: *mut ExtensionProperties) -> ResultCode;
+        fn vkEnumerateInstanceLayerProperties(pPropertyCount: *mut uint32_t, pProperties: *mut LayerProperties) -> ResultCode;
+    } {
+    }
+}
+
+dispatch_table! {
+    InstanceFns(instance_table) {
+        fn vkDestroyInstance(instance: Instance, pAllocator: *const AllocationCallbacks);
+        fn vkCreateDevice(physicalDevice: PhysicalDevice, pCreateInfo: *const DeviceCreateInfo, pAllocator: *const AllocationCallbacks,
+                          pDevice: *mut Device) -> ResultCode;
+        fn vkGetDeviceProcAddr(device: Device, pName: *const c_char) -> Option<VoidFunction>;
+    } {
+        fn vkEnumeratePhysicalDevices(instance: Instance, pPhysicalDeviceCount: *mut uint32_t, pPhysicalDevices: *mut PhysicalDevice) -> ResultCode;
+        fn vkGetPhysicalDeviceFeatures(physicalDevice: PhysicalDevice, pFeatures: *mut PhysicalDeviceFeatures);
+        fn vkGetPhysicalDeviceFormatProperties(physicalDevice: PhysicalDevice, format: Format, pFormatProperties: *mut FormatProperties);
+        fn vkGetPhysicalDeviceImageFormatProperties(physicalDevice: PhysicalDevice, format: Format, imageType: ImageType, tiling: ImageTiling,
+                                                    usage: ImageUsageFlags, flags: ImageCreateFlags,
+                                                    pImageFormatProperties: *mut ImageFormatProperties) -> ResultCode;
+        fn vkGetPhysicalDeviceProperties(physicalDevice: PhysicalDevice, pProperties: *mut PhysicalDeviceProperties);
+        fn vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice: PhysicalDevice, pQueueFamilyPropertyCount: *mut uint32_t,
+                                                    pQueueFamilyProperties: *mut QueueFamilyProperties);
+        fn vkGetPhysicalDeviceMemoryProperties(physicalDevice: PhysicalDevice, pMemoryProperties: *mut PhysicalDeviceMemoryProperties);
+        fn vkEnumerateDeviceExtensionProperties(physicalDevice: PhysicalDevice, pLayerName: *const c_char, pPropertyCount: *mut uint32_t,
+                                                pProperties: *mut ExtensionProperties) -> ResultCode;
+        fn vkEnumerateDeviceLayerProperties(physicalDevice: PhysicalDevice, pPropertyCount: *mut uint32_t, pProperties: *mut LayerProperties) -> ResultCode;
+        fn vkGetPhysicalDeviceSparseImageFormatProperties(physicalDevice: PhysicalDevice, format: Format, imageType: ImageType, samples: SampleCountFlags,
+                                                          usage: ImageUsageFlags, tiling: ImageTiling, pPropertyCount: *mut uint32_t,
+                                                          pProperties: *mut SparseImageFormatProperties);
+        fn vkDestroySurfaceKHR(instance: Instance, surface: SurfaceKHR, pAllocator: *const AllocationCallbacks);
+        fn vkGetPhysicalDeviceSurfaceSupportKHR(physicalDevice: PhysicalDevice, queueFamilyIndex: uint32_t, surface: SurfaceKHR,
+                                                pSupported: *mut Bool32) -> ResultCode;
+        fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR(physicalDevice: PhysicalDevice, surface: SurfaceKHR,
+                                                     pSurfaceCapabilities: *mut SurfaceCapabilitiesKHR) -> ResultCode;
+        fn vkGetPhysicalDeviceSurfaceFormatsKHR(physicalDevice: PhysicalDevice, surface: SurfaceKHR, pSurfaceFormatCount: *mut uint32_t,
+                                                pSurfaceFormats: *mut SurfaceFormatKHR) -> ResultCode;
+        fn vkGetPhysicalDeviceSurfacePresentModesKHR(physicalDevice: PhysicalDevice, surface: SurfaceKHR, pPresentModeCount: *mut uint32_t,
+                                                     pPresentModes: *mut PresentModeKHR) -> ResultCode;
+        fn vkCreateXlibSurfaceKHR(instance: Instance, pCreateInfo: *const XlibSurfaceCreateInfoKHR, pAllocator: *const AllocationCallbacks,
+                                  pSurface: *mut SurfaceKHR) -> ResultCode;
+        fn vkGetPhysicalDeviceXlibPresentationSupportKHR(physicalDevice: PhysicalDevice, queueFamilyIndex: uint32_t, dpy: *mut Display,
+                                                         visualID: VisualID) -> Bool32;
+        fn vkCreateXcbSurfaceKHR(instance: Instance, pCreateInfo: *const XcbSurfaceCreateInfoKHR, pAllocator: *const AllocationCallbacks,
+                                 pSurface: *mut SurfaceKHR) -> ResultCode;
+        fn vkGetPhysicalDeviceXcbPresentationSupportKHR(physicalDevice: PhysicalDevice, queueFamilyIndex: uint32_t, connection: *mut xcb_connection_t,
+                                                        visual_id: xcb_visualid_t) -> Bool32;
+        fn vkCreateWaylandSurfaceKHR(instance: Instance, pCreateInfo: *const WaylandSurfaceCreateInfoKHR, pAllocator: *const AllocationCallbacks,
+                                     pSurface: *mut SurfaceKHR) -> ResultCode;
+        fn vkGetPhysicalDeviceWaylandPresentationSupportKHR(physicalDevice: PhysicalDevice, queueFamilyIndex: uint32_t, display: *mut wl_display) -> Bool32;
+        fn vkCreateHeadlessSurfaceEXT(instance: Instance, pCreateInfo: *const HeadlessSurfaceCreateInfoEXT, pAllocator: *const AllocationCallbacks,
+                                      pSurface: *mut SurfaceKHR) -> ResultCode;
+        fn vkCreateDebugReportCallbackEXT(instance: Instance, pCreateInfo: *const DebugReportCallbackCreateInfoEXT, pAllocator: *const AllocationCallbacks,
+                                          pCallback: *mut DebugReportCallbackEXT) -> ResultCode;
+        fn vkDestroyDebugReportCallbackEXT(instance: Instance, callback: DebugReportCallbackEXT, pAllocator: *const AllocationCallbacks);
+        fn vkCreateDebugUtilsMessengerEXT(instance: Instance, pCreateInfo: *const DebugUtilsMessengerCreateInfoEXT, pAllocator: *const AllocationCallbacks,
+                                          pMessenger: *mut DebugUtilsMessengerEXT) -> ResultCode;
+        fn vkDestroyDebugUtilsMessengerEXT(instance: Instance, messenger: DebugUtilsMessengerEXT, pAllocator: *const AllocationCallbacks);
+    }
+}
+
+dispatch_table! {
+    DeviceFns(device_table) {
+        fn vkDestroyDevice(device: Device, pAllocator: *const AllocationCallbacks);
+        fn vkAllocateMemory(device: Device, pAllocateInfo: *const MemoryAllocateInfo, pAllocator: *const AllocationCallbacks,
+                            pMemory: *mut DeviceMemory) -> ResultCode;
+        fn vkFreeMemory(device: Device, memory: DeviceMemory, pAllocator: *const AllocationCallbacks);
+        fn vkCreateImage(device: Device, pCreateInfo: *const ImageCreateInfo, pAllocator: *const AllocationCallbacks, pImage: *mut Image) -> ResultCode;
+        fn vkDestroyImage(device: Device, image: Image, pAllocator: *const AllocationCallbacks);
+        fn vkCreateQueryPool(device: Device, pCreateInfo: *const QueryPoolCreateInfo, pAllocator: *const AllocationCallbacks,
+                             pQueryPool: *mut QueryPool) -> ResultCode;
+        fn vkDestroyQueryPool(device: Device, queryPool: QueryPool, pAllocator: *const AllocationCallbacks);
+        fn vkCreateSwapchainKHR(device: Device, pCreateInfo: *const SwapchainCreateInfoKHR, pAllocator: *const AllocationCallbacks,
+                                pSwapchain: *mut SwapchainKHR) -> ResultCode;
+        fn vkDestroySwapchainKHR(device: Device, swapchain: SwapchainKHR, pAllocator: *const AllocationCallbacks);
+        fn vkGetSwapchainImagesKHR(device: Device, swapchain: SwapchainKHR, pSwapchainImageCount: *mut uint32_t, pSwapchainImages: *mut Image) -> ResultCode;
+    } {
+        fn vkGetDeviceProcAddr(device: Device, pName: *const c_char) -> Option<VoidFunction>;
+        fn vkGetDeviceQueue(device: Device, queueFamilyIndex: uint32_t, queueIndex: uint32_t, pQueue: *mut Queue);
+        fn vkQueueSubmit(queue: Queue, submitCount: uint32_t, pSubmits: *const SubmitInfo, fence: Fence) -> ResultCode;
+        fn vkQueueWaitIdle(queue: Queue) -> ResultCode;
+        fn vkDeviceWaitIdle(device: Device) -> ResultCode;
+        fn vkMapMemory(device: Device, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize, flags: MemoryMapFlags,
+                       ppData: *mut *mut c_void) -> ResultCode;
+        fn vkUnmapMemory(device: Device, memory: DeviceMemory);
+        fn vkFlushMappedMemoryRanges(device: Device, memoryRangeCount: uint32_t, pMemoryRanges: *const MappedMemoryRange) -> ResultCode;
+        fn vkInvalidateMappedMemoryRanges(device: Device, memoryRangeCount: uint32_t, pMemoryRanges: *const MappedMemoryRange) -> ResultCode;
+        fn vkGetDeviceMemoryCommitment(device: Device, memory: DeviceMemory, pCommittedMemoryInBytes: *mut DeviceSize);
+        fn vkBindBufferMemory(device: Device, buffer: Buffer, memory: DeviceMemory, memoryOffset: DeviceSize) -> ResultCode;
+        fn vkBindImageMemory(device: Device, image: Image, memory: DeviceMemory, memoryOffset: DeviceSize) -> ResultCode;
+        fn vkGetBufferMemoryRequirements(device: Device, buffer: Buffer, pMemoryRequirements: *mut MemoryRequirements);
+        fn vkGetImageMemoryRequirements(device: Device, image: Image, pMemoryRequirements: *mut MemoryRequirements);
+        fn vkGetImageSparseMemoryRequirements(device: Device, image: Image, pSparseMemoryRequirementCount: *mut uint32_t,
+                                              pSparseMemoryRequirements: *mut SparseImageMemoryRequirements);
+        fn vkQueueBindSparse(queue: Queue, bindInfoCount: uint32_t, pBindInfo: *const BindSparseInfo, fence: Fence) -> ResultCode;
+        fn vkCreateFence(device: Device, pCreateInfo: *const FenceCreateInfo, pAllocator: *const AllocationCallbacks, pFence: *mut Fence) -> ResultCode;
+        fn vkDestroyFence(device: Device, fence: Fence, pAllocator: *const AllocationCallbacks);
+        fn vkResetFences(device: Device, fenceCount: uint32_t, pFences: *const Fence) -> ResultCode;
+        fn vkGetFenceStatus(device: Device, fence: Fence) -> ResultCode;
+        fn vkWaitForFences(device: Device, fenceCount: uint32_t, pFences: *const Fence, waitAll: Bool32, timeout: uint64_t) -> ResultCode;
+        fn vkCreateSemaphore(device: Device, pCreateInfo: *const SemaphoreCreateInfo, pAllocator: *const AllocationCallbacks,
+                             pSemaphore: *mut Semaphore) -> ResultCode;
+        fn vkDestroySemaphore(device: Device, semaphore: Semaphore, pAllocator: *const AllocationCallbacks);
+        fn vkCreateEvent(device: Device, pCreateInfo: *const EventCreateInfo, pAllocator: *const AllocationCallbacks, pEvent: *mut Event) -> ResultCode;
+        fn vkDestroyEvent(device: Device, event: Event, pAllocator: *const AllocationCallbacks);
+        fn vkGetEventStatus(device: Device, event: Event) -> ResultCode;
+        fn vkSetEvent(device: Device, event: Event) -> ResultCode;
+        fn vkResetEvent(device: Device, event: Event) -> ResultCode;
+        fn vkGetQueryPoolResults(device: Device, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t, dataSize: size_t, pData: *mut c_void,
+                                 stride: DeviceSize, flags: QueryResultFlags) -> ResultCode;
+        fn vkCreateBuffer(device: Device, pCreateInfo: *const BufferCreateInfo, pAllocator: *const AllocationCallbacks, pBuffer: *mut Buffer) -> ResultCode;
+        fn vkDestroyBuffer(device: Device, buffer: Buffer, pAllocator: *const AllocationCallbacks);
+        fn vkCreateBufferView(device: Device, pCreateInfo: *const BufferViewCreateInfo, pAllocator: *const AllocationCallbacks,
+                              pView: *mut BufferView) -> ResultCode;
+        fn vkDestroyBufferView(device: Device, bufferView: BufferView, pAllocator: *const AllocationCallbacks);
+        fn vkGetImageSubresourceLayout(device: Device, image: Image, pSubresource: *const ImageSubresource, pLayout: *mut SubresourceLayout);
+        fn vkCreateImageView(device: Device, pCreateInfo: *const ImageViewCreateInfo, pAllocator: *const AllocationCallbacks,
+                             pView: *mut ImageView) -> ResultCode;
+        fn vkDestroyImageView(device: Device, imageView: ImageView, pAllocator: *const AllocationCallbacks);
+        fn vkCreateShaderModule(device: Device, pCreateInfo: *const ShaderModuleCreateInfo, pAllocator: *const AllocationCallbacks,
+                                pShaderModule: *mut ShaderModule) -> ResultCode;
+        fn vkDestroyShaderModule(device: Device, shaderModule: ShaderModule, pAllocator: *const AllocationCallbacks);
+        fn vkCreatePipelineCache(device: Device, pCreateInfo: *const PipelineCacheCreateInfo, pAllocator: *const AllocationCallbacks,
+                                 pPipelineCache: *mut PipelineCache) -> ResultCode;
+        fn vkDestroyPipelineCache(device: Device, pipelineCache: PipelineCache, pAllocator: *const AllocationCallbacks);
+        fn vkGetPipelineCacheData(device: Device, pipelineCache: PipelineCache, pDataSize: *mut size_t, pData: *mut c_void) -> ResultCode;
+        fn vkMergePipelineCaches(device: Device, dstCache: PipelineCache, srcCacheCount: uint32_t, pSrcCaches: *const PipelineCache) -> ResultCode;
+        fn vkCreateGraphicsPipelines(device: Device, pipelineCache: PipelineCache, createInfoCount: uint32_t, pCreateInfos: *const GraphicsPipelineCreateInfo,
+                                     pAllocator: *const AllocationCallbacks, pPipelines: *mut Pipeline) -> ResultCode;
+        fn vkCreateComputePipelines(device: Device, pipelineCache: PipelineCache, createInfoCount: uint32_t, pCreateInfos: *const ComputePipelineCreateInfo,
+                                    pAllocator: *const AllocationCallbacks, pPipelines: *mut Pipeline) -> ResultCode;
+        fn vkDestroyPipeline(device: Device, pipeline: Pipeline, pAllocator: *const AllocationCallbacks);
+        fn vkCreatePipelineLayout(device: Device, pCreateInfo: *const PipelineLayoutCreateInfo, pAllocator: *const AllocationCallbacks,
+                                  pPipelineLayout: *mut PipelineLayout) -> ResultCode;
+        fn vkDestroyPipelineLayout(device: Device, pipelineLayout: PipelineLayout, pAllocator: *const AllocationCallbacks);
+        fn vkCreateSampler(device: Device, pCreateInfo: *const SamplerCreateInfo, pAllocator: *const AllocationCallbacks, pSampler: *mut Sampler) -> ResultCode;
+        fn vkDestroySampler(device: Device, sampler: Sampler, pAllocator: *const AllocationCallbacks);
+        fn vkCreateDescriptorSetLayout(device: Device, pCreateInfo: *const DescriptorSetLayoutCreateInfo, pAllocator: *const AllocationCallbacks,
+                                       pSetLayout: *mut DescriptorSetLayout) -> ResultCode;
+        fn vkDestroyDescriptorSetLayout(device: Device, descriptorSetLayout: DescriptorSetLayout, pAllocator: *const AllocationCallbacks);
+        fn vkCreateDescriptorPool(device: Device, pCreateInfo: *const DescriptorPoolCreateInfo, pAllocator: *const AllocationCallbacks,
+                                  pDescriptorPool: *mut DescriptorPool) -> ResultCode;
+        fn vkDestroyDescriptorPool(device: Device, descriptorPool: DescriptorPool, pAllocator: *const AllocationCallbacks);
+        fn vkResetDescriptorPool(device: Device, descriptorPool: DescriptorPool, flags: DescriptorPoolResetFlags) -> ResultCode;
+        fn vkAllocateDescriptorSets(device: Device, pAllocateInfo: *const DescriptorSetAllocateInfo, pDescriptorSets: *mut DescriptorSet) -> ResultCode;
+        fn vkFreeDescriptorSets(device: Device, descriptorPool: DescriptorPool, descriptorSetCount: uint32_t,
+                                pDescriptorSets: *const DescriptorSet) -> ResultCode;
+        fn vkUpdateDescriptorSets(device: Device, descriptorWriteCount: uint32_t, pDescriptorWrites: *const WriteDescriptorSet, descriptorCopyCount: uint32_t,
+                                  pDescriptorCopies: *const CopyDescriptorSet);
+        fn vkCreateFramebuffer(device: Device, pCreateInfo: *const FramebufferCreateInfo, pAllocator: *const AllocationCallbacks,
+                               pFramebuffer: *mut Framebuffer) -> ResultCode;
+        fn vkDestroyFramebuffer(device: Device, framebuffer: Framebuffer, pAllocator: *const AllocationCallbacks);
+        fn vkCreateRenderPass(device: Device, pCreateInfo: *const RenderPassCreateInfo, pAllocator: *const AllocationCallbacks,
+                              pRenderPass: *mut RenderPass) -> ResultCode;
+        fn vkDestroyRenderPass(device: Device, renderPass: RenderPass, pAllocator: *const AllocationCallbacks);
+        fn vkGetRenderAreaGranularity(device: Device, renderPass: RenderPass, pGranularity: *mut Extent2D);
+        fn vkCreateCommandPool(device: Device, pCreateInfo: *const CommandPoolCreateInfo, pAllocator: *const AllocationCallbacks,
+                               pCommandPool: *mut CommandPool) -> ResultCode;
+        fn vkDestroyCommandPool(device: Device, commandPool: CommandPool, pAllocator: *const AllocationCallbacks);
+        fn vkResetCommandPool(device: Device, commandPool: CommandPool, flags: CommandPoolResetFlags) -> ResultCode;
+        fn vkAllocateCommandBuffers(device: Device, pAllocateInfo: *const CommandBufferAllocateInfo, pCommandBuffers: *mut CommandBuffer) -> ResultCode;
+        fn vkFreeCommandBuffers(device: Device, commandPool: CommandPool, commandBufferCount: uint32_t, pCommandBuffers: *const CommandBuffer);
+        fn vkBeginCommandBuffer(commandBuffer: CommandBuffer, pBeginInfo: *const CommandBufferBeginInfo) -> ResultCode;
+        fn vkEndCommandBuffer(commandBuffer: CommandBuffer) -> ResultCode;
+        fn vkResetCommandBuffer(commandBuffer: CommandBuffer, flags: CommandBufferResetFlags) -> ResultCode;
+        fn vkCmdBindPipeline(commandBuffer: CommandBuffer, pipelineBindPoint: PipelineBindPoint, pipeline: Pipeline);
+        fn vkCmdSetViewport(commandBuffer: CommandBuffer, firstViewport: uint32_t, viewportCount: uint32_t, pViewports: *const Viewport);
+        fn vkCmdSetScissor(commandBuffer: CommandBuffer, firstScissor: uint32_t, scissorCount: uint32_t, pScissors: *const Rect2D);
+        fn vkCmdSetLineWidth(commandBuffer: CommandBuffer, lineWidth: c_float);
+        fn vkCmdSetDepthBias(commandBuffer: CommandBuffer, depthBiasConstantFactor: c_float, depthBiasClamp: c_float, depthBiasSlopeFactor: c_float);
+        fn vkCmdSetBlendConstants(commandBuffer: CommandBuffer, blendConstants: *const [c_float; 4]);
+        fn vkCmdSetDepthBounds(commandBuffer: CommandBuffer, minDepthBounds: c_float, maxDepthBounds: c_float);
+        fn vkCmdSetStencilCompareMask(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, compareMask: uint32_t);
+        fn vkCmdSetStencilWriteMask(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, writeMask: uint32_t);
+        fn vkCmdSetStencilReference(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, reference: uint32_t);
+        fn vkCmdBindDescriptorSets(commandBuffer: CommandBuffer, pipelineBindPoint: PipelineBindPoint, layout: PipelineLayout, firstSet: uint32_t,
+                                   descriptorSetCount: uint32_t, pDescriptorSets: *const DescriptorSet, dynamicOffsetCount: uint32_t,
+                                   pDynamicOffsets: *const uint32_t);
+        fn vkCmdBindIndexBuffer(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, indexType: IndexType);
+        fn vkCmdBindVertexBuffers(commandBuffer: CommandBuffer, firstBinding: uint32_t, bindingCount: uint32_t, pBuffers: *const Buffer,
+                                  pOffsets: *const DeviceSize);
+        fn vkCmdDraw(commandBuffer: CommandBuffer, vertexCount: uint32_t, instanceCount: uint32_t, firstVertex: uint32_t, firstInstance: uint32_t);
+        fn vkCmdDrawIndexed(commandBuffer: CommandBuffer, indexCount: uint32_t, instanceCount: uint32_t, firstIndex: uint32_t, vertexOffset: int32_t,
+                            firstInstance: uint32_t);
+        fn vkCmdDrawIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, drawCount: uint32_t, stride: uint32_t);
+        fn vkCmdDrawIndexedIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, drawCount: uint32_t, stride: uint32_t);
+        fn vkCmdDispatch(commandBuffer: CommandBuffer, groupCountX: uint32_t, groupCountY: uint32_t, groupCountZ: uint32_t);
+        fn vkCmdDispatchIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize);
+        fn vkCmdCopyBuffer(commandBuffer: CommandBuffer, srcBuffer: Buffer, dstBuffer: Buffer, regionCount: uint32_t, pRegions: *const BufferCopy);
+        fn vkCmdCopyImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
+                          regionCount: uint32_t, pRegions: *const ImageCopy);
+        fn vkCmdBlitImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
+                          regionCount: uint32_t, pRegions: *const ImageBlit, filter: Filter);
+        fn vkCmdCopyBufferToImage(commandBuffer: CommandBuffer, srcBuffer: Buffer, dstImage: Image, dstImageLayout: ImageLayout, regionCount: uint32_t,
+                                  pRegions: *const BufferImageCopy);
+        fn vkCmdCopyImageToBuffer(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstBuffer: Buffer, regionCount: uint32_t,
+                                  pRegions: *const BufferImageCopy);
+        fn vkCmdUpdateBuffer(commandBuffer: CommandBuffer, dstBuffer: Buffer, dstOffset: DeviceSize, dataSize: DeviceSize, pData: *const c_void);
+        fn vkCmdFillBuffer(commandBuffer: CommandBuffer, dstBuffer: Buffer, dstOffset: DeviceSize, size: DeviceSize, data: uint32_t);
+        fn vkCmdClearColorImage(commandBuffer: CommandBuffer, image: Image, imageLayout: ImageLayout, pColor: *const ClearColorValue, rangeCount: uint32_t,
+                                pRanges: *const ImageSubresourceRange);
+        fn vkCmdClearDepthStencilImage(commandBuffer: CommandBuffer, image: Image, imageLayout: ImageLayout, pDepthStencil: *const ClearDepthStencilValue,
+                                       rangeCount: uint32_t, pRanges: *const ImageSubresourceRange);
+        fn vkCmdClearAttachments(commandBuffer: CommandBuffer, attachmentCount: uint32_t, pAttachments: *const ClearAttachment, rectCount: uint32_t,
+                                 pRects: *const ClearRect);
+        fn vkCmdResolveImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
+                             regionCount: uint32_t, pRegions: *const ImageResolve);
+        fn vkCmdSetEvent(commandBuffer: CommandBuffer, event: Event, stageMask: PipelineStageFlags);
+        fn vkCmdResetEvent(commandBuffer: CommandBuffer, event: Event, stageMask: PipelineStageFlags);
+        fn vkCmdWaitEvents(commandBuffer: CommandBuffer, eventCount: uint32_t, pEvents: *const Event, srcStageMask: PipelineStageFlags,
+                           dstStageMask: PipelineStageFlags, memoryBarrierCount: uint32_t, pMemoryBarriers: *const MemoryBarrier,
+                           bufferMemoryBarrierCount: uint32_t, pBufferMemoryBarriers: *const BufferMemoryBarrier, imageMemoryBarrierCount: uint32_t,
+                           pImageMemoryBarriers: *const ImageMemoryBarrier);
+        fn vkCmdPipelineBarrier(commandBuffer: CommandBuffer, srcStageMask: PipelineStageFlags, dstStageMask: PipelineStageFlags,
+                                dependencyFlags: DependencyFlags, memoryBarrierCount: uint32_t, pMemoryBarriers: *const MemoryBarrier,
+                                bufferMemoryBarrierCount: uint32_t, pBufferMemoryBarriers: *const BufferMemoryBarrier, imageMemoryBarrierCount: uint32_t,
+                                pImageMemoryBarriers: *const ImageMemoryBarrier);
+        fn vkCmdBeginQuery(commandBuffer: CommandBuffer, queryPool: QueryPool, query: uint32_t, flags: QueryControlFlags);
+        fn vkCmdEndQuery(commandBuffer: CommandBuffer, queryPool: QueryPool, query: uint32_t);
+        fn vkCmdResetQueryPool(commandBuffer: CommandBuffer, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t);
+        fn vkCmdWriteTimestamp(commandBuffer: CommandBuffer, pipelineStage: PipelineStageFlags, queryPool: QueryPool, query: uint32_t);
+        fn vkCmdCopyQueryPoolResults(commandBuffer: CommandBuffer, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t, dstBuffer: Buffer,
+                                     dstOffset: DeviceSize, stride: DeviceSize, flags: QueryResultFlags);
+        fn vkCmdPushConstants(commandBuffer: CommandBuffer, layout: PipelineLayout, stageFlags: ShaderStageFlags, offset: uint32_t, size: uint32_t,
+                              pValues: *const c_void);
+        fn vkCmdBeginRenderPass(commandBuffer: CommandBuffer, pRenderPassBegin: *const RenderPassBeginInfo, contents: SubpassContents);
+        fn vkCmdNextSubpass(commandBuffer: CommandBuffer, contents: SubpassContents);
+        fn vkCmdEndRenderPass(commandBuffer: CommandBuffer);
+        fn vkCmdExecuteCommands(commandBuffer: CommandBuffer, commandBufferCount: uint32_t, pCommandBuffers: *const CommandBuffer);
+        fn vkAcquireNextImageKHR(device: Device, swapchain: SwapchainKHR, timeout: uint64_t, semaphore: Semaphore, fence: Fence,
+                                 pImageIndex: *mut uint32_t) -> ResultCode;
+        fn vkQueuePresentKHR(queue: Queue, pPresentInfo: *const PresentInfoKHR) -> ResultCode;
+        fn vkSetDebugUtilsObjectNameEXT(device: Device, pNameInfo: *const DebugUtilsObjectNameInfoEXT) -> ResultCode;
+        fn vkCmdBeginDebugUtilsLabelEXT(commandBuffer: CommandBuffer, pLabelInfo: *const DebugUtilsLabelEXT);
+        fn vkCmdEndDebugUtilsLabelEXT(commandBuffer: CommandBuffer);
+        fn vkCmdInsertDebugUtilsLabelEXT(commandBuffer: CommandBuffer, pLabelInfo: *const DebugUtilsLabelEXT);
+    }
+}
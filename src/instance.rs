@@ -0,0 +1,203 @@
+// Builds an instance from owned strings. InstanceCreateInfo and ApplicationInfo
+// only point at their names, so filling them in by hand makes it easy to point
+// at a CString that has already been dropped; the builder keeps every string
+// alive until vkCreateInstance has returned.
+//
+// Requested layers and extensions are checked against what the loader offers
+// before the instance is created, so that a missing one is reported by name
+// rather than as a bare LayerNotPresent or ExtensionNotPresent.
+
+use std::error;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr;
+
+use libc::c_char;
+
+use loader::{self, LoaderError};
+use owned::OwnedInstance;
+use types::*;
+use {enumerate_instance_extension_properties, enumerate_instance_layer_properties, make_version};
+
+#[derive(Clone)]
+pub enum InstanceError {
+    Loader(LoaderError),
+    MissingLayers(Vec<String>),
+    MissingExtensions(Vec<String>),
+    // A name that cannot be passed to Vulkan because it contains a nul.
+    InvalidName(String),
+    Vulkan(Error)
+}
+
+impl fmt::Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InstanceError::Loader(ref error)           => write!(f, "{}", error),
+            InstanceError::MissingLayers(ref names)     => write!(f, "Instance layers not present: {}", names.join(", ")),
+            InstanceError::MissingExtensions(ref names) => write!(f, "Instance extensions not present: {}", names.join(", ")),
+            InstanceError::InvalidName(ref name)        => write!(f, "Name contains a nul byte: {:?}", name),
+            InstanceError::Vulkan(error)                => write!(f, "Unable to create instance ({})", error)
+        }
+    }
+}
+
+impl fmt::Debug for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for InstanceError {}
+
+impl From<Error> for InstanceError {
+    fn from(error: Error) -> InstanceError {
+        InstanceError::Vulkan(error)
+    }
+}
+
+#[derive(Clone)]
+pub struct InstanceBuilder {
+    application_name: Option<String>,
+    application_version: u32,
+    engine_name: Option<String>,
+    engine_version: u32,
+    api_version: u32,
+    layers: Vec<String>,
+    extensions: Vec<String>
+}
+
+impl Default for InstanceBuilder {
+    fn default() -> InstanceBuilder {
+        InstanceBuilder::new()
+    }
+}
+
+impl InstanceBuilder {
+    pub fn new() -> InstanceBuilder {
+        InstanceBuilder {
+            application_name: None,
+            application_version: 0,
+            engine_name: None,
+            engine_version: 0,
+            api_version: make_version(1, 0, 0),
+            layers: Vec::new(),
+            extensions: Vec::new()
+        }
+    }
+
+    pub fn application(mut self, name: &str, version: u32) -> InstanceBuilder {
+        self.application_name = Some(name.to_string());
+        self.application_version = version;
+        self
+    }
+
+    pub fn engine(mut self, name: &str, version: u32) -> InstanceBuilder {
+        self.engine_name = Some(name.to_string());
+        self.engine_version = version;
+        self
+    }
+
+    // The highest version of Vulkan the application uses; 1.0 by default.
+    pub fn api_version(mut self, version: u32) -> InstanceBuilder {
+        self.api_version = version;
+        self
+    }
+
+    pub fn layer(self, name: &str) -> InstanceBuilder {
+        self.layers(&[name])
+    }
+
+    pub fn layers(mut self, names: &[&str]) -> InstanceBuilder {
+        add_names(&mut self.layers, names);
+        self
+    }
+
+    pub fn extension(self, name: &str) -> InstanceBuilder {
+        self.extensions(&[name])
+    }
+
+    // Takes lists such as wsi::XLIB_EXTENSIONS as they are.
+    pub fn extensions(mut self, names: &[&str]) -> InstanceBuilder {
+        add_names(&mut self.extensions, names);
+        self
+    }
+
+    pub fn build(&self, allocator_opt: Option<AllocationCallbacks>) -> Result<OwnedInstance, InstanceError> {
+        let handle = self.build_raw(allocator_opt)?;
+        Ok(unsafe { OwnedInstance::from_raw(handle, allocator_opt) })
+    }
+
+    // The instance must be destroyed with the same `allocator_opt`.
+    pub fn build_raw(&self, allocator_opt: Option<AllocationCallbacks>) -> Result<Instance, InstanceError> {
+        loader::init().map_err(InstanceError::Loader)?;
+        self.check_layers()?;
+        self.check_extensions()?;
+
+        let application_name = c_string_of_option(&self.application_name)?;
+        let engine_name = c_string_of_option(&self.engine_name)?;
+        let layers = c_strings(&self.layers)?;
+        let extensions = c_strings(&self.extensions)?;
+        let layer_pointers: Vec<*const c_char> = layers.iter().map(|name| name.as_ptr()).collect();
+        let extension_pointers: Vec<*const c_char> = extensions.iter().map(|name| name.as_ptr()).collect();
+
+        let application_info = ApplicationInfo {
+            pApplicationName: application_name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+            applicationVersion: self.application_version,
+            pEngineName: engine_name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+            engineVersion: self.engine_version,
+            apiVersion: self.api_version,
+            ..Default::default()
+        };
+        let create_info = InstanceCreateInfo {
+            pApplicationInfo: &application_info,
+            enabledLayerCount: layer_pointers.len() as u32,
+            ppEnabledLayerNames: layer_pointers.as_ptr(),
+            enabledExtensionCount: extension_pointers.len() as u32,
+            ppEnabledExtensionNames: extension_pointers.as_ptr(),
+            ..Default::default()
+        };
+
+        Ok(::create_instance(create_info, allocator_opt)?)
+    }
+
+    fn check_layers(&self) -> Result<(), InstanceError> {
+        let available = enumerate_instance_layer_properties()?;
+        let missing = missing_names(&self.layers, available.iter().map(|layer| &layer.name));
+        if missing.is_empty() { Ok(()) } else { Err(InstanceError::MissingLayers(missing)) }
+    }
+
+    // An extension may come from the implementation or from one of the
+    // layers being enabled.
+    fn check_extensions(&self) -> Result<(), InstanceError> {
+        let mut available = enumerate_instance_extension_properties(None)?;
+        for layer in &self.layers {
+            available.extend(enumerate_instance_extension_properties(Some(layer))?);
+        }
+        let missing = missing_names(&self.extensions, available.iter().map(|extension| &extension.name));
+        if missing.is_empty() { Ok(()) } else { Err(InstanceError::MissingExtensions(missing)) }
+    }
+}
+
+fn add_names(names: &mut Vec<String>, new_names: &[&str]) {
+    for &name in new_names {
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+    }
+}
+
+fn missing_names<'a, I: Iterator<Item = &'a String>>(wanted: &[String], available: I) -> Vec<String> {
+    let available: Vec<&String> = available.collect();
+    wanted.iter().filter(|name| !available.contains(name)).cloned().collect()
+}
+
+fn c_strings(names: &[String]) -> Result<Vec<CString>, InstanceError> {
+    names.iter().map(|name| CString::new(name.as_str()).map_err(|_| InstanceError::InvalidName(name.clone()))).collect()
+}
+
+fn c_string_of_option(name: &Option<String>) -> Result<Option<CString>, InstanceError> {
+    match *name {
+        None           => Ok(None),
+        Some(ref name) => CString::new(name.as_str()).map(Some).map_err(|_| InstanceError::InvalidName(name.clone()))
+    }
+}
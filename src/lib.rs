@@ -3,946 +3,1348 @@
 
 extern crate libc;
 
+// Borrows the value rather than moving it into the match, so the pointer
+// stays valid for as long as `$opt` itself does.
+macro_rules! pointer_of_option {
+    ($opt:expr) => {{
+        match $opt {
+            None            => ptr::null(),
+            Some(ref value) => value
+        }
+    }}
+}
+
 pub mod types;
+pub mod loader;
 pub mod vk;
-
+pub mod instance;
+pub mod owned;
+pub mod cached;
+pub mod memory;
+pub mod allocator;
+pub mod staging;
+pub mod query;
+pub mod swapchain;
+pub mod wsi;
+pub mod debug;
+
+use debug::LabelScope;
+use memory::{MappedMemory, MemoryUsage};
+use query::{QueryResults, QueryValue};
 use types::*;
 use vk::*;
 
+use libc::{c_char, c_void};
+use std::ffi::CString;
+use std::mem;
 use std::option::Option;
 use std::result::Result;
 use std::ptr;
+use std::slice;
 
-macro_rules! pointer_of_option {
-    ($opt:expr) => {{
-        match $opt {
-            None        => ptr::null(),
-            Some(value) => &value
-        }
-    }}
+// Reinterprets a function returned by get_proc_addr as the caller's type,
+// which must be a function pointer.
+unsafe fn cast_function<F: Copy>(function: VoidFunction) -> F {
+    assert!(mem::size_of::<F>() == mem::size_of::<VoidFunction>(), "get_proc_addr_as must be called with a function pointer type");
+    mem::transmute_copy(&function)
 }
 
+// Returns `$ret` if `$code` is any success code. Commands whose other
+// success codes matter call ResultCode::result themselves instead.
 macro_rules! vulkan_result {
-    ($err:expr, $ret:expr) => {{
-        match $err {
-            Error::Success => Ok($ret),
-            _              => Err($err)
+    ($code:expr, $ret:expr) => {{
+        match $code.result() {
+            Ok(_)      => Ok($ret),
+            Err(error) => Err(error)
         }
     }}
 }
 
+// Packs a version number the way ApplicationInfo and the properties structs
+// carry them: 10 bits of major version, 10 of minor and 12 of patch. Each
+// part is cut to its width first, so that one too large for it cannot spill
+// into the others.
+pub const fn make_version(major: u32, minor: u32, patch: u32) -> u32 {
+    ((major & 0x3FF) << 22) | ((minor & 0x3FF) << 12) | (patch & 0xFFF)
+}
+
+// Fails with InitializationFailed if the Vulkan loader cannot be opened, as do
+// the instance enumerate functions below. loader::init gives the reason.
 pub fn create_instance(create_info: InstanceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Instance, Error> {
+    if loader::init().is_err() {
+        return Err(Error::InitializationFailed);
+    }
+
     unsafe {
-        let mut instance: Instance = ptr::null_mut();
+        let mut instance: Instance = Instance::null();
         let result = vkCreateInstance(&create_info, pointer_of_option!(allocator_opt), &mut instance);
         vulkan_result!(result, instance)
     }
 }
 
-// pub fn count_instance_extension_properties(layer_name: String) -> Result<u32, Error> {
-//     unsafe {
-//         let mut count = 0;
-//         let result = vkEnumerateInstanceExtensionProperties(layer_name, &mut count, ptr::null_mut());
-//         vulkan_result!(result, count)
-//     }
-// }
+// Destroying an instance or device is kept off SpockInstance and SpockDevice,
+// whose methods can be called on the owned wrappers in `owned`, so that a
+// handle one of them owns cannot be destroyed out from under it.
+pub fn destroy_instance(instance: Instance, allocator_opt: Option<AllocationCallbacks>) {
+    unsafe { vkDestroyInstance(instance, pointer_of_option!(allocator_opt)); }
+}
 
-// pub fn enumerate_instance_extension_properties(layer_name: String, property_count: u32) -> Result<Vec<ExtensionProperty>, Error> {
-// }
+pub fn destroy_device(device: Device, allocator_opt: Option<AllocationCallbacks>) {
+    unsafe { vkDestroyDevice(device, pointer_of_option!(allocator_opt)); }
+}
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtensionProperty {
+    pub name: String,
+    pub spec_version: u32
+}
 
+impl<'a> From<&'a ExtensionProperties> for ExtensionProperty {
+    fn from(properties: &'a ExtensionProperties) -> ExtensionProperty {
+        ExtensionProperty {
+            name: string_of_c_chars(&properties.extensionName),
+            spec_version: properties.specVersion
+        }
+    }
+}
 
-// pub fn enumerate_instance_layer_properties(layer_name: String, property_count: u32) -> Result<Vec<LayerProperty>, Error> {
-// }
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerProperty {
+    pub name: String,
+    pub spec_version: u32,
+    pub implementation_version: u32,
+    pub description: String
+}
 
-pub trait SpockInstance {
-    fn destroy(self, allocator_opt: Option<AllocationCallbacks>);
-    fn count_physical_devices(self) -> Result<u32, Error>;
-    fn enumerate_physical_devices(self, u32) -> Result<Vec<PhysicalDevice>, Error>;
-    fn enumerate_all_physical_devices(self) -> Result<Vec<PhysicalDevice>, Error>;
-    // fn get_proc_addr(self, name: String) -> ???;
+impl<'a> From<&'a LayerProperties> for LayerProperty {
+    fn from(properties: &'a LayerProperties) -> LayerProperty {
+        LayerProperty {
+            name: string_of_c_chars(&properties.layerName),
+            spec_version: properties.specVersion,
+            implementation_version: properties.implementationVersion,
+            description: string_of_c_chars(&properties.description)
+        }
+    }
+}
+
+fn string_of_c_chars(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+// Runs a vkEnumerate*/vkGet* style command twice, once for the count and
+// once to fill the results. The implementation may report more results
+// between the two calls, in which case it returns Incomplete and we retry.
+unsafe fn enumerate<T, F>(mut command: F) -> Result<Vec<T>, Error>
+    where T: Clone + Default, F: FnMut(*mut u32, *mut T) -> ResultCode
+{
+    loop {
+        let mut count = 0;
+        try!(command(&mut count, ptr::null_mut()).result());
+
+        let mut values = vec![T::default(); count as usize];
+        match try!(command(&mut count, values.as_mut_ptr()).result()) {
+            Success::Incomplete => continue,
+            _                   => {
+                values.truncate(count as usize);
+                return Ok(values);
+            }
+        }
+    }
+}
+
+// Converts an optional layer name for the enumerate commands, keeping the
+// CString alive for as long as the returned pointer is used.
+fn layer_name_of_option(layer_name: Option<&str>) -> Result<Option<CString>, Error> {
+    match layer_name {
+        None       => Ok(None),
+        Some(name) => CString::new(name).map(Some).map_err(|_| Error::LayerNotPresent)
+    }
+}
+
+// Object names and labels are only ever shown to people, so rather than fail
+// on an interior nul the name is cut short there.
+fn debug_name(name: &str) -> CString {
+    let end = name.find('\0').unwrap_or(name.len());
+    CString::new(&name[..end]).unwrap()
 }
 
-impl SpockInstance for Instance {
-    fn destroy(self, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyInstance(self, pointer_of_option!(allocator_opt)); }
+pub fn enumerate_instance_extension_properties(layer_name: Option<&str>) -> Result<Vec<ExtensionProperty>, Error> {
+    if loader::init().is_err() {
+        return Err(Error::InitializationFailed);
     }
 
-    fn count_physical_devices(self) -> Result<u32, Error> {
+    let c_layer_name = try!(layer_name_of_option(layer_name));
+    let layer_name_ptr = c_layer_name.as_ref().map_or(ptr::null(), |name| name.as_ptr());
+
+    unsafe {
+        let properties = try!(enumerate(|count, values| vkEnumerateInstanceExtensionProperties(layer_name_ptr, count, values)));
+        Ok(properties.iter().map(ExtensionProperty::from).collect())
+    }
+}
+
+pub fn enumerate_instance_layer_properties() -> Result<Vec<LayerProperty>, Error> {
+    if loader::init().is_err() {
+        return Err(Error::InitializationFailed);
+    }
+
+    unsafe {
+        let properties = try!(enumerate(|count, values| vkEnumerateInstanceLayerProperties(count, values)));
+        Ok(properties.iter().map(LayerProperty::from).collect())
+    }
+}
+
+pub trait SpockInstance {
+    fn count_physical_devices(&self) -> Result<u32, Error>;
+    fn enumerate_physical_devices(&self, u32) -> Result<Vec<PhysicalDevice>, Error>;
+    fn enumerate_all_physical_devices(&self) -> Result<Vec<PhysicalDevice>, Error>;
+    fn get_proc_addr(&self, &str) -> Option<VoidFunction>;
+    unsafe fn get_proc_addr_as<F: Copy>(&self, &str) -> Option<F>;
+}
+
+impl<I: InstanceDispatch> SpockInstance for I {
+    fn count_physical_devices(&self) -> Result<u32, Error> {
         unsafe {
             let mut count = 0;
-            let result = vkEnumeratePhysicalDevices(self, &mut count, ptr::null_mut());
+            let result = self.fns().vkEnumeratePhysicalDevices(*self.instance(), &mut count, ptr::null_mut());
             vulkan_result!(result, count)
         }
     }
 
-    fn enumerate_physical_devices(self, count: u32) -> Result<Vec<PhysicalDevice>, Error> {
+    fn enumerate_physical_devices(&self, count: u32) -> Result<Vec<PhysicalDevice>, Error> {
         unsafe {
             let mut count_mut = count;
             let mut physical_devices = Vec::with_capacity(count as usize);
-            physical_devices.resize(count as usize, ptr::null_mut());
+            physical_devices.resize(count as usize, PhysicalDevice::null());
 
-            let result = vkEnumeratePhysicalDevices(self, &mut count_mut, physical_devices.as_mut_ptr()); 
+            let result = self.fns().vkEnumeratePhysicalDevices(*self.instance(), &mut count_mut, physical_devices.as_mut_ptr()); 
             vulkan_result!(result, physical_devices)
         }
     }
 
-    fn enumerate_all_physical_devices(self) -> Result<Vec<PhysicalDevice>, Error> {
+    fn enumerate_all_physical_devices(&self) -> Result<Vec<PhysicalDevice>, Error> {
         self.enumerate_physical_devices(try!(self.count_physical_devices()))
     }
+
+    fn get_proc_addr(&self, name: &str) -> Option<VoidFunction> {
+        match CString::new(name) {
+            Ok(c_name) => unsafe { vkGetInstanceProcAddr(*self.instance(), c_name.as_ptr()) },
+            Err(_)     => None
+        }
+    }
+
+    // Looks up the command `name` and returns it as F, or None if the
+    // instance does not have it. Panics if F is not the size of a function
+    // pointer.
+    //
+    // # Safety
+    //
+    // F must be the `unsafe extern "system" fn` type of exactly the command
+    // `name` names, as the registry declares it: the same parameters, in the
+    // same order, with the same return type. Vulkan cannot check this, and
+    // calling the command through any other type is undefined behaviour.
+    unsafe fn get_proc_addr_as<F: Copy>(&self, name: &str) -> Option<F> {
+        SpockInstance::get_proc_addr(self, name).map(|function| cast_function(function))
+    }
 }
 
 pub trait SpockPhysicalDevice {
-    fn create_device(self, DeviceCreateInfo, Option<AllocationCallbacks>) -> Result<Device, Error>;
-    fn get_features(self) -> PhysicalDeviceFeatures;
-    fn get_format_properties(self, Format) -> FormatProperties;
-    fn get_image_format_properties(self, Format, ImageType, ImageTiling, ImageUsageFlags, ImageCreateFlags) -> Result<ImageFormatProperties, Error>;
-    fn get_properties(self) -> PhysicalDeviceProperties;
-    fn get_queue_count(self) -> u32;
-    fn get_queue_family_properties(self, u32) -> Vec<QueueFamilyProperties>;
-    fn get_all_queue_family_properties(self) -> Vec<QueueFamilyProperties>;
-    fn get_memory_properties(self) -> PhysicalDeviceMemoryProperties;
-    fn get_sparse_image_format_properties_count(self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling) -> u32;
-    fn get_sparse_image_format_properties(self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling, u32) -> Vec<SparseImageFormatProperties>;
-    fn get_all_sparse_image_format_properties(self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling) -> Vec<SparseImageFormatProperties>;
-    // fn enumerate_extension_properties(self, layer_name: String, property_count: u32) -> Result<Vec<ExtensionProperty>, Error>
-    // fn enumerate_layer_properties(self, layer_name: String, property_count: u32) -> Result<Vec<LayerPropery>, Error>
+    fn create_device(&self, DeviceCreateInfo, Option<AllocationCallbacks>) -> Result<Device, Error>;
+    fn get_features(&self) -> PhysicalDeviceFeatures;
+    fn get_format_properties(&self, Format) -> FormatProperties;
+    fn get_image_format_properties(&self, Format, ImageType, ImageTiling, ImageUsageFlags, ImageCreateFlags) -> Result<ImageFormatProperties, Error>;
+    fn get_properties(&self) -> PhysicalDeviceProperties;
+    fn get_queue_count(&self) -> u32;
+    fn get_queue_family_properties(&self, u32) -> Vec<QueueFamilyProperties>;
+    fn get_all_queue_family_properties(&self) -> Vec<QueueFamilyProperties>;
+    fn get_memory_properties(&self) -> PhysicalDeviceMemoryProperties;
+    fn find_memory_type(&self, u32, MemoryPropertyFlags, MemoryPropertyFlags) -> Option<u32>;
+    fn find_memory_type_for_usage(&self, u32, MemoryUsage) -> Option<u32>;
+    fn get_sparse_image_format_properties_count(&self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling) -> u32;
+    fn get_sparse_image_format_properties(&self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling, u32) -> Vec<SparseImageFormatProperties>;
+    fn get_all_sparse_image_format_properties(&self, Format, ImageType, SampleCountFlags, ImageUsageFlags, ImageTiling) -> Vec<SparseImageFormatProperties>;
+    fn enumerate_extension_properties(&self, Option<&str>) -> Result<Vec<ExtensionProperty>, Error>;
+    fn enumerate_layer_properties(&self) -> Result<Vec<LayerProperty>, Error>;
+    fn get_surface_support(&self, u32, SurfaceKHR) -> Result<bool, Error>;
+    fn get_surface_capabilities(&self, SurfaceKHR) -> Result<SurfaceCapabilitiesKHR, Error>;
+    fn get_surface_formats(&self, SurfaceKHR) -> Result<Vec<SurfaceFormatKHR>, Error>;
+    fn get_surface_present_modes(&self, SurfaceKHR) -> Result<Vec<PresentModeKHR>, Error>;
 }
 
-impl SpockPhysicalDevice for PhysicalDevice {
-    fn get_features(self) -> PhysicalDeviceFeatures {
+impl<P: PhysicalDeviceDispatch> SpockPhysicalDevice for P {
+    fn get_features(&self) -> PhysicalDeviceFeatures {
         unsafe {
             let mut features= PhysicalDeviceFeatures{..Default::default()};
-            vkGetPhysicalDeviceFeatures(self, &mut features);
+            self.fns().vkGetPhysicalDeviceFeatures(*self.physical_device(), &mut features);
             features
         }
     }
 
-    fn get_format_properties(self, format: Format) -> FormatProperties {
+    fn get_format_properties(&self, format: Format) -> FormatProperties {
         unsafe {
             let mut properties = FormatProperties{..Default::default()};
-            vkGetPhysicalDeviceFormatProperties(self, format, &mut properties);
+            self.fns().vkGetPhysicalDeviceFormatProperties(*self.physical_device(), format, &mut properties);
             properties
         }
     }
 
-    fn get_image_format_properties(self, format: Format, image_type: ImageType, tiling: ImageTiling, usage_flags: ImageUsageFlags, create_flags: ImageCreateFlags) -> Result<ImageFormatProperties, Error> {
+    fn get_image_format_properties(&self, format: Format, image_type: ImageType, tiling: ImageTiling, usage_flags: ImageUsageFlags, create_flags: ImageCreateFlags) -> Result<ImageFormatProperties, Error> {
         unsafe {
             let mut properties = ImageFormatProperties{..Default::default()};
-            let result = vkGetPhysicalDeviceImageFormatProperties(self, format, image_type, tiling, usage_flags, create_flags, &mut properties);
+            let result = self.fns().vkGetPhysicalDeviceImageFormatProperties(*self.physical_device(), format, image_type, tiling, usage_flags, create_flags, &mut properties);
             vulkan_result!(result, properties)
         }
     }
 
-    fn get_properties(self) -> PhysicalDeviceProperties {
+    fn get_properties(&self) -> PhysicalDeviceProperties {
         unsafe {
             let mut properties = PhysicalDeviceProperties{..Default::default()};
-            vkGetPhysicalDeviceProperties(self, &mut properties);
+            self.fns().vkGetPhysicalDeviceProperties(*self.physical_device(), &mut properties);
             properties
         }
     }
 
-    fn get_queue_count(self) -> u32 {
+    fn get_queue_count(&self) -> u32 {
         unsafe {
             let mut count: u32 = 0;
-            vkGetPhysicalDeviceQueueFamilyProperties(self, &mut count, ptr::null_mut());
+            self.fns().vkGetPhysicalDeviceQueueFamilyProperties(*self.physical_device(), &mut count, ptr::null_mut());
             count
         }
     }
 
-    fn get_queue_family_properties(self, queue_count: u32) -> Vec<QueueFamilyProperties> {
+    fn get_queue_family_properties(&self, queue_count: u32) -> Vec<QueueFamilyProperties> {
         unsafe {
             let mut mut_queue_count = queue_count;
             let mut properties = Vec::with_capacity(queue_count as usize);
             properties.resize(queue_count as usize, QueueFamilyProperties{..Default::default()});
 
-            vkGetPhysicalDeviceQueueFamilyProperties(self, &mut mut_queue_count, properties.as_mut_ptr());
+            self.fns().vkGetPhysicalDeviceQueueFamilyProperties(*self.physical_device(), &mut mut_queue_count, properties.as_mut_ptr());
             properties
         }
     }
 
-    fn get_all_queue_family_properties(self) -> Vec<QueueFamilyProperties> {
+    fn get_all_queue_family_properties(&self) -> Vec<QueueFamilyProperties> {
         self.get_queue_family_properties(self.get_queue_count())
     }
 
-    fn get_memory_properties(self) -> PhysicalDeviceMemoryProperties {
+    fn get_memory_properties(&self) -> PhysicalDeviceMemoryProperties {
         unsafe {
             let mut memory_properties: PhysicalDeviceMemoryProperties = PhysicalDeviceMemoryProperties { ..Default::default() };
-            vkGetPhysicalDeviceMemoryProperties(self, &mut memory_properties);
+            self.fns().vkGetPhysicalDeviceMemoryProperties(*self.physical_device(), &mut memory_properties);
             memory_properties
         }
     }
 
-    fn create_device(self, create_info: DeviceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Device, Error> {
+    // See memory::find_memory_type, which ranks the types.
+    fn find_memory_type(&self, memory_type_bits: u32, required: MemoryPropertyFlags, preferred: MemoryPropertyFlags) -> Option<u32> {
+        memory::find_memory_type(&self.get_memory_properties(), memory_type_bits, required, preferred)
+    }
+
+    fn find_memory_type_for_usage(&self, memory_type_bits: u32, usage: MemoryUsage) -> Option<u32> {
+        memory::find_memory_type_for_usage(&self.get_memory_properties(), memory_type_bits, usage)
+    }
+
+    fn create_device(&self, create_info: DeviceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Device, Error> {
         unsafe {
-            let mut device: Device = ptr::null_mut();
-            let result = vkCreateDevice(self, &create_info, pointer_of_option!(allocator_opt), &mut device);
+            let mut device: Device = Device::null();
+            let result = vkCreateDevice(*self.physical_device(), &create_info, pointer_of_option!(allocator_opt), &mut device);
             vulkan_result!(result, device)
         }
     }
 
-    fn get_sparse_image_format_properties_count(self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling) -> u32 {
+    fn get_sparse_image_format_properties_count(&self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling) -> u32 {
         unsafe {
             let mut count: u32 = 0;
-            vkGetPhysicalDeviceSparseImageFormatProperties(self, format, image_type, sample_count, usage, tiling, &mut count, ptr::null_mut());
+            self.fns().vkGetPhysicalDeviceSparseImageFormatProperties(*self.physical_device(), format, image_type, sample_count, usage, tiling, &mut count, ptr::null_mut());
             count
         }
     }
 
-    fn get_sparse_image_format_properties(self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling, count: u32) -> Vec<SparseImageFormatProperties> {
+    fn get_sparse_image_format_properties(&self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling, count: u32) -> Vec<SparseImageFormatProperties> {
         unsafe {
             let mut mut_count = count;
             let mut properties = Vec::with_capacity(count as usize);
             properties.resize(count as usize, SparseImageFormatProperties{..Default::default()});
 
-            vkGetPhysicalDeviceSparseImageFormatProperties(self, format, image_type, sample_count, usage, tiling, &mut mut_count, properties.as_mut_ptr());
+            self.fns().vkGetPhysicalDeviceSparseImageFormatProperties(*self.physical_device(), format, image_type, sample_count, usage, tiling, &mut mut_count, properties.as_mut_ptr());
             properties
         }
     }
 
-    fn get_all_sparse_image_format_properties(self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling) -> Vec<SparseImageFormatProperties> {
+    fn get_all_sparse_image_format_properties(&self, format: Format, image_type: ImageType, sample_count: SampleCountFlags, usage: ImageUsageFlags, tiling: ImageTiling) -> Vec<SparseImageFormatProperties> {
         let count = self.get_sparse_image_format_properties_count(format, image_type, sample_count, usage, tiling);
         self.get_sparse_image_format_properties(format, image_type, sample_count, usage, tiling, count)
     }
 
+    fn enumerate_extension_properties(&self, layer_name: Option<&str>) -> Result<Vec<ExtensionProperty>, Error> {
+        let c_layer_name = try!(layer_name_of_option(layer_name));
+        let layer_name_ptr = c_layer_name.as_ref().map_or(ptr::null(), |name| name.as_ptr());
+
+        unsafe {
+            let properties = try!(enumerate(|count, values| self.fns().vkEnumerateDeviceExtensionProperties(*self.physical_device(), layer_name_ptr, count, values)));
+            Ok(properties.iter().map(ExtensionProperty::from).collect())
+        }
+    }
+
+    fn enumerate_layer_properties(&self) -> Result<Vec<LayerProperty>, Error> {
+        unsafe {
+            let properties = try!(enumerate(|count, values| self.fns().vkEnumerateDeviceLayerProperties(*self.physical_device(), count, values)));
+            Ok(properties.iter().map(LayerProperty::from).collect())
+        }
+    }
+
+    fn get_surface_support(&self, queue_family_index: u32, surface: SurfaceKHR) -> Result<bool, Error> {
+        unsafe {
+            let mut supported: Bool32 = VK_FALSE;
+            let result = self.fns().vkGetPhysicalDeviceSurfaceSupportKHR(*self.physical_device(), queue_family_index, surface, &mut supported);
+            vulkan_result!(result, supported != VK_FALSE)
+        }
+    }
+
+    fn get_surface_capabilities(&self, surface: SurfaceKHR) -> Result<SurfaceCapabilitiesKHR, Error> {
+        unsafe {
+            let mut capabilities = SurfaceCapabilitiesKHR{..Default::default()};
+            let result = self.fns().vkGetPhysicalDeviceSurfaceCapabilitiesKHR(*self.physical_device(), surface, &mut capabilities);
+            vulkan_result!(result, capabilities)
+        }
+    }
+
+    // Format and ColorSpaceKHR are open enums, so formats and color spaces
+    // newer than our registry come back as they are.
+    fn get_surface_formats(&self, surface: SurfaceKHR) -> Result<Vec<SurfaceFormatKHR>, Error> {
+        unsafe { enumerate(|count, values| self.fns().vkGetPhysicalDeviceSurfaceFormatsKHR(*self.physical_device(), surface, count, values)) }
+    }
+
+    // Read as integers first, so present modes newer than our registry can be
+    // dropped rather than turned into invalid PresentModeKHR values.
+    fn get_surface_present_modes(&self, surface: SurfaceKHR) -> Result<Vec<PresentModeKHR>, Error> {
+        unsafe {
+            let modes: Vec<i32> = try!(enumerate(|count, values: *mut i32| self.fns().vkGetPhysicalDeviceSurfacePresentModesKHR(*self.physical_device(), surface, count, values as *mut PresentModeKHR)));
+            Ok(modes.into_iter().filter_map(wsi::present_mode_of_raw).collect())
+        }
+    }
 }
 
 pub trait SpockDevice {
-    fn destroy(self, Option<AllocationCallbacks>);
-    fn get_queue(self, u32, u32) -> Result<Queue, Error>;
-    fn wait_idle(self) -> Error;
-    fn allocate_memory(self, MemoryAllocateInfo, Option<AllocationCallbacks>) -> Result<DeviceMemory, Error>;
-    fn free_memory(self, DeviceMemory, Option<AllocationCallbacks>);
-    // fn map_memory(self, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize, flags: MemoryMapFlags, pointer: *mut *mut c_void???) -> Error;
-    fn flush_mapped_memory_ranges(self, Vec<MappedMemoryRange>) -> Error;
-    fn invalidate_mapped_memory_ranges(self, Vec<MappedMemoryRange>) -> Error;
-    fn get_memory_commitment(self, DeviceMemory) -> DeviceSize;
-    fn bind_buffer_memory(self, Buffer, DeviceMemory, DeviceSize) -> Error;
-    fn bind_image_memory(self, Image, DeviceMemory, DeviceSize) -> Error;
-    fn get_buffer_memory_requirements(self, Buffer) -> MemoryRequirements;
-    fn get_image_memory_requirements(self, Image) -> MemoryRequirements;
-    fn get_image_sparse_memory_requirements_count(self, Image) -> u32;
-    fn get_image_sparse_memory_requirements(self, Image, count: u32) -> SparseImageMemoryRequirements;
-    fn get_all_image_sparse_memory_requirements(self, Image) -> SparseImageMemoryRequirements;
-    fn create_fence(self, FenceCreateInfo, Option<AllocationCallbacks>) -> Result<Fence, Error>;
-    fn destroy_fence(self, Fence, Option<AllocationCallbacks>);
-    fn reset_fences(self, Vec<Fence>) -> Error;
-    fn get_fence_status(self, Fence) -> Error;
-    fn wait_for_fences(self, Vec<Fence>, bool, u64) -> Error;
-    fn create_semaphore(self, SemaphoreCreateInfo, Option<AllocationCallbacks>) -> Result<Semaphore, Error>;
-    fn destroy_semaphore(self, Semaphore, Option<AllocationCallbacks>);
-    fn create_event(self, EventCreateInfo, Option<AllocationCallbacks>) -> Result<Event, Error>;
-    fn destroy_event(self, Event, Option<AllocationCallbacks>);
-    fn get_event_status(self, Event) -> Error;
-    fn set_event(self, Event) -> Error;
-    fn reset_event(self, Event) -> Error;
-    fn create_query_pool(self, QueryPoolCreateInfo, Option<AllocationCallbacks>) -> Result<QueryPool, Error>;
-    fn destroy_query_pool(self, QueryPool, Option<AllocationCallbacks>);
-    // fn get_query_pool_errors(self, QueryPool, u32, u32, ???...);
-    fn create_buffer(self, BufferCreateInfo, Option<AllocationCallbacks>) -> Result<Buffer, Error>;
-    fn destroy_buffer(self, Buffer, Option<AllocationCallbacks>);
-    fn create_buffer_view(self, BufferViewCreateInfo, Option<AllocationCallbacks>) -> Result<BufferView, Error>;
-    fn destroy_buffer_view(self, BufferView, Option<AllocationCallbacks>);
-    fn create_image(self, ImageCreateInfo, Option<AllocationCallbacks>) -> Result<Image, Error>;
-    fn destroy_image(self, Image, Option<AllocationCallbacks>);
-    fn get_image_subresource_layout(self, Image, ImageSubresource) -> SubresourceLayout;
-    fn create_image_view(self, ImageViewCreateInfo, Option<AllocationCallbacks>) -> Result<ImageView, Error>;
-    fn destroy_image_view(self, ImageView, Option<AllocationCallbacks>);
-    fn create_shader_module(self, ShaderModuleCreateInfo, Option<AllocationCallbacks>) -> Result<ShaderModule, Error>;
-    fn destroy_shader_module(self, ShaderModule, Option<AllocationCallbacks>);
-    fn create_pipeline_cache(self, PipelineCacheCreateInfo, Option<AllocationCallbacks>) -> Result<PipelineCache, Error>;
-    fn destroy_pipeline_cache(self, PipelineCache, Option<AllocationCallbacks>);
+    fn get_physical_device(&self) -> PhysicalDevice;
+    fn get_queue(&self, u32, u32) -> Result<Queue, Error>;
+    fn wait_idle(&self) -> Result<(), Error>;
+    fn allocate_memory(&self, MemoryAllocateInfo, Option<AllocationCallbacks>) -> Result<DeviceMemory, Error>;
+    fn free_memory(&self, DeviceMemory, Option<AllocationCallbacks>);
+    fn map_memory(&self, DeviceMemory, DeviceSize, DeviceSize) -> Result<MappedMemory<'_>, Error>;
+    fn flush_mapped_memory_ranges(&self, &[MappedMemoryRange]) -> Result<(), Error>;
+    fn invalidate_mapped_memory_ranges(&self, &[MappedMemoryRange]) -> Result<(), Error>;
+    fn get_memory_commitment(&self, DeviceMemory) -> DeviceSize;
+    fn bind_buffer_memory(&self, Buffer, DeviceMemory, DeviceSize) -> Result<(), Error>;
+    fn bind_image_memory(&self, Image, DeviceMemory, DeviceSize) -> Result<(), Error>;
+    fn get_buffer_memory_requirements(&self, Buffer) -> MemoryRequirements;
+    fn get_image_memory_requirements(&self, Image) -> MemoryRequirements;
+    fn get_image_sparse_memory_requirements_count(&self, Image) -> u32;
+    fn get_image_sparse_memory_requirements(&self, Image, count: u32) -> SparseImageMemoryRequirements;
+    fn get_all_image_sparse_memory_requirements(&self, Image) -> SparseImageMemoryRequirements;
+    fn create_fence(&self, FenceCreateInfo, Option<AllocationCallbacks>) -> Result<Fence, Error>;
+    fn destroy_fence(&self, Fence, Option<AllocationCallbacks>);
+    fn reset_fences(&self, &[Fence]) -> Result<(), Error>;
+    fn get_fence_status(&self, Fence) -> Result<Success, Error>;
+    fn wait_for_fences(&self, &[Fence], bool, u64) -> Result<Success, Error>;
+    fn create_semaphore(&self, SemaphoreCreateInfo, Option<AllocationCallbacks>) -> Result<Semaphore, Error>;
+    fn destroy_semaphore(&self, Semaphore, Option<AllocationCallbacks>);
+    fn create_event(&self, EventCreateInfo, Option<AllocationCallbacks>) -> Result<Event, Error>;
+    fn destroy_event(&self, Event, Option<AllocationCallbacks>);
+    fn get_event_status(&self, Event) -> Result<Success, Error>;
+    fn set_event(&self, Event) -> Result<(), Error>;
+    fn reset_event(&self, Event) -> Result<(), Error>;
+    fn create_query_pool(&self, QueryPoolCreateInfo, Option<AllocationCallbacks>) -> Result<QueryPool, Error>;
+    fn destroy_query_pool(&self, QueryPool, Option<AllocationCallbacks>);
+    fn get_query_pool_results<T: QueryValue>(&self, QueryPool, u32, u32, QueryResultFlags) -> Result<QueryResults<T>, Error>;
+    fn create_buffer(&self, BufferCreateInfo, Option<AllocationCallbacks>) -> Result<Buffer, Error>;
+    fn destroy_buffer(&self, Buffer, Option<AllocationCallbacks>);
+    fn create_buffer_view(&self, BufferViewCreateInfo, Option<AllocationCallbacks>) -> Result<BufferView, Error>;
+    fn destroy_buffer_view(&self, BufferView, Option<AllocationCallbacks>);
+    fn create_image(&self, ImageCreateInfo, Option<AllocationCallbacks>) -> Result<Image, Error>;
+    fn destroy_image(&self, Image, Option<AllocationCallbacks>);
+    fn get_image_subresource_layout(&self, Image, ImageSubresource) -> SubresourceLayout;
+    fn create_image_view(&self, ImageViewCreateInfo, Option<AllocationCallbacks>) -> Result<ImageView, Error>;
+    fn destroy_image_view(&self, ImageView, Option<AllocationCallbacks>);
+    fn create_shader_module(&self, ShaderModuleCreateInfo, Option<AllocationCallbacks>) -> Result<ShaderModule, Error>;
+    fn destroy_shader_module(&self, ShaderModule, Option<AllocationCallbacks>);
+    fn create_pipeline_cache(&self, PipelineCacheCreateInfo, Option<AllocationCallbacks>) -> Result<PipelineCache, Error>;
+    fn destroy_pipeline_cache(&self, PipelineCache, Option<AllocationCallbacks>);
     // fn get_pipeline_cache_data(self, PipelineCache, ???...);
-    fn merge_pipeline_caches(self, PipelineCache, Vec<PipelineCache>) -> Error;
-    fn create_graphics_pipelines(self, PipelineCache, Vec<GraphicsPipelineCreateInfo>, Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error>;
-    fn create_compute_pipelines(self, PipelineCache, Vec<ComputePipelineCreateInfo>, Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error>;
-    fn destroy_pipeline(self, Pipeline, Option<AllocationCallbacks>);
-    fn create_pipeline_layout(self, PipelineLayoutCreateInfo, Option<AllocationCallbacks>) -> Result<PipelineLayout, Error>;
-    fn destroy_pipeline_layout(self, Pipeline, Option<AllocationCallbacks>);
-    fn create_sampler(self, SamplerCreateInfo, Option<AllocationCallbacks>) -> Error;
-    fn destroy_sampler(self, Sampler, Option<AllocationCallbacks>);
-    fn create_descriptor_set_layout(self, DescriptorSetLayoutCreateInfo, Option<AllocationCallbacks>) -> Result<DescriptorSetLayout, Error>;
-    fn destroy_descriptor_set_layout(self, DescriptorSetLayout, Option<AllocationCallbacks>);
-    fn create_descriptor_pool(self, DescriptorPoolCreateInfo, Option<AllocationCallbacks>) -> Result<DescriptorPool, Error>;
-    fn destroy_descriptor_pool(self, DescriptorPool, Option<AllocationCallbacks>);
-    fn reset_descriptor_pool(self, DescriptorPool, DescriptorPoolResetFlags) -> Error;
-    fn allocate_descriptor_set(self, DescriptorSetAllocateInfo) -> Result<Vec<DescriptorSet>, Error>;
-    fn free_descriptor_sets(self, DescriptorPool, Vec<DescriptorSet>) -> Error;
-    fn update_descriptor_sets(self, Vec<WriteDescriptorSet>, Vec<CopyDescriptorSet>);
-    fn create_framebuffer(self, FramebufferCreateInfo, Option<AllocationCallbacks>) -> Result<Framebuffer, Error>;
-    fn destroy_framebuffer(self, Framebuffer, Option<AllocationCallbacks>);
-    fn create_render_pass(self, RenderPassCreateInfo, Option<AllocationCallbacks>) -> Result<RenderPass, Error>;
-    fn destroy_render_pass(self, RenderPass, Option<AllocationCallbacks>);
-    fn get_render_area_granularity(self, RenderPass) -> Extent2D;
-    fn create_command_pool(self, CommandPoolCreateInfo, Option<AllocationCallbacks>) -> Result<CommandPool, Error>;
-    fn destroy_command_pool(self, CommandPool, Option<AllocationCallbacks>);
-    fn reset_command_pool(self, CommandPool, CommandPoolResetFlags) -> Error;
-    fn allocate_command_buffers(self, CommandBufferAllocateInfo) -> Result<Vec<CommandBuffer>, Error>;
-    fn free_command_buffers(self, CommandPool, Vec<CommandBuffer>);
-    // fn get_proc_addr(self, name: String) -> ???;
+    fn merge_pipeline_caches(&self, PipelineCache, &[PipelineCache]) -> Result<(), Error>;
+    fn create_graphics_pipelines(&self, PipelineCache, &[GraphicsPipelineCreateInfo], Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error>;
+    fn create_compute_pipelines(&self, PipelineCache, &[ComputePipelineCreateInfo], Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error>;
+    fn destroy_pipeline(&self, Pipeline, Option<AllocationCallbacks>);
+    fn create_pipeline_layout(&self, PipelineLayoutCreateInfo, Option<AllocationCallbacks>) -> Result<PipelineLayout, Error>;
+    fn destroy_pipeline_layout(&self, PipelineLayout, Option<AllocationCallbacks>);
+    fn create_sampler(&self, SamplerCreateInfo, Option<AllocationCallbacks>) -> Result<Sampler, Error>;
+    fn destroy_sampler(&self, Sampler, Option<AllocationCallbacks>);
+    fn create_descriptor_set_layout(&self, DescriptorSetLayoutCreateInfo, Option<AllocationCallbacks>) -> Result<DescriptorSetLayout, Error>;
+    fn destroy_descriptor_set_layout(&self, DescriptorSetLayout, Option<AllocationCallbacks>);
+    fn create_descriptor_pool(&self, DescriptorPoolCreateInfo, Option<AllocationCallbacks>) -> Result<DescriptorPool, Error>;
+    fn destroy_descriptor_pool(&self, DescriptorPool, Option<AllocationCallbacks>);
+    fn reset_descriptor_pool(&self, DescriptorPool, DescriptorPoolResetFlags) -> Result<(), Error>;
+    fn allocate_descriptor_set(&self, DescriptorSetAllocateInfo) -> Result<Vec<DescriptorSet>, Error>;
+    fn free_descriptor_sets(&self, DescriptorPool, &[DescriptorSet]) -> Result<(), Error>;
+    fn update_descriptor_sets(&self, &[WriteDescriptorSet], &[CopyDescriptorSet]);
+    fn create_framebuffer(&self, FramebufferCreateInfo, Option<AllocationCallbacks>) -> Result<Framebuffer, Error>;
+    fn destroy_framebuffer(&self, Framebuffer, Option<AllocationCallbacks>);
+    fn create_render_pass(&self, RenderPassCreateInfo, Option<AllocationCallbacks>) -> Result<RenderPass, Error>;
+    fn destroy_render_pass(&self, RenderPass, Option<AllocationCallbacks>);
+    fn get_render_area_granularity(&self, RenderPass) -> Extent2D;
+    fn create_command_pool(&self, CommandPoolCreateInfo, Option<AllocationCallbacks>) -> Result<CommandPool, Error>;
+    fn destroy_command_pool(&self, CommandPool, Option<AllocationCallbacks>);
+    fn reset_command_pool(&self, CommandPool, CommandPoolResetFlags) -> Result<(), Error>;
+    fn allocate_command_buffers(&self, CommandBufferAllocateInfo) -> Result<Vec<CommandBuffer>, Error>;
+    fn free_command_buffers(&self, CommandPool, &[CommandBuffer]);
+    fn get_proc_addr(&self, &str) -> Option<VoidFunction>;
+    unsafe fn get_proc_addr_as<F: Copy>(&self, &str) -> Option<F>;
+    fn set_object_name<H: Handle>(&self, H, &str) -> Result<(), Error>;
 }
 
-impl SpockDevice for Device {
-    fn destroy(self, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyDevice(self, pointer_of_option!(allocator_opt)); }
+impl<D: DeviceDispatch> SpockDevice for D {
+    fn get_physical_device(&self) -> PhysicalDevice {
+        self.state().physical_device
     }
 
-    fn get_queue(self, queue_family_index: u32, queue_index: u32) -> Result<Queue, Error> {
+    fn get_queue(&self, queue_family_index: u32, queue_index: u32) -> Result<Queue, Error> {
         unsafe {
-            let mut queue: Queue = ptr::null_mut();
-            let result = vkGetDeviceQueue(self, queue_family_index, queue_index, &mut queue);
-            vulkan_result!(result, queue)
+            let mut queue: Queue = Queue::null();
+            self.state().vkGetDeviceQueue(*self.device(), queue_family_index, queue_index, &mut queue);
+            Ok(queue)
         }
     }
 
-    fn wait_idle(self) -> Error {
-        unsafe { vkDeviceWaitIdle(self) }
+    fn wait_idle(&self) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkDeviceWaitIdle(*self.device()), ()) }
     }
 
-    fn allocate_memory(self, allocate_info: MemoryAllocateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DeviceMemory, Error> {
+    fn allocate_memory(&self, allocate_info: MemoryAllocateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DeviceMemory, Error> {
         unsafe {
-            let mut memory: DeviceMemory = ptr::null_mut();
-            let result = vkAllocateMemory(self, &allocate_info, pointer_of_option!(allocator_opt), &mut memory);
+            let mut memory: DeviceMemory = DeviceMemory::null();
+            let result = vkAllocateMemory(*self.device(), &allocate_info, pointer_of_option!(allocator_opt), &mut memory);
             vulkan_result!(result, memory)
         }
     }
 
-    fn free_memory(self, memory: DeviceMemory, allocator_opt: Option<AllocationCallbacks>) {
+    // Panics if a MappedMemory of `memory` is still alive.
+    fn free_memory(&self, memory: DeviceMemory, allocator_opt: Option<AllocationCallbacks>) {
         unsafe {
-            vkFreeMemory(self, memory, pointer_of_option!(allocator_opt));
+            vkFreeMemory(*self.device(), memory, pointer_of_option!(allocator_opt));
         }
     }
 
-    // fn map_memory(...) {
-    // }
+    fn map_memory(&self, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize) -> Result<MappedMemory<'_>, Error> {
+        MappedMemory::map(self.device(), memory, offset, size)
+    }
 
-    fn flush_mapped_memory_ranges(self, ranges: Vec<MappedMemoryRange>) -> Error {
-        unsafe { vkFlushMappedMemoryRanges(self, ranges.len() as u32, ranges.as_ptr()) }
+    fn flush_mapped_memory_ranges(&self, ranges: &[MappedMemoryRange]) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkFlushMappedMemoryRanges(*self.device(), ranges.len() as u32, ranges.as_ptr()), ()) }
     }
 
-    fn invalidate_mapped_memory_ranges(self, ranges: Vec<MappedMemoryRange>) -> Error {
-        unsafe { vkInvalidateMappedMemoryRanges(self, ranges.len() as u32, ranges.as_ptr()) }
+    fn invalidate_mapped_memory_ranges(&self, ranges: &[MappedMemoryRange]) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkInvalidateMappedMemoryRanges(*self.device(), ranges.len() as u32, ranges.as_ptr()), ()) }
     }
 
-    fn get_memory_commitment(self, memory: DeviceMemory) -> DeviceSize {
+    fn get_memory_commitment(&self, memory: DeviceMemory) -> DeviceSize {
         unsafe {
             let mut commitment: DeviceSize = 0;
-            vkGetDeviceMemoryCommitment(self, memory, &mut commitment);
+            self.state().vkGetDeviceMemoryCommitment(*self.device(), memory, &mut commitment);
             commitment
         }
     }
 
-    fn bind_buffer_memory(self, buffer: Buffer, memory: DeviceMemory, size: DeviceSize) -> Error {
-        unsafe { vkBindBufferMemory(self, buffer, memory, size) }
+    fn bind_buffer_memory(&self, buffer: Buffer, memory: DeviceMemory, size: DeviceSize) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkBindBufferMemory(*self.device(), buffer, memory, size), ()) }
     }
 
-    fn bind_image_memory(self, image: Image, memory: DeviceMemory, size: DeviceSize) -> Error {
-        unsafe { vkBindImageMemory(self, image, memory, size) }
+    fn bind_image_memory(&self, image: Image, memory: DeviceMemory, size: DeviceSize) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkBindImageMemory(*self.device(), image, memory, size), ()) }
     }
 
-    fn get_buffer_memory_requirements(self, buffer: Buffer) -> MemoryRequirements {
+    fn get_buffer_memory_requirements(&self, buffer: Buffer) -> MemoryRequirements {
         unsafe {
             let mut requirements = MemoryRequirements{..Default::default()};
-            vkGetBufferMemoryRequirements(self, buffer, &mut requirements);
+            self.state().vkGetBufferMemoryRequirements(*self.device(), buffer, &mut requirements);
             requirements
         }
     }
 
-    fn get_image_memory_requirements(self, image: Image) -> MemoryRequirements {
+    fn get_image_memory_requirements(&self, image: Image) -> MemoryRequirements {
         unsafe {
             let mut requirements = MemoryRequirements{..Default::default()};
-            vkGetImageMemoryRequirements(self, image, &mut requirements);
+            self.state().vkGetImageMemoryRequirements(*self.device(), image, &mut requirements);
             requirements
         }
     }
 
-    fn get_image_sparse_memory_requirements_count(self, image: Image) -> u32 {
+    fn get_image_sparse_memory_requirements_count(&self, image: Image) -> u32 {
         unsafe {
             let mut count: u32 = 0;
-            vkGetImageSparseMemoryRequirements(self, image, &mut count, ptr::null_mut());
+            self.state().vkGetImageSparseMemoryRequirements(*self.device(), image, &mut count, ptr::null_mut());
             count
         }
     }
 
-    fn get_image_sparse_memory_requirements(self, image: Image, count: u32) -> SparseImageMemoryRequirements {
+    fn get_image_sparse_memory_requirements(&self, image: Image, count: u32) -> SparseImageMemoryRequirements {
         unsafe {
             let mut mut_count = count;
             let mut requirements = SparseImageMemoryRequirements{..Default::default()};
-            vkGetImageSparseMemoryRequirements(self, image, &mut mut_count, &mut requirements);
+            self.state().vkGetImageSparseMemoryRequirements(*self.device(), image, &mut mut_count, &mut requirements);
             requirements
         }
     }
 
-    fn get_all_image_sparse_memory_requirements(self, image: Image) -> SparseImageMemoryRequirements {
+    fn get_all_image_sparse_memory_requirements(&self, image: Image) -> SparseImageMemoryRequirements {
         self.get_image_sparse_memory_requirements(image, self.get_image_sparse_memory_requirements_count(image))
     }
 
-    fn create_fence(self, create_info: FenceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Fence, Error> {
+    fn create_fence(&self, create_info: FenceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Fence, Error> {
         unsafe {
-            let mut fence: Fence = ptr::null_mut();
-            let result = vkCreateFence(self, &create_info, pointer_of_option!(allocator_opt), &mut fence);
+            let mut fence: Fence = Fence::null();
+            let result = self.state().vkCreateFence(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut fence);
             vulkan_result!(result, fence)
         }
     }
 
-    fn destroy_fence(self, fence: Fence, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyFence(self, fence, pointer_of_option!(allocator_opt)); }
+    fn destroy_fence(&self, fence: Fence, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyFence(*self.device(), fence, pointer_of_option!(allocator_opt)); }
     }
 
-    fn reset_fences(self, fences: Vec<Fence>) -> Error {
-        unsafe { vkResetFences(self, fences.len() as u32, fences.as_ptr()) }
+    fn reset_fences(&self, fences: &[Fence]) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkResetFences(*self.device(), fences.len() as u32, fences.as_ptr()), ()) }
     }
 
-    fn get_fence_status(self, fence: Fence) -> Error {
-        unsafe { vkGetFenceStatus(self, fence) }
+    fn get_fence_status(&self, fence: Fence) -> Result<Success, Error> {
+        unsafe { self.state().vkGetFenceStatus(*self.device(), fence).result() }
     }
 
-    fn wait_for_fences(self, fences: Vec<Fence>, wait_for_all: bool, timeout: u64) -> Error {
-        unsafe { vkWaitForFences(self, fences.len() as u32, fences.as_ptr(), wait_for_all as u32, timeout) }
+    fn wait_for_fences(&self, fences: &[Fence], wait_for_all: bool, timeout: u64) -> Result<Success, Error> {
+        unsafe { self.state().vkWaitForFences(*self.device(), fences.len() as u32, fences.as_ptr(), wait_for_all as u32, timeout).result() }
     }
 
-    fn create_semaphore(self, create_info: SemaphoreCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Semaphore, Error> {
+    fn create_semaphore(&self, create_info: SemaphoreCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Semaphore, Error> {
         unsafe {
-            let mut semaphore: Semaphore = ptr::null_mut();
-            let result = vkCreateSemaphore(self, &create_info, pointer_of_option!(allocator_opt), &mut semaphore);
+            let mut semaphore: Semaphore = Semaphore::null();
+            let result = self.state().vkCreateSemaphore(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut semaphore);
             vulkan_result!(result, semaphore)
         }
     }
 
-    fn destroy_semaphore(self, semaphore: Semaphore, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroySemaphore(self, semaphore, pointer_of_option!(allocator_opt)); }
+    fn destroy_semaphore(&self, semaphore: Semaphore, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroySemaphore(*self.device(), semaphore, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_event(self, create_info: EventCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Event, Error> {
+    fn create_event(&self, create_info: EventCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Event, Error> {
         unsafe {
-            let mut event: Event = ptr::null_mut();
-            let result = vkCreateEvent(self, &create_info, pointer_of_option!(allocator_opt), &mut event);
+            let mut event: Event = Event::null();
+            let result = self.state().vkCreateEvent(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut event);
             vulkan_result!(result, event)
         }
     }
 
-    fn destroy_event(self, event: Event, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyEvent(self, event, pointer_of_option!(allocator_opt)); }
+    fn destroy_event(&self, event: Event, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyEvent(*self.device(), event, pointer_of_option!(allocator_opt)); }
     }
 
-    fn get_event_status(self, event: Event) -> Error {
-        unsafe { vkGetEventStatus(self, event) }
+    fn get_event_status(&self, event: Event) -> Result<Success, Error> {
+        unsafe { self.state().vkGetEventStatus(*self.device(), event).result() }
     }
 
-    fn set_event(self, event: Event) -> Error {
-        unsafe { vkSetEvent(self, event) }
+    fn set_event(&self, event: Event) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkSetEvent(*self.device(), event), ()) }
     }
 
-    fn reset_event(self, event: Event) -> Error {
-        unsafe { vkResetEvent(self, event) }
+    fn reset_event(&self, event: Event) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkResetEvent(*self.device(), event), ()) }
     }
 
-    fn create_query_pool(self, create_info: QueryPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<QueryPool, Error> {
+    fn create_query_pool(&self, create_info: QueryPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<QueryPool, Error> {
         unsafe {
-            let mut query_pool: QueryPool = ptr::null_mut();
-            let result = vkCreateQueryPool(self, &create_info, pointer_of_option!(allocator_opt), &mut query_pool);
+            let mut query_pool: QueryPool = QueryPool::null();
+            let result = vkCreateQueryPool(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut query_pool);
             vulkan_result!(result, query_pool)
         }
     }
 
-    fn destroy_query_pool(self, query_pool: QueryPool, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyQueryPool(self, query_pool, pointer_of_option!(allocator_opt)); }
+    fn destroy_query_pool(&self, query_pool: QueryPool, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { vkDestroyQueryPool(*self.device(), query_pool, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_buffer(self, create_info: BufferCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Buffer, Error> {
+    // The number of values per query comes from what the pool was created
+    // with, and the width of the results from T, so Result64 need not be
+    // passed. Panics if `query_pool` was not created on this device.
+    fn get_query_pool_results<T: QueryValue>(&self, query_pool: QueryPool, first_query: u32, query_count: u32, flags: QueryResultFlags) -> Result<QueryResults<T>, Error> {
+        let state = self.state();
+        let values_per_query = state.query_values(query_pool).expect("get_query_pool_results was given a query pool from another device") as usize;
+        let flags = (flags & !QueryResultFlags::Result64) | T::FLAGS;
+        let stride = values_per_query + if flags.contains(QueryResultFlags::WithAvailability) { 1 } else { 0 };
+        let mut data: Vec<T> = vec![T::default(); query_count as usize * stride];
+
         unsafe {
-            let mut buffer: Buffer = ptr::null_mut();
-            let result = vkCreateBuffer(self, &create_info, pointer_of_option!(allocator_opt), &mut buffer);
+            let result = state.vkGetQueryPoolResults(*self.device(), query_pool, first_query, query_count, mem::size_of_val(&data[..]),
+                                                     data.as_mut_ptr() as *mut c_void, (stride * mem::size_of::<T>()) as DeviceSize, flags);
+            let success = try!(result.result());
+            Ok(QueryResults::new(data, query_count as usize, values_per_query, flags, success == Success::Success))
+        }
+    }
+
+    fn create_buffer(&self, create_info: BufferCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Buffer, Error> {
+        unsafe {
+            let mut buffer: Buffer = Buffer::null();
+            let result = self.state().vkCreateBuffer(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut buffer);
             vulkan_result!(result, buffer)
         }
     }
 
-    fn destroy_buffer(self, buffer: Buffer, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyBuffer(self, buffer, pointer_of_option!(allocator_opt)); }
+    fn destroy_buffer(&self, buffer: Buffer, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyBuffer(*self.device(), buffer, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_buffer_view(self, create_info: BufferViewCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<BufferView, Error> {
+    fn create_buffer_view(&self, create_info: BufferViewCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<BufferView, Error> {
         unsafe {
-            let mut buffer_view: BufferView = ptr::null_mut();
-            let result = vkCreateBufferView(self, &create_info, pointer_of_option!(allocator_opt), &mut buffer_view);
+            let mut buffer_view: BufferView = BufferView::null();
+            let result = self.state().vkCreateBufferView(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut buffer_view);
             vulkan_result!(result, buffer_view)
         }
     }
 
-    fn destroy_buffer_view(self, buffer_view: BufferView, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyBufferView(self, buffer_view, pointer_of_option!(allocator_opt)); }
+    fn destroy_buffer_view(&self, buffer_view: BufferView, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyBufferView(*self.device(), buffer_view, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_image(self, create_info: ImageCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Image, Error> {
+    fn create_image(&self, create_info: ImageCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Image, Error> {
         unsafe {
-            let mut image: Image = ptr::null_mut();
-            let result = vkCreateImage(self, &create_info, pointer_of_option!(allocator_opt), &mut image);
+            let mut image: Image = Image::null();
+            let result = vkCreateImage(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut image);
             vulkan_result!(result, image)
         }
     }
 
-    fn destroy_image(self, image: Image, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyImage(self, image, pointer_of_option!(allocator_opt)); }
+    fn destroy_image(&self, image: Image, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { vkDestroyImage(*self.device(), image, pointer_of_option!(allocator_opt)); }
     }
 
-    fn get_image_subresource_layout(self, image: Image, subresource: ImageSubresource) -> SubresourceLayout {
+    fn get_image_subresource_layout(&self, image: Image, subresource: ImageSubresource) -> SubresourceLayout {
         unsafe {
             let mut layout = SubresourceLayout{..Default::default()};
-            vkGetImageSubresourceLayout(self, image, &subresource, &mut layout);
+            self.state().vkGetImageSubresourceLayout(*self.device(), image, &subresource, &mut layout);
             layout
         }
     }
 
-    fn create_image_view(self, create_info: ImageViewCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<ImageView, Error> {
+    fn create_image_view(&self, create_info: ImageViewCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<ImageView, Error> {
         unsafe {
-            let mut image_view: ImageView = ptr::null_mut();
-            let result = vkCreateImageView(self, &create_info, pointer_of_option!(allocator_opt), &mut image_view);
+            let mut image_view: ImageView = ImageView::null();
+            let result = self.state().vkCreateImageView(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut image_view);
             vulkan_result!(result, image_view)
         }
     }
 
-    fn destroy_image_view(self, image_view: ImageView, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyImageView(self, image_view, pointer_of_option!(allocator_opt)); }
+    fn destroy_image_view(&self, image_view: ImageView, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyImageView(*self.device(), image_view, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_shader_module(self, create_info: ShaderModuleCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<ShaderModule, Error> {
+    fn create_shader_module(&self, create_info: ShaderModuleCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<ShaderModule, Error> {
         unsafe {
-            let mut shader_module: ShaderModule = ptr::null_mut();
-            let result = vkCreateShaderModule(self, &create_info, pointer_of_option!(allocator_opt), &mut shader_module);
+            let mut shader_module: ShaderModule = ShaderModule::null();
+            let result = self.state().vkCreateShaderModule(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut shader_module);
             vulkan_result!(result, shader_module)
         }
     }
 
-    fn destroy_shader_module(self, shader_module: ShaderModule, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyShaderModule(self, shader_module, pointer_of_option!(allocator_opt)); }
+    fn destroy_shader_module(&self, shader_module: ShaderModule, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyShaderModule(*self.device(), shader_module, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_pipeline_cache(self, create_info: PipelineCacheCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<PipelineCache, Error> {
+    fn create_pipeline_cache(&self, create_info: PipelineCacheCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<PipelineCache, Error> {
         unsafe {
-            let mut pipeline_cache: PipelineCache = ptr::null_mut();
-            let result = vkCreatePipelineCache(self, &create_info, pointer_of_option!(allocator_opt), &mut pipeline_cache);
+            let mut pipeline_cache: PipelineCache = PipelineCache::null();
+            let result = self.state().vkCreatePipelineCache(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut pipeline_cache);
             vulkan_result!(result, pipeline_cache)
         }
     }
 
-    fn destroy_pipeline_cache(self, pipeline_cache: PipelineCache, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyPipelineCache(self, pipeline_cache, pointer_of_option!(allocator_opt)); }
+    fn destroy_pipeline_cache(&self, pipeline_cache: PipelineCache, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyPipelineCache(*self.device(), pipeline_cache, pointer_of_option!(allocator_opt)); }
     }
 
-    fn merge_pipeline_caches(self, destination_cache: PipelineCache, source_caches: Vec<PipelineCache>) -> Error {
+    fn merge_pipeline_caches(&self, destination_cache: PipelineCache, source_caches: &[PipelineCache]) -> Result<(), Error> {
         unsafe {
-            vkMergePipelineCaches(self, destination_cache, source_caches.len() as u32, source_caches.as_ptr())
+            vulkan_result!(self.state().vkMergePipelineCaches(*self.device(), destination_cache, source_caches.len() as u32, source_caches.as_ptr()), ())
         }
     }
 
-    fn create_graphics_pipelines(self, pipeline_cache: PipelineCache, create_infos: Vec<GraphicsPipelineCreateInfo>, allocator_opt: Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error> {
+    fn create_graphics_pipelines(&self, pipeline_cache: PipelineCache, create_infos: &[GraphicsPipelineCreateInfo], allocator_opt: Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error> {
         unsafe {
             let mut pipelines = Vec::with_capacity(create_infos.len());
-            pipelines.resize(create_infos.len(), ptr::null_mut() as Pipeline);
+            pipelines.resize(create_infos.len(), Pipeline::null());
 
-            let result = vkCreateGraphicsPipelines(self, pipeline_cache, create_infos.len() as u32, create_infos.as_ptr(), pointer_of_option!(allocator_opt), pipelines.as_mut_ptr());
+            let result = self.state().vkCreateGraphicsPipelines(*self.device(), pipeline_cache, create_infos.len() as u32, create_infos.as_ptr(), pointer_of_option!(allocator_opt), pipelines.as_mut_ptr());
             vulkan_result!(result, pipelines)
         }
     }
 
-    fn create_compute_pipelines(self, pipeline_cache: PipelineCache, create_infos: Vec<ComputePipelineCreateInfo>, allocator_opt: Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error> {
+    fn create_compute_pipelines(&self, pipeline_cache: PipelineCache, create_infos: &[ComputePipelineCreateInfo], allocator_opt: Option<AllocationCallbacks>) -> Result<Vec<Pipeline>, Error> {
         unsafe {
             let mut pipelines = Vec::with_capacity(create_infos.len());
-            pipelines.resize(create_infos.len(), ptr::null_mut() as Pipeline);
+            pipelines.resize(create_infos.len(), Pipeline::null());
 
-            let result = vkCreateComputePipelines(self, pipeline_cache, create_infos.len() as u32, create_infos.as_ptr(), pointer_of_option!(allocator_opt), pipelines.as_mut_ptr());
+            let result = self.state().vkCreateComputePipelines(*self.device(), pipeline_cache, create_infos.len() as u32, create_infos.as_ptr(), pointer_of_option!(allocator_opt), pipelines.as_mut_ptr());
             vulkan_result!(result, pipelines)
         }
     }
 
-    fn destroy_pipeline(self, pipeline: Pipeline, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyPipeline(self, pipeline, pointer_of_option!(allocator_opt)); }
+    fn destroy_pipeline(&self, pipeline: Pipeline, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyPipeline(*self.device(), pipeline, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_pipeline_layout(self, create_info: PipelineLayoutCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<PipelineLayout, Error> {
+    fn create_pipeline_layout(&self, create_info: PipelineLayoutCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<PipelineLayout, Error> {
         unsafe {
-            let mut layout: PipelineLayout = ptr::null_mut();
-            let result = vkCreatePipelineLayout(self, &create_info, pointer_of_option!(allocator_opt), &mut layout);
+            let mut layout: PipelineLayout = PipelineLayout::null();
+            let result = self.state().vkCreatePipelineLayout(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut layout);
             vulkan_result!(result, layout)
         }
     }
 
-    fn destroy_pipeline_layout(self, pipeline: Pipeline, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyPipelineLayout(self, pipeline, pointer_of_option!(allocator_opt)); }
+    fn destroy_pipeline_layout(&self, pipeline_layout: PipelineLayout, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyPipelineLayout(*self.device(), pipeline_layout, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_sampler(self, create_info: SamplerCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Error {
-        unsafe { vkCreateSampler(self, &create_info, pointer_of_option!(allocator_opt)) }
+    fn create_sampler(&self, create_info: SamplerCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Sampler, Error> {
+        unsafe {
+            let mut sampler: Sampler = Sampler::null();
+            let result = self.state().vkCreateSampler(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut sampler);
+            vulkan_result!(result, sampler)
+        }
     }
 
-    fn destroy_sampler(self, sampler: Sampler, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroySampler(self, sampler, pointer_of_option!(allocator_opt)); }
+    fn destroy_sampler(&self, sampler: Sampler, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroySampler(*self.device(), sampler, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_descriptor_set_layout(self, create_info: DescriptorSetLayoutCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DescriptorSetLayout, Error> {
+    fn create_descriptor_set_layout(&self, create_info: DescriptorSetLayoutCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DescriptorSetLayout, Error> {
         unsafe {
-            let mut layout: DescriptorSetLayout = ptr::null_mut();
-            let result = vkCreateDescriptorSetLayout(self, &create_info, pointer_of_option!(allocator_opt), &mut layout);
+            let mut layout: DescriptorSetLayout = DescriptorSetLayout::null();
+            let result = self.state().vkCreateDescriptorSetLayout(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut layout);
             vulkan_result!(result, layout)
         }
     }
 
-    fn destroy_descriptor_set_layout(self, layout: DescriptorSetLayout, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyDescriptorSetLayout(self, layout, pointer_of_option!(allocator_opt)); }
+    fn destroy_descriptor_set_layout(&self, layout: DescriptorSetLayout, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyDescriptorSetLayout(*self.device(), layout, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_descriptor_pool(self, create_info: DescriptorPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DescriptorPool, Error> {
+    fn create_descriptor_pool(&self, create_info: DescriptorPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<DescriptorPool, Error> {
         unsafe {
-            let mut pool: DescriptorPool = ptr::null_mut();
-            let result = vkCreateDescriptorPool(self, &create_info, pointer_of_option!(allocator_opt), &mut pool);
+            let mut pool: DescriptorPool = DescriptorPool::null();
+            let result = self.state().vkCreateDescriptorPool(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut pool);
             vulkan_result!(result, pool)
         }
     }
 
-    fn destroy_descriptor_pool(self, pool: DescriptorPool, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyDescriptorPool(self, pool, pointer_of_option!(allocator_opt)); }
+    fn destroy_descriptor_pool(&self, pool: DescriptorPool, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyDescriptorPool(*self.device(), pool, pointer_of_option!(allocator_opt)); }
     }
 
-    fn reset_descriptor_pool(self, pool: DescriptorPool, reset_flags: DescriptorPoolResetFlags) -> Error {
-        unsafe { vkResetDescriptorPool(self, pool, reset_flags) }
+    fn reset_descriptor_pool(&self, pool: DescriptorPool, reset_flags: DescriptorPoolResetFlags) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkResetDescriptorPool(*self.device(), pool, reset_flags), ()) }
     }
 
-    fn allocate_descriptor_set(self, allocate_info: DescriptorSetAllocateInfo) -> Result<Vec<DescriptorSet>, Error> {
+    fn allocate_descriptor_set(&self, allocate_info: DescriptorSetAllocateInfo) -> Result<Vec<DescriptorSet>, Error> {
         unsafe {
             let mut descriptor_sets = Vec::with_capacity(allocate_info.descriptorSetCount as usize);
-            descriptor_sets.resize(allocate_info.descriptorSetCount as usize, ptr::null_mut());
+            descriptor_sets.resize(allocate_info.descriptorSetCount as usize, DescriptorSet::null());
 
-            let result = vkAllocateDescriptorSets(self, &allocate_info, descriptor_sets.as_mut_ptr());
+            let result = self.state().vkAllocateDescriptorSets(*self.device(), &allocate_info, descriptor_sets.as_mut_ptr());
             vulkan_result!(result, descriptor_sets)
         }
     }
 
-    fn free_descriptor_sets(self, pool: DescriptorPool, descriptor_sets: Vec<DescriptorSet>) -> Error {
-        unsafe { vkFreeDescriptorSets(self, pool, descriptor_sets.len() as u32, descriptor_sets.as_ptr()) }
+    fn free_descriptor_sets(&self, pool: DescriptorPool, descriptor_sets: &[DescriptorSet]) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkFreeDescriptorSets(*self.device(), pool, descriptor_sets.len() as u32, descriptor_sets.as_ptr()), ()) }
     }
 
-    fn update_descriptor_sets(self, writes: Vec<WriteDescriptorSet>, copies: Vec<CopyDescriptorSet>) {
-        unsafe { vkUpdateDescriptorSets(self, writes.len() as u32, writes.as_ptr(), copies.len() as u32, copies.as_ptr()); }
+    fn update_descriptor_sets(&self, writes: &[WriteDescriptorSet], copies: &[CopyDescriptorSet]) {
+        unsafe { self.state().vkUpdateDescriptorSets(*self.device(), writes.len() as u32, writes.as_ptr(), copies.len() as u32, copies.as_ptr()); }
     }
 
-    fn create_framebuffer(self, create_info: FramebufferCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Framebuffer, Error> {
+    fn create_framebuffer(&self, create_info: FramebufferCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<Framebuffer, Error> {
         unsafe {
-            let mut framebuffer: Framebuffer = ptr::null_mut();
-            let result = vkCreateFramebuffer(self, &create_info, pointer_of_option!(allocator_opt), &mut framebuffer);
+            let mut framebuffer: Framebuffer = Framebuffer::null();
+            let result = self.state().vkCreateFramebuffer(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut framebuffer);
             vulkan_result!(result, framebuffer)
         }
     }
 
-    fn destroy_framebuffer(self, framebuffer: Framebuffer, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyFramebuffer(self, framebuffer, pointer_of_option!(allocator_opt)); }
+    fn destroy_framebuffer(&self, framebuffer: Framebuffer, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyFramebuffer(*self.device(), framebuffer, pointer_of_option!(allocator_opt)); }
     }
 
-    fn create_render_pass(self, create_info: RenderPassCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<RenderPass, Error> {
+    fn create_render_pass(&self, create_info: RenderPassCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<RenderPass, Error> {
         unsafe {
-            let mut render_pass: RenderPass = ptr::null_mut();
-            let result = vkCreateRenderPass(self, &create_info, pointer_of_option!(allocator_opt), &mut render_pass);
+            let mut render_pass: RenderPass = RenderPass::null();
+            let result = self.state().vkCreateRenderPass(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut render_pass);
             vulkan_result!(result, render_pass)
         }
     }
 
-    fn destroy_render_pass(self, render_pass: RenderPass, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyRenderPass(self, render_pass, pointer_of_option!(allocator_opt)); }
+    fn destroy_render_pass(&self, render_pass: RenderPass, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyRenderPass(*self.device(), render_pass, pointer_of_option!(allocator_opt)); }
     }
 
-    fn get_render_area_granularity(self, render_pass: RenderPass) -> Extent2D {
+    fn get_render_area_granularity(&self, render_pass: RenderPass) -> Extent2D {
         unsafe {
             let mut extent: Extent2D = Extent2D{..Default::default()};
-            vkGetRenderAreaGranularity(self, render_pass, &mut extent);
+            self.state().vkGetRenderAreaGranularity(*self.device(), render_pass, &mut extent);
             extent
         }
     }
 
-    fn create_command_pool(self, create_info: CommandPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<CommandPool, Error> {
+    fn create_command_pool(&self, create_info: CommandPoolCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<CommandPool, Error> {
         unsafe {
-            let mut pool: CommandPool = ptr::null_mut();
-            let result = vkCreateCommandPool(self, &create_info, pointer_of_option!(allocator_opt), &mut pool);
+            let mut pool: CommandPool = CommandPool::null();
+            let result = self.state().vkCreateCommandPool(*self.device(), &create_info, pointer_of_option!(allocator_opt), &mut pool);
             vulkan_result!(result, pool)
         }
     }
 
-    fn destroy_command_pool(self, pool: CommandPool, allocator_opt: Option<AllocationCallbacks>) {
-        unsafe { vkDestroyCommandPool(self, pool, pointer_of_option!(allocator_opt)); }
+    fn destroy_command_pool(&self, pool: CommandPool, allocator_opt: Option<AllocationCallbacks>) {
+        unsafe { self.state().vkDestroyCommandPool(*self.device(), pool, pointer_of_option!(allocator_opt)); }
     }
 
-    fn reset_command_pool(self, pool: CommandPool, reset_flags: CommandPoolResetFlags) -> Error {
-        unsafe { vkResetCommandPool(self, pool, reset_flags) }
+    fn reset_command_pool(&self, pool: CommandPool, reset_flags: CommandPoolResetFlags) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkResetCommandPool(*self.device(), pool, reset_flags), ()) }
     }
 
-    fn allocate_command_buffers(self, allocate_info: CommandBufferAllocateInfo) -> Result<Vec<CommandBuffer>, Error> {
+    fn allocate_command_buffers(&self, allocate_info: CommandBufferAllocateInfo) -> Result<Vec<CommandBuffer>, Error> {
         unsafe {
             let mut buffers = Vec::with_capacity(allocate_info.commandBufferCount as usize);
-            buffers.resize(allocate_info.commandBufferCount as usize, ptr::null_mut());
+            buffers.resize(allocate_info.commandBufferCount as usize, CommandBuffer::null());
 
-            let result = vkAllocateCommandBuffers(self, &allocate_info, buffers.as_mut_ptr());
+            let result = self.state().vkAllocateCommandBuffers(*self.device(), &allocate_info, buffers.as_mut_ptr());
             vulkan_result!(result, buffers)
         }
     }
 
-    fn free_command_buffers(self, pool: CommandPool, buffers: Vec<CommandBuffer>) {
-        unsafe { vkFreeCommandBuffers(self, pool, buffers.len() as u32, buffers.as_ptr()); }
+    fn free_command_buffers(&self, pool: CommandPool, buffers: &[CommandBuffer]) {
+        unsafe { self.state().vkFreeCommandBuffers(*self.device(), pool, buffers.len() as u32, buffers.as_ptr()); }
+    }
+
+    fn get_proc_addr(&self, name: &str) -> Option<VoidFunction> {
+        match CString::new(name) {
+            Ok(c_name) => unsafe { self.state().vkGetDeviceProcAddr(*self.device(), c_name.as_ptr()) },
+            Err(_)     => None
+        }
+    }
+
+    // As SpockInstance::get_proc_addr_as, for a command of this device.
+    //
+    // # Safety
+    //
+    // As for SpockInstance::get_proc_addr_as: F must be the exact
+    // `unsafe extern "system" fn` type of the command `name` names.
+    unsafe fn get_proc_addr_as<F: Copy>(&self, name: &str) -> Option<F> {
+        SpockDevice::get_proc_addr(self, name).map(|function| cast_function(function))
+    }
+
+    // Names the object in validation messages and debuggers. Does nothing
+    // unless VK_EXT_debug_utils was enabled on the instance.
+    fn set_object_name<H: Handle>(&self, handle: H, name: &str) -> Result<(), Error> {
+        let set_name = match self.state().vkSetDebugUtilsObjectNameEXT {
+            Some(set_name) => set_name,
+            None           => return Ok(())
+        };
+        let c_name = debug_name(name);
+        let name_info = DebugUtilsObjectNameInfoEXT {
+            objectType: H::OBJECT_TYPE,
+            objectHandle: handle.as_u64(),
+            pObjectName: c_name.as_ptr(),
+            ..Default::default()
+        };
+        unsafe { vulkan_result!(set_name(*self.device(), &name_info), ()) }
     }
 }
 
 pub trait SpockQueue {
-    fn submit(self, u32, Vec<SubmitInfo>) -> Error;
-    fn wait_idle(self) -> Error;
-    fn bind_sparse(self, Vec<BindSparseInfo>, Fence) -> Error;
+    fn submit(&mut self, &[SubmitInfo], Fence) -> Result<(), Error>;
+    fn wait_idle(&mut self) -> Result<(), Error>;
+    fn bind_sparse(&mut self, &[BindSparseInfo], Fence) -> Result<(), Error>;
 }
 
-impl SpockQueue for Queue {
-    fn submit(self, count: u32, info: Vec<SubmitInfo>) -> Error {
-        unsafe { vkQueueSubmit(self, count, info.as_ptr()) }
+impl<Q: QueueDispatch> SpockQueue for Q {
+    fn submit(&mut self, info: &[SubmitInfo], fence: Fence) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkQueueSubmit(*self.queue(), info.len() as u32, info.as_ptr(), fence), ()) }
     }
 
-    fn wait_idle(self) -> Error {
-        unsafe { vkQueueWaitIdle(self) }
+    fn wait_idle(&mut self) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkQueueWaitIdle(*self.queue()), ()) }
     }
 
-    fn bind_sparse(self, bind_info: Vec<BindSparseInfo>, fence: Fence) -> Error {
-        unsafe { vkQueueBindSparse(self, bind_info.len() as u32, bind_info.as_ptr(), fence) }
+    fn bind_sparse(&mut self, bind_info: &[BindSparseInfo], fence: Fence) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkQueueBindSparse(*self.queue(), bind_info.len() as u32, bind_info.as_ptr(), fence), ()) }
+    }
+}
+
+const MAX_UPDATE_BUFFER_SIZE: usize = 65536;
+
+// Types whose every byte is initialized, whatever their value, so that a
+// slice of them can be read as bytes and copied to the device. Implementors
+// have no padding, no pointers and no invalid bit patterns: the primitive
+// numbers, arrays of them, and #[repr(C)] structs of them laid out with no
+// gaps, which must be marked with an unsafe impl of their own.
+pub unsafe trait PlainData: Copy {}
+
+unsafe impl PlainData for u8 {}
+unsafe impl PlainData for u16 {}
+unsafe impl PlainData for u32 {}
+unsafe impl PlainData for u64 {}
+unsafe impl PlainData for i8 {}
+unsafe impl PlainData for i16 {}
+unsafe impl PlainData for i32 {}
+unsafe impl PlainData for i64 {}
+unsafe impl PlainData for f32 {}
+unsafe impl PlainData for f64 {}
+unsafe impl<T: PlainData, const N: usize> PlainData for [T; N] {}
+
+pub fn bytes_of<T: PlainData>(values: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values)) }
+}
+
+// Describes a #[repr(C)] struct laid out to match a shader's push constant
+// block, so the range a PipelineLayoutCreateInfo needs for it comes from the
+// struct itself. Implement it with the push_constants! macro.
+pub trait PushConstants: Copy {
+    const STAGES: ShaderStageFlags;
+    const OFFSET: u32;
+
+    fn range() -> PushConstantRange {
+        PushConstantRange {
+            stageFlags: Self::STAGES,
+            offset: Self::OFFSET,
+            size: mem::size_of::<Self>() as u32
+        }
     }
 }
 
+// Implements PushConstants for a struct, read by the shader stages `$stages`
+// starting at `$offset`, or 0 if none is given:
+//
+//     push_constants!(Transform, ShaderStageFlags::Vertex);
+//     push_constants!(Material, ShaderStageFlags::Fragment, 64);
+#[macro_export]
+macro_rules! push_constants {
+    ($name:ty, $stages:expr) => {
+        push_constants!($name, $stages, 0);
+    };
+    ($name:ty, $stages:expr, $offset:expr) => {
+        impl $crate::PushConstants for $name {
+            const STAGES: $crate::types::ShaderStageFlags = $stages;
+            const OFFSET: u32 = $offset;
+        }
+    };
+}
+
 pub trait SpockCommandBuffer {
-    fn begin(self, CommandBufferBeginInfo) -> Error;
-    fn end(self) -> Error;
-    fn reset(self, CommandBufferResetFlags) -> Error;
-    fn cmd_bind_pipeline(self, PipelineBindPoint, Pipeline);
-    fn cmd_set_viewport(self, u32, Vec<Viewport>);
-    fn cmd_set_scissor(self, u32, Vec<Rect2D>);
-    fn cmd_set_line_width(self, f32);
-    fn cmd_set_depth_bias(self, f32, f32, f32);
-    fn cmd_set_blend_constants(self, [f32; 4]);
-    fn cmd_set_depth_bounds(self, f32, f32);
-    fn cmd_set_stencil_compare_mask(self, StencilFaceFlags, u32);
-    fn cmd_set_stencil_write_mask(self, StencilFaceFlags, u32);
-    fn cmd_set_stencil_reference(self, StencilFaceFlags, u32);
-    fn cmd_bind_descriptor_sets(self, PipelineBindPoint, PipelineLayout, u32, Vec<DescriptorSet>, Vec<u32>);
-    fn cmd_bind_index_buffer(self, Buffer, DeviceSize, IndexType);
-    fn cmd_bind_vertex_buffer(self, u32, Vec<Buffer>, Vec<DeviceSize>);
-    fn cmd_draw(self, u32, u32, u32, u32);
-    fn cmd_draw_indexed(self, u32, u32, u32, i32, u32);
-    fn cmd_draw_indirect(self, Buffer, DeviceSize, u32, u32);
-    fn cmd_draw_indexed_indirect(self, Buffer, DeviceSize, u32, u32);
-    fn cmd_dispatch(self, u32, u32, u32);
-    fn cmd_dispatch_indirect(self, Buffer, DeviceSize);
-    fn cmd_copy_buffer(self, Buffer, Buffer, Vec<BufferCopy>);
-    fn cmd_copy_image(self, Image, ImageLayout, Image, ImageLayout, Vec<ImageCopy>);
-    fn cmd_blit_image(self, Image, ImageLayout, Image, ImageLayout, Vec<ImageBlit>, Filter);
-    fn cmd_copy_buffer_to_image(self, Buffer, Image, ImageLayout, Vec<BufferImageCopy>);
-    fn cmd_copy_image_to_buffer(self, Image, ImageLayout, Buffer, Vec<BufferImageCopy>);
-    // cmd_fn update_buffer(self, Buffer, DeviceSize, DeviceSize, ???);
-    fn cmd_fill_buffer(self, Buffer, DeviceSize, DeviceSize, u32);
-    fn cmd_clear_color_image(self, Image, ImageLayout, ClearColorValue, Vec<ImageSubresourceRange>);
-    fn cmd_clear_depth_stencil_image(self, Image, ImageLayout, ClearDepthStencilValue, Vec<ImageSubresourceRange>);
-    fn cmd_clear_attachments(self, Vec<ClearAttachment>, Vec<ClearRect>);
-    fn cmd_resolve_image(self, Image, ImageLayout, Image, ImageLayout, Vec<ImageResolve>);
-    fn cmd_set_event(self, Event, PipelineStageFlags);
-    fn cmd_reset_event(self, Event, PipelineStageFlags);
-    fn cmd_wait_events(self, Vec<Event>, PipelineStageFlags, PipelineStageFlags, Vec<MemoryBarrier>, Vec<BufferMemoryBarrier>, Vec<ImageMemoryBarrier>);
-    fn cmd_pipeline_barrier(self, PipelineStageFlags, PipelineStageFlags, DependencyFlags, Vec<MemoryBarrier>, Vec<BufferMemoryBarrier>, Vec<ImageMemoryBarrier>);
-    fn cmd_begin_query(self, QueryPool, u32, QueryControlFlags);
-    fn cmd_end_query(self, QueryPool, u32);
-    fn cmd_reset_query_pool(self, QueryPool, u32, u32);
-    fn cmd_write_timestamp(self, PipelineStageFlags, QueryPool, u32);
-    fn cmd_copy_query_pool_errors(self, QueryPool, u32, u32, Buffer, DeviceSize, DeviceSize, QueryErrorFlags);
-    // cmd_fn push_constants(self, PipelineLayout, ShaderStageFlags, u32, u32, ???);
-    fn cmd_begin_render_pass(self, RenderPassBeginInfo, SubpassContents);
-    fn cmd_next_subpass(self, SubpassContents);
-    fn cmd_end_render_pass(self);
-    fn cmd_execute_commands(self, Vec<CommandBuffer>);
+    fn begin(&mut self, CommandBufferBeginInfo) -> Result<(), Error>;
+    fn end(&mut self) -> Result<(), Error>;
+    fn reset(&mut self, CommandBufferResetFlags) -> Result<(), Error>;
+    fn cmd_bind_pipeline(&mut self, PipelineBindPoint, Pipeline);
+    fn cmd_set_viewport(&mut self, u32, &[Viewport]);
+    fn cmd_set_scissor(&mut self, u32, &[Rect2D]);
+    fn cmd_set_line_width(&mut self, f32);
+    fn cmd_set_depth_bias(&mut self, f32, f32, f32);
+    fn cmd_set_blend_constants(&mut self, [f32; 4]);
+    fn cmd_set_depth_bounds(&mut self, f32, f32);
+    fn cmd_set_stencil_compare_mask(&mut self, StencilFaceFlags, u32);
+    fn cmd_set_stencil_write_mask(&mut self, StencilFaceFlags, u32);
+    fn cmd_set_stencil_reference(&mut self, StencilFaceFlags, u32);
+    fn cmd_bind_descriptor_sets(&mut self, PipelineBindPoint, PipelineLayout, u32, &[DescriptorSet], &[u32]);
+    fn cmd_bind_index_buffer(&mut self, Buffer, DeviceSize, IndexType);
+    fn cmd_bind_vertex_buffers(&mut self, u32, &[Buffer], &[DeviceSize]);
+    fn cmd_draw(&mut self, u32, u32, u32, u32);
+    fn cmd_draw_indexed(&mut self, u32, u32, u32, i32, u32);
+    fn cmd_draw_indirect(&mut self, Buffer, DeviceSize, u32, u32);
+    fn cmd_draw_indexed_indirect(&mut self, Buffer, DeviceSize, u32, u32);
+    fn cmd_dispatch(&mut self, u32, u32, u32);
+    fn cmd_dispatch_indirect(&mut self, Buffer, DeviceSize);
+    fn cmd_copy_buffer(&mut self, Buffer, Buffer, &[BufferCopy]);
+    fn cmd_copy_image(&mut self, Image, ImageLayout, Image, ImageLayout, &[ImageCopy]);
+    fn cmd_blit_image(&mut self, Image, ImageLayout, Image, ImageLayout, &[ImageBlit], Filter);
+    fn cmd_copy_buffer_to_image(&mut self, Buffer, Image, ImageLayout, &[BufferImageCopy]);
+    fn cmd_copy_image_to_buffer(&mut self, Image, ImageLayout, Buffer, &[BufferImageCopy]);
+    fn cmd_update_buffer<T: PlainData>(&mut self, Buffer, DeviceSize, &[T]);
+    fn cmd_fill_buffer(&mut self, Buffer, DeviceSize, DeviceSize, u32);
+    fn cmd_clear_color_image(&mut self, Image, ImageLayout, ClearColorValue, &[ImageSubresourceRange]);
+    fn cmd_clear_depth_stencil_image(&mut self, Image, ImageLayout, ClearDepthStencilValue, &[ImageSubresourceRange]);
+    fn cmd_clear_attachments(&mut self, &[ClearAttachment], &[ClearRect]);
+    fn cmd_resolve_image(&mut self, Image, ImageLayout, Image, ImageLayout, &[ImageResolve]);
+    fn cmd_set_event(&mut self, Event, PipelineStageFlags);
+    fn cmd_reset_event(&mut self, Event, PipelineStageFlags);
+    fn cmd_wait_events(&mut self, &[Event], PipelineStageFlags, PipelineStageFlags, &[MemoryBarrier], &[BufferMemoryBarrier], &[ImageMemoryBarrier]);
+    fn cmd_pipeline_barrier(&mut self, PipelineStageFlags, PipelineStageFlags, DependencyFlags, &[MemoryBarrier], &[BufferMemoryBarrier], &[ImageMemoryBarrier]);
+    fn cmd_begin_query(&mut self, QueryPool, u32, QueryControlFlags);
+    fn cmd_end_query(&mut self, QueryPool, u32);
+    fn cmd_reset_query_pool(&mut self, QueryPool, u32, u32);
+    fn cmd_write_timestamp(&mut self, PipelineStageFlags, QueryPool, u32);
+    fn cmd_copy_query_pool_results(&mut self, QueryPool, u32, u32, Buffer, DeviceSize, DeviceSize, QueryResultFlags);
+    fn cmd_push_constants<T: PushConstants>(&mut self, PipelineLayout, &T);
+    fn cmd_begin_render_pass(&mut self, RenderPassBeginInfo, SubpassContents);
+    fn cmd_next_subpass(&mut self, SubpassContents);
+    fn cmd_end_render_pass(&mut self);
+    fn cmd_execute_commands(&mut self, &[CommandBuffer]);
+    fn cmd_begin_label(&mut self, &str, [f32; 4]);
+    fn cmd_end_label(&mut self);
+    fn cmd_insert_label(&mut self, &str, [f32; 4]);
+    fn cmd_label_scope(&mut self, &str, [f32; 4]) -> LabelScope<'_, Self> where Self: Sized;
 }
 
-impl SpockCommandBuffer for CommandBuffer {
-    fn begin(self, info: CommandBufferBeginInfo) -> Error {
-        unsafe { vkBeginCommandBuffer(self, &info) }
+impl<C: CommandBufferDispatch> SpockCommandBuffer for C {
+    fn begin(&mut self, info: CommandBufferBeginInfo) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkBeginCommandBuffer(*self.command_buffer(), &info), ()) }
     }
 
-    fn end(self) -> Error {
-        unsafe { vkEndCommandBuffer(self) }
+    fn end(&mut self) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkEndCommandBuffer(*self.command_buffer()), ()) }
     }
 
-    fn reset(self, flags: CommandBufferResetFlags) -> Error {
-        unsafe { vkResetCommandBuffer(self, flags) }
+    fn reset(&mut self, flags: CommandBufferResetFlags) -> Result<(), Error> {
+        unsafe { vulkan_result!(self.state().vkResetCommandBuffer(*self.command_buffer(), flags), ()) }
     }
 
-    fn cmd_bind_pipeline(self, bind_point: PipelineBindPoint, pipeline: Pipeline) {
-        unsafe { vkCmdBindPipeline(self, bind_point, pipeline); }
+    fn cmd_bind_pipeline(&mut self, bind_point: PipelineBindPoint, pipeline: Pipeline) {
+        unsafe { self.state().vkCmdBindPipeline(*self.command_buffer(), bind_point, pipeline); }
     }
 
-    fn cmd_set_viewport(self, first_viewport: u32, viewports: Vec<Viewport>) {
-        unsafe { vkCmdSetViewport(self, first_viewport, viewports.len() as u32, viewports.as_ptr()); }
+    fn cmd_set_viewport(&mut self, first_viewport: u32, viewports: &[Viewport]) {
+        unsafe { self.state().vkCmdSetViewport(*self.command_buffer(), first_viewport, viewports.len() as u32, viewports.as_ptr()); }
     }
 
-    fn cmd_set_scissor(self, first_scissor: u32, scissors: Vec<Rect2D>) {
-        unsafe { vkCmdSetScissor(self, first_scissor, scissors.len() as u32, scissors.as_ptr()); }
+    fn cmd_set_scissor(&mut self, first_scissor: u32, scissors: &[Rect2D]) {
+        unsafe { self.state().vkCmdSetScissor(*self.command_buffer(), first_scissor, scissors.len() as u32, scissors.as_ptr()); }
     }
 
-    fn cmd_set_line_width(self, width: f32) {
-        unsafe { vkCmdSetLineWidth(self, width); }
+    fn cmd_set_line_width(&mut self, width: f32) {
+        unsafe { self.state().vkCmdSetLineWidth(*self.command_buffer(), width); }
     }
 
-    fn cmd_set_depth_bias(self, constant_factor: f32, clamp: f32, slope_factor: f32) {
-        unsafe { vkCmdSetDepthBias(self, constant_factor, clamp, slope_factor); }
+    fn cmd_set_depth_bias(&mut self, constant_factor: f32, clamp: f32, slope_factor: f32) {
+        unsafe { self.state().vkCmdSetDepthBias(*self.command_buffer(), constant_factor, clamp, slope_factor); }
     }
 
-    fn cmd_set_blend_constants(self, constants: [f32; 4]) {
-        unsafe { vkCmdSetBlendConstants(self, constants); }
+    fn cmd_set_blend_constants(&mut self, constants: [f32; 4]) {
+        unsafe { self.state().vkCmdSetBlendConstants(*self.command_buffer(), &constants); }
     }
 
-    fn cmd_set_depth_bounds(self, min: f32, max: f32) {
-        unsafe { vkCmdSetDepthBounds(self, min, max); }
+    fn cmd_set_depth_bounds(&mut self, min: f32, max: f32) {
+        unsafe { self.state().vkCmdSetDepthBounds(*self.command_buffer(), min, max); }
     }
 
-    fn cmd_set_stencil_compare_mask(self, flags: StencilFaceFlags, compare_mask: u32) {
-        unsafe { vkCmdSetStencilCompareMask(self, flags, compare_mask); }
+    fn cmd_set_stencil_compare_mask(&mut self, flags: StencilFaceFlags, compare_mask: u32) {
+        unsafe { self.state().vkCmdSetStencilCompareMask(*self.command_buffer(), flags, compare_mask); }
     }
 
-    fn cmd_set_stencil_write_mask(self, flags: StencilFaceFlags, write_mask: u32) {
-        unsafe { vkCmdSetStencilWriteMask(self, flags, write_mask); }
+    fn cmd_set_stencil_write_mask(&mut self, flags: StencilFaceFlags, write_mask: u32) {
+        unsafe { self.state().vkCmdSetStencilWriteMask(*self.command_buffer(), flags, write_mask); }
     }
 
-    fn cmd_set_stencil_reference(self, flags: StencilFaceFlags, reference: u32) {
-        unsafe { vkCmdSetStencilReference(self, flags, reference); }
+    fn cmd_set_stencil_reference(&mut self, flags: StencilFaceFlags, reference: u32) {
+        unsafe { self.state().vkCmdSetStencilReference(*self.command_buffer(), flags, reference); }
     }
 
-    fn cmd_bind_descriptor_sets(self, bind_point: PipelineBindPoint, layout: PipelineLayout, first_set: u32, descriptor_sets: Vec<DescriptorSet>, dynamic_offsets: Vec<u32>) {
-        unsafe { vkCmdBindDescriptorSets(self, bind_point, layout, first_set, descriptor_sets.len() as u32, descriptor_sets.as_ptr(), dynamic_offsets.len() as u32, dynamic_offsets.as_ptr()); }
+    fn cmd_bind_descriptor_sets(&mut self, bind_point: PipelineBindPoint, layout: PipelineLayout, first_set: u32, descriptor_sets: &[DescriptorSet], dynamic_offsets: &[u32]) {
+        unsafe { self.state().vkCmdBindDescriptorSets(*self.command_buffer(), bind_point, layout, first_set, descriptor_sets.len() as u32, descriptor_sets.as_ptr(), dynamic_offsets.len() as u32, dynamic_offsets.as_ptr()); }
     }
 
-    fn cmd_bind_index_buffer(self, buffer: Buffer, offset: DeviceSize, index_type: IndexType) {
-        unsafe { vkCmdBindIndexBuffer(self, buffer, offset, index_type); }
+    fn cmd_bind_index_buffer(&mut self, buffer: Buffer, offset: DeviceSize, index_type: IndexType) {
+        unsafe { self.state().vkCmdBindIndexBuffer(*self.command_buffer(), buffer, offset, index_type); }
     }
 
-    fn cmd_bind_vertex_buffer(self, first_binding: u32, bindings: Vec<Buffer>, offsets: Vec<DeviceSize>) {
+    fn cmd_bind_vertex_buffers(&mut self, first_binding: u32, bindings: &[Buffer], offsets: &[DeviceSize]) {
         unsafe {
             if bindings.len() != offsets.len() {
-                panic!("CommandBuffer.cmd_bind_vertex_buffer must be called with equal length bindings and offsets");
+                panic!("CommandBuffer.cmd_bind_vertex_buffers must be called with equal length bindings and offsets");
             }
 
-            vkCmdBindVertexBuffer(self, first_binding, bindings.len() as u32, bindings.as_ptr(), offsets.as_ptr());
+            self.state().vkCmdBindVertexBuffers(*self.command_buffer(), first_binding, bindings.len() as u32, bindings.as_ptr(), offsets.as_ptr());
         }
     }
 
-    fn cmd_draw(self, vertex_count: u32, instance_count: u32, first_vertex: u32, first_instance: u32) {
-        unsafe { vkCmdDraw(self, vertex_count, instance_count, first_vertex, first_instance); }
+    fn cmd_draw(&mut self, vertex_count: u32, instance_count: u32, first_vertex: u32, first_instance: u32) {
+        unsafe { self.state().vkCmdDraw(*self.command_buffer(), vertex_count, instance_count, first_vertex, first_instance); }
     }
 
-    fn cmd_draw_indexed(self, index_count: u32, instance_count: u32, first_index: u32, vertex_offset: i32, first_instance: u32) {
-        unsafe { vkCmdDrawIndexed(self, index_count, instance_count, first_index, vertex_offset, first_instance); }
+    fn cmd_draw_indexed(&mut self, index_count: u32, instance_count: u32, first_index: u32, vertex_offset: i32, first_instance: u32) {
+        unsafe { self.state().vkCmdDrawIndexed(*self.command_buffer(), index_count, instance_count, first_index, vertex_offset, first_instance); }
     }
 
-    fn cmd_draw_indirect(self, buffer: Buffer, offset: DeviceSize, draw_count: u32, stride: u32) {
-        unsafe { vkCmdDrawIndirect(self, buffer, offset, draw_count, stride); }
+    fn cmd_draw_indirect(&mut self, buffer: Buffer, offset: DeviceSize, draw_count: u32, stride: u32) {
+        unsafe { self.state().vkCmdDrawIndirect(*self.command_buffer(), buffer, offset, draw_count, stride); }
     }
 
-    fn cmd_draw_indexed_indirect(self, buffer: Buffer, offset: DeviceSize, draw_count: u32, stride: u32) {
-        unsafe { vkCmdDrawIndexedIndirect(self, buffer, offset, draw_count, stride); }
+    fn cmd_draw_indexed_indirect(&mut self, buffer: Buffer, offset: DeviceSize, draw_count: u32, stride: u32) {
+        unsafe { self.state().vkCmdDrawIndexedIndirect(*self.command_buffer(), buffer, offset, draw_count, stride); }
     }
 
-    fn cmd_dispatch(self, x: u32, y: u32, z: u32) {
-        unsafe { vkCmdDispatch(self, x, y, z); }
+    fn cmd_dispatch(&mut self, x: u32, y: u32, z: u32) {
+        unsafe { self.state().vkCmdDispatch(*self.command_buffer(), x, y, z); }
     }
 
-    fn cmd_dispatch_indirect(self, buffer: Buffer, offset: DeviceSize) {
-        unsafe { vkCmdDispatchIndirect(self, buffer, offset); }
+    fn cmd_dispatch_indirect(&mut self, buffer: Buffer, offset: DeviceSize) {
+        unsafe { self.state().vkCmdDispatchIndirect(*self.command_buffer(), buffer, offset); }
     }
 
-    fn cmd_copy_buffer(self, source: Buffer, destination: Buffer, regions: Vec<BufferCopy>) {
-        unsafe { vkCmdCopyBuffer(self, source, destination, regions.len() as u32, regions.as_ptr()); }
+    fn cmd_copy_buffer(&mut self, source: Buffer, destination: Buffer, regions: &[BufferCopy]) {
+        unsafe { self.state().vkCmdCopyBuffer(*self.command_buffer(), source, destination, regions.len() as u32, regions.as_ptr()); }
     }
 
-    fn cmd_copy_image(self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: Vec<ImageCopy>) {
-        unsafe { vkCmdCopyImage(self, source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr()); }
+    fn cmd_copy_image(&mut self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: &[ImageCopy]) {
+        unsafe { self.state().vkCmdCopyImage(*self.command_buffer(), source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr()); }
     }
 
-    fn cmd_blit_image(self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: Vec<ImageBlit>, filter: Filter) {
-        unsafe { vkCmdBlitImage(self, source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr(), filter); }
+    fn cmd_blit_image(&mut self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: &[ImageBlit], filter: Filter) {
+        unsafe { self.state().vkCmdBlitImage(*self.command_buffer(), source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr(), filter); }
     }
 
-    fn cmd_copy_buffer_to_image(self, buffer: Buffer, image: Image, layout: ImageLayout, regions: Vec<BufferImageCopy>) {
-        unsafe { vkCmdCopyBufferToImage(self, buffer, image, layout, regions.len() as u32, regions.as_ptr()); }
+    fn cmd_copy_buffer_to_image(&mut self, buffer: Buffer, image: Image, layout: ImageLayout, regions: &[BufferImageCopy]) {
+        unsafe { self.state().vkCmdCopyBufferToImage(*self.command_buffer(), buffer, image, layout, regions.len() as u32, regions.as_ptr()); }
     }
 
-    fn cmd_copy_image_to_buffer(self, image: Image, layout: ImageLayout, buffer: Buffer, regions: Vec<BufferImageCopy>) {
-        unsafe { vkCmdCopyImageToBuffer(self, image, layout, buffer, regions.len() as u32, regions.as_ptr()); }
+    fn cmd_copy_image_to_buffer(&mut self, image: Image, layout: ImageLayout, buffer: Buffer, regions: &[BufferImageCopy]) {
+        unsafe { self.state().vkCmdCopyImageToBuffer(*self.command_buffer(), image, layout, buffer, regions.len() as u32, regions.as_ptr()); }
     }
 
-    fn cmd_fill_buffer(self, buffer: Buffer, offset: DeviceSize, size: DeviceSize, data: u32) {
-        unsafe { vkCmdFillBuffer(self, buffer, offset, size, data); }
+    // Writes `data` into `buffer` at `offset` as the command buffer executes.
+    // Panics, before anything is recorded, if `data` is empty or larger than
+    // 65536 bytes, or if `offset` or its size is not a multiple of 4, none of
+    // which Vulkan allows.
+    fn cmd_update_buffer<T: PlainData>(&mut self, buffer: Buffer, offset: DeviceSize, data: &[T]) {
+        let bytes = bytes_of(data);
+        let size = bytes.len();
+        assert!(size != 0, "CommandBuffer.cmd_update_buffer must be called with some data");
+        assert!(offset.is_multiple_of(4) && size.is_multiple_of(4), "CommandBuffer.cmd_update_buffer must be called with an offset and size that are multiples of 4");
+        assert!(size <= MAX_UPDATE_BUFFER_SIZE, "CommandBuffer.cmd_update_buffer cannot update more than 65536 bytes at once");
+
+        unsafe { self.state().vkCmdUpdateBuffer(*self.command_buffer(), buffer, offset, size as DeviceSize, bytes.as_ptr() as *const c_void); }
     }
 
-    fn cmd_clear_color_image(self, image: Image, layout: ImageLayout, color: ClearColorValue, ranges: Vec<ImageSubresourceRange>) {
-        unsafe { vkCmdClearColorImage(self, image, layout, &color, ranges.len() as u32, ranges.as_ptr()); }
+    fn cmd_fill_buffer(&mut self, buffer: Buffer, offset: DeviceSize, size: DeviceSize, data: u32) {
+        unsafe { self.state().vkCmdFillBuffer(*self.command_buffer(), buffer, offset, size, data); }
     }
 
-    fn cmd_clear_depth_stencil_image(self, image: Image, layout: ImageLayout, depth_stencil: ClearDepthStencilValue, ranges: Vec<ImageSubresourceRange>) {
-        unsafe { vkCmdClearDepthStencilImage(self, image, layout, &depth_stencil, ranges.len() as u32, ranges.as_ptr()); }
+    fn cmd_clear_color_image(&mut self, image: Image, layout: ImageLayout, color: ClearColorValue, ranges: &[ImageSubresourceRange]) {
+        unsafe { self.state().vkCmdClearColorImage(*self.command_buffer(), image, layout, &color, ranges.len() as u32, ranges.as_ptr()); }
     }
 
-    fn cmd_clear_attachments(self, attachments: Vec<ClearAttachment>, rects: Vec<ClearRect>) {
-        unsafe { vkCmdClearAttachments(self, attachments.len() as u32, attachments.as_ptr(), rects.len() as u32, rects.as_ptr()); }
+    fn cmd_clear_depth_stencil_image(&mut self, image: Image, layout: ImageLayout, depth_stencil: ClearDepthStencilValue, ranges: &[ImageSubresourceRange]) {
+        unsafe { self.state().vkCmdClearDepthStencilImage(*self.command_buffer(), image, layout, &depth_stencil, ranges.len() as u32, ranges.as_ptr()); }
     }
 
-    fn cmd_resolve_image(self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: Vec<ImageResolve>) {
-        unsafe { vkCmdResolveImage(self, source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr()); }
+    fn cmd_clear_attachments(&mut self, attachments: &[ClearAttachment], rects: &[ClearRect]) {
+        unsafe { self.state().vkCmdClearAttachments(*self.command_buffer(), attachments.len() as u32, attachments.as_ptr(), rects.len() as u32, rects.as_ptr()); }
     }
 
-    fn cmd_set_event(self, event: Event, flags: PipelineStageFlags) {
-        unsafe { vkCmdSetEvent(self, event, flags); }
+    fn cmd_resolve_image(&mut self, source: Image, source_layout: ImageLayout, destination: Image, destination_layout: ImageLayout, regions: &[ImageResolve]) {
+        unsafe { self.state().vkCmdResolveImage(*self.command_buffer(), source, source_layout, destination, destination_layout, regions.len() as u32, regions.as_ptr()); }
     }
 
-    fn cmd_reset_event(self, event: Event, flags: PipelineStageFlags) {
-        unsafe { vkCmdResetEvent(self, event, flags); }
+    fn cmd_set_event(&mut self, event: Event, flags: PipelineStageFlags) {
+        unsafe { self.state().vkCmdSetEvent(*self.command_buffer(), event, flags); }
     }
 
-    fn cmd_wait_events(self, events: Vec<Event>, source_mask: PipelineStageFlags, destination_mask: PipelineStageFlags, memory_barriers: Vec<MemoryBarrier>, buffer_memory_barriers: Vec<BufferMemoryBarrier>, image_memory_barriers: Vec<ImageMemoryBarrier>) {
-        unsafe { vkCmdWaitEvents(self, events.len() as u32, events.as_ptr(), source_mask, destination_mask, memory_barriers.len() as u32, memory_barriers.as_ptr(), buffer_memory_barriers.len() as u32, buffer_memory_barriers.as_ptr(), image_memory_barriers.len() as u32, image_memory_barriers.as_ptr()); }
+    fn cmd_reset_event(&mut self, event: Event, flags: PipelineStageFlags) {
+        unsafe { self.state().vkCmdResetEvent(*self.command_buffer(), event, flags); }
     }
 
-    fn cmd_pipeline_barrier(self, source_mask: PipelineStageFlags, destination_mask: PipelineStageFlags, dependency_flags: DependencyFlags, memory_barrier: Vec<MemoryBarrier>, buffer_memory_barrier: Vec<BufferMemoryBarrier>, image_memory_barrier: Vec<ImageMemoryBarrier>) {
-        unsafe { vkCmdPipelineBarrier(self, source_mask, destination_mask, dependency_flags, memory_barrier.len() as u32, memory_barrier.as_ptr(), buffer_memory_barrier.len() as u32, buffer_memory_barrier.as_ptr(), image_memory_barrier.len() as u32, image_memory_barrier.as_ptr()); }
+    fn cmd_wait_events(&mut self, events: &[Event], source_mask: PipelineStageFlags, destination_mask: PipelineStageFlags, memory_barriers: &[MemoryBarrier], buffer_memory_barriers: &[BufferMemoryBarrier], image_memory_barriers: &[ImageMemoryBarrier]) {
+        unsafe { self.state().vkCmdWaitEvents(*self.command_buffer(), events.len() as u32, events.as_ptr(), source_mask, destination_mask, memory_barriers.len() as u32, memory_barriers.as_ptr(), buffer_memory_barriers.len() as u32, buffer_memory_barriers.as_ptr(), image_memory_barriers.len() as u32, image_memory_barriers.as_ptr()); }
     }
 
-    fn cmd_begin_query(self, pool: QueryPool, query: u32, flags: QueryControlFlags) {
-        unsafe { vkCmdBeginQuery(self, pool, query, flags); }
+    fn cmd_pipeline_barrier(&mut self, source_mask: PipelineStageFlags, destination_mask: PipelineStageFlags, dependency_flags: DependencyFlags, memory_barrier: &[MemoryBarrier], buffer_memory_barrier: &[BufferMemoryBarrier], image_memory_barrier: &[ImageMemoryBarrier]) {
+        unsafe { self.state().vkCmdPipelineBarrier(*self.command_buffer(), source_mask, destination_mask, dependency_flags, memory_barrier.len() as u32, memory_barrier.as_ptr(), buffer_memory_barrier.len() as u32, buffer_memory_barrier.as_ptr(), image_memory_barrier.len() as u32, image_memory_barrier.as_ptr()); }
     }
 
-    fn cmd_end_query(self, pool: QueryPool, query: u32) {
-        unsafe { vkCmdEndQuery(self, pool, query); }
+    fn cmd_begin_query(&mut self, pool: QueryPool, query: u32, flags: QueryControlFlags) {
+        unsafe { self.state().vkCmdBeginQuery(*self.command_buffer(), pool, query, flags); }
     }
 
-    fn cmd_reset_query_pool(self, pool: QueryPool, first_query: u32, query_count: u32) {
-        unsafe { vkCmdResetQueryPool(self, pool, first_query, query_count); }
+    fn cmd_end_query(&mut self, pool: QueryPool, query: u32) {
+        unsafe { self.state().vkCmdEndQuery(*self.command_buffer(), pool, query); }
     }
 
-    fn cmd_write_timestamp(self, stage: PipelineStageFlags, pool: QueryPool, query: u32) {
-        unsafe { vkCmdWriteTimestamp(self, stage, pool, query); }
+    fn cmd_reset_query_pool(&mut self, pool: QueryPool, first_query: u32, query_count: u32) {
+        unsafe { self.state().vkCmdResetQueryPool(*self.command_buffer(), pool, first_query, query_count); }
     }
 
-    fn cmd_copy_query_pool_errors(self, pool: QueryPool, first_query: u32, query_offset: u32, buffer: Buffer, offset: DeviceSize, stride: DeviceSize, flags: QueryErrorFlags) {
-        unsafe { vkCmdCopyQueryPoolErrors(self, pool, first_query, query_offset, buffer, offset, stride, flags); }
+    fn cmd_write_timestamp(&mut self, stage: PipelineStageFlags, pool: QueryPool, query: u32) {
+        unsafe { self.state().vkCmdWriteTimestamp(*self.command_buffer(), stage, pool, query); }
     }
 
-    fn cmd_begin_render_pass(self, info: RenderPassBeginInfo, contents: SubpassContents) {
-        unsafe { vkCmdBeginRenderPass(self, &info, contents); }
+    fn cmd_copy_query_pool_results(&mut self, pool: QueryPool, first_query: u32, query_count: u32, buffer: Buffer, offset: DeviceSize, stride: DeviceSize, flags: QueryResultFlags) {
+        unsafe { self.state().vkCmdCopyQueryPoolResults(*self.command_buffer(), pool, first_query, query_count, buffer, offset, stride, flags); }
     }
 
-    fn cmd_next_subpass(self, contents: SubpassContents) {
-        unsafe { vkCmdNextSubpass(self, contents); }
+    // Pushes `values` to the stages and offset its PushConstants impl names.
+    // Panics, before anything is recorded, if T is empty, if its offset or
+    // size is not a multiple of 4, or if it reaches past the device's
+    // maxPushConstantsSize, none of which Vulkan allows.
+    fn cmd_push_constants<T: PushConstants>(&mut self, layout: PipelineLayout, values: &T) {
+        let size = mem::size_of::<T>() as u32;
+        assert!(size != 0, "CommandBuffer.cmd_push_constants must be called with some data");
+        assert!(T::OFFSET.is_multiple_of(4) && size.is_multiple_of(4), "CommandBuffer.cmd_push_constants must be called with an offset and size that are multiples of 4");
+
+        let state = self.state();
+        let end = T::OFFSET.checked_add(size);
+        assert!(end.is_some_and(|end| end <= state.limits.maxPushConstantsSize), "CommandBuffer.cmd_push_constants cannot push beyond maxPushConstantsSize");
+
+        unsafe { state.vkCmdPushConstants(*self.command_buffer(), layout, T::STAGES, T::OFFSET, size, values as *const T as *const c_void); }
     }
 
-    fn cmd_end_render_pass(self) {
-        unsafe { vkCmdEndRenderPass(self); }
+    fn cmd_begin_render_pass(&mut self, info: RenderPassBeginInfo, contents: SubpassContents) {
+        unsafe { self.state().vkCmdBeginRenderPass(*self.command_buffer(), &info, contents); }
+    }
+
+    fn cmd_next_subpass(&mut self, contents: SubpassContents) {
+        unsafe { self.state().vkCmdNextSubpass(*self.command_buffer(), contents); }
+    }
+
+    fn cmd_end_render_pass(&mut self) {
+        unsafe { self.state().vkCmdEndRenderPass(*self.command_buffer()); }
+    }
+
+    fn cmd_execute_commands(&mut self, buffers: &[CommandBuffer]) {
+        unsafe { self.state().vkCmdExecuteCommands(*self.command_buffer(), buffers.len() as u32, buffers.as_ptr()); }
+    }
+
+    // The label commands do nothing unless VK_EXT_debug_utils was enabled on
+    // the instance. A `color` of all zeroes means no color.
+    fn cmd_begin_label(&mut self, name: &str, color: [f32; 4]) {
+        if let Some(begin_label) = self.state().vkCmdBeginDebugUtilsLabelEXT {
+            let c_name = debug_name(name);
+            let label = DebugUtilsLabelEXT { pLabelName: c_name.as_ptr(), color: color, ..Default::default() };
+            unsafe { begin_label(*self.command_buffer(), &label); }
+        }
+    }
+
+    fn cmd_end_label(&mut self) {
+        if let Some(end_label) = self.state().vkCmdEndDebugUtilsLabelEXT {
+            unsafe { end_label(*self.command_buffer()); }
+        }
+    }
+
+    fn cmd_insert_label(&mut self, name: &str, color: [f32; 4]) {
+        if let Some(insert_label) = self.state().vkCmdInsertDebugUtilsLabelEXT {
+            let c_name = debug_name(name);
+            let label = DebugUtilsLabelEXT { pLabelName: c_name.as_ptr(), color: color, ..Default::default() };
+            unsafe { insert_label(*self.command_buffer(), &label); }
+        }
     }
 
-    fn cmd_execute_commands(self, buffers: Vec<CommandBuffer>) {
-        unsafe { vkCmdExecuteCommands(self, buffers.len() as u32, buffers.as_ptr()); }
+    // Begins a label that is ended when the returned scope is dropped.
+    // Commands recorded through the scope land inside the label.
+    fn cmd_label_scope(&mut self, name: &str, color: [f32; 4]) -> LabelScope<'_, Self> where Self: Sized {
+        self.cmd_begin_label(name, color);
+        LabelScope::new(self)
     }
 }
 
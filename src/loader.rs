@@ -0,0 +1,178 @@
+use libc::{c_char, c_void};
+use std::env;
+use std::error;
+use std::ffi::{CStr, CString, OsStr};
+use std::fmt;
+use std::mem;
+use std::sync::OnceLock;
+
+use types::*;
+
+// Environment variable that overrides which Vulkan loader library is opened.
+pub const LIBRARY_PATH_VARIABLE: &'static str = "SPOCK_VULKAN_LIBRARY";
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios"))))]
+pub const DEFAULT_LIBRARY_NAME: &'static str = "libvulkan.so.1";
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub const DEFAULT_LIBRARY_NAME: &'static str = "libvulkan.1.dylib";
+#[cfg(windows)]
+pub const DEFAULT_LIBRARY_NAME: &'static str = "vulkan-1.dll";
+
+pub type GetInstanceProcAddrFunction = unsafe extern "system" fn(Instance, *const c_char) -> Option<VoidFunction>;
+
+#[derive(Clone)]
+pub enum LoaderError {
+    LibraryNotFound { path: String, reason: String },
+    MissingEntryPoint(&'static str)
+}
+
+impl fmt::Display for LoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoaderError::LibraryNotFound { ref path, ref reason } => write!(f, "Unable to open Vulkan loader \"{}\" ({})", path, reason),
+            LoaderError::MissingEntryPoint(name)                   => write!(f, "Vulkan loader does not export {}", name)
+        }
+    }
+}
+
+impl fmt::Debug for LoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for LoaderError {}
+
+// A Vulkan loader library opened at runtime. Every other entry point is
+// resolved through the `vkGetInstanceProcAddr` it exports.
+pub struct Loader {
+    library: *mut c_void,
+    get_instance_proc_addr: GetInstanceProcAddrFunction
+}
+
+// The library handle is only used for symbol lookup, which is thread safe.
+unsafe impl Send for Loader {}
+unsafe impl Sync for Loader {}
+
+impl Loader {
+    // Opens the library named by SPOCK_VULKAN_LIBRARY, or the platform default.
+    pub fn new() -> Result<Loader, LoaderError> {
+        match env::var_os(LIBRARY_PATH_VARIABLE) {
+            Some(path) => Loader::open(path),
+            None       => Loader::open(DEFAULT_LIBRARY_NAME)
+        }
+    }
+
+    pub fn open<P: AsRef<OsStr>>(path: P) -> Result<Loader, LoaderError> {
+        let display_path = path.as_ref().to_string_lossy().into_owned();
+        let c_path = match CString::new(display_path.clone()) {
+            Ok(c_path) => c_path,
+            Err(_)     => return Err(LoaderError::LibraryNotFound { path: display_path, reason: "path contains a nul byte".to_string() })
+        };
+
+        unsafe {
+            let library = platform::open(&c_path);
+            if library.is_null() {
+                return Err(LoaderError::LibraryNotFound { path: display_path, reason: platform::last_error() });
+            }
+
+            let symbol = platform::symbol(library, CStr::from_bytes_with_nul_unchecked(b"vkGetInstanceProcAddr\0"));
+            if symbol.is_null() {
+                platform::close(library);
+                return Err(LoaderError::MissingEntryPoint("vkGetInstanceProcAddr"));
+            }
+
+            Ok(Loader {
+                library: library,
+                get_instance_proc_addr: mem::transmute::<*mut c_void, GetInstanceProcAddrFunction>(symbol)
+            })
+        }
+    }
+
+    pub unsafe fn get_instance_proc_addr(&self, instance: Instance, name: &CStr) -> Option<VoidFunction> {
+        (self.get_instance_proc_addr)(instance, name.as_ptr())
+    }
+}
+
+impl Drop for Loader {
+    fn drop(&mut self) {
+        unsafe { platform::close(self.library); }
+    }
+}
+
+static LOADER: OnceLock<Result<Loader, LoaderError>> = OnceLock::new();
+
+// The process wide loader, opened on first use.
+pub fn loader() -> Result<&'static Loader, LoaderError> {
+    match *LOADER.get_or_init(Loader::new) {
+        Ok(ref loader) => Ok(loader),
+        Err(ref error) => Err(error.clone())
+    }
+}
+
+// Opens the process wide loader if it has not been already. The entry points
+// that need it, such as create_instance, can only fail with the Vulkan error
+// InitializationFailed when it is missing; calling this first says which
+// library could not be opened or which symbol it lacks.
+pub fn init() -> Result<(), LoaderError> {
+    loader().map(|_| ())
+}
+
+#[cfg(unix)]
+mod platform {
+    use libc::{self, c_void};
+    use std::ffi::CStr;
+
+    pub unsafe fn open(path: &CStr) -> *mut c_void {
+        libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL)
+    }
+
+    pub unsafe fn symbol(library: *mut c_void, name: &CStr) -> *mut c_void {
+        libc::dlsym(library, name.as_ptr())
+    }
+
+    pub unsafe fn close(library: *mut c_void) {
+        libc::dlclose(library);
+    }
+
+    pub fn last_error() -> String {
+        unsafe {
+            let message = libc::dlerror();
+            if message.is_null() {
+                "unknown error".to_string()
+            } else {
+                CStr::from_ptr(message).to_string_lossy().into_owned()
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use libc::{c_char, c_void};
+    use std::ffi::CStr;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn LoadLibraryA(lpLibFileName: *const c_char) -> *mut c_void;
+        fn GetProcAddress(hModule: *mut c_void, lpProcName: *const c_char) -> *mut c_void;
+        fn FreeLibrary(hLibModule: *mut c_void) -> i32;
+        fn GetLastError() -> u32;
+    }
+
+    pub unsafe fn open(path: &CStr) -> *mut c_void {
+        LoadLibraryA(path.as_ptr())
+    }
+
+    pub unsafe fn symbol(library: *mut c_void, name: &CStr) -> *mut c_void {
+        GetProcAddress(library, name.as_ptr())
+    }
+
+    pub unsafe fn close(library: *mut c_void) {
+        FreeLibrary(library);
+    }
+
+    pub fn last_error() -> String {
+        format!("error code {}", unsafe { GetLastError() })
+    }
+}
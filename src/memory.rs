@@ -0,0 +1,303 @@
+// Host access to device memory. A MappedMemory is returned by
+// SpockDevice::map_memory, derefs to the mapped bytes and unmaps them when
+// dropped. Memory can only be mapped once at a time, and freeing it while it
+// is mapped panics rather than leave the mapping pointing at nothing.
+
+use std::cmp;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+use std::sync::Arc;
+
+use types::*;
+use vk::*;
+
+pub struct MappedMemory<'d> {
+    device: &'d Device,
+    state: Arc<DeviceState>,
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    pointer: *mut u8,
+    size: usize,
+    atom_size: DeviceSize
+}
+
+impl<'d> MappedMemory<'d> {
+    // Maps `size` bytes of `memory` starting at `offset`, or with a size of
+    // VK_WHOLE_SIZE, the rest of the allocation. Fails with MemoryMapFailed if
+    // `memory` is already mapped or the range runs past its end.
+    pub fn map(device: &'d Device, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize) -> Result<MappedMemory<'d>, Error> {
+        let state = unsafe { device_state(device.as_raw()).expect("device was not created through spock") };
+        let allocation_size = state.begin_map(memory)?;
+        match MappedMemory::map_range(device, &state, memory, offset, size, allocation_size) {
+            Ok((pointer, size)) => Ok(MappedMemory {
+                device: device,
+                atom_size: cmp::max(state.limits.nonCoherentAtomSize, 1),
+                state: state,
+                memory: memory,
+                offset: offset,
+                pointer: pointer,
+                size: size
+            }),
+            Err(error) => {
+                state.end_map(memory);
+                Err(error)
+            }
+        }
+    }
+
+    fn map_range(device: &Device, state: &DeviceState, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize,
+                 allocation_size: DeviceSize) -> Result<(*mut u8, usize), Error> {
+        let size = if size == VK_WHOLE_SIZE { allocation_size.saturating_sub(offset) } else { size };
+        if size == 0 || offset.checked_add(size).is_none_or(|end| end > allocation_size) || size > usize::MAX as DeviceSize {
+            return Err(Error::MemoryMapFailed);
+        }
+
+        let atom_size = cmp::max(state.limits.nonCoherentAtomSize, 1);
+        let mapped_offset = offset / atom_size * atom_size;
+        let padding = offset - mapped_offset;
+
+        unsafe {
+            let mut pointer = ptr::null_mut();
+            state.vkMapMemory(*device, memory, mapped_offset, padding + size, MemoryMapFlags::empty(), &mut pointer).result()?;
+            Ok(((pointer as *mut u8).offset(padding as isize), size as usize))
+        }
+    }
+
+    pub fn memory(&self) -> DeviceMemory {
+        self.memory
+    }
+
+    pub fn offset(&self) -> DeviceSize {
+        self.offset
+    }
+
+    // The start of the mapping, for handing out parts of it without
+    // borrowing the whole of it as a slice.
+    pub fn pointer(&self) -> *mut u8 {
+        self.pointer
+    }
+
+    // Views the mapping as a slice of `T`, leaving off any trailing bytes that
+    // do not make up a whole `T`. Panics if `T` is zero-sized or the mapping
+    // is not aligned for it; mappings are aligned to minMemoryMapAlignment
+    // from the start of the allocation, so an offset that is a multiple of
+    // `T`'s alignment is enough for any `T` no more aligned than that.
+    //
+    // # Safety
+    //
+    // Every `T` in the slice must be a valid `T` whatever bytes the mapping
+    // holds, which is only so for plain data such as integers, floats and
+    // arrays or padding-free structs of them; a bool, an enum or a reference
+    // read from memory the device wrote is undefined behaviour. The device
+    // must not write to the mapped range while the slice is alive.
+    pub unsafe fn as_slice_of<T: Copy>(&self) -> &[T] {
+        slice::from_raw_parts(self.typed_pointer::<T>(), self.size / mem::size_of::<T>())
+    }
+
+    // As as_slice_of, for writing.
+    //
+    // # Safety
+    //
+    // As for as_slice_of. The device must also not read the mapped range
+    // while the slice is alive, and any padding in `T` is written as
+    // whatever it happened to hold, so the device must not rely on it.
+    pub unsafe fn as_mut_slice_of<T: Copy>(&mut self) -> &mut [T] {
+        slice::from_raw_parts_mut(self.typed_pointer::<T>(), self.size / mem::size_of::<T>())
+    }
+
+    fn typed_pointer<T>(&self) -> *mut T {
+        assert!(mem::size_of::<T>() != 0, "MappedMemory cannot be viewed as a zero-sized type");
+        assert!((self.pointer as usize).is_multiple_of(mem::align_of::<T>()), "MappedMemory is not aligned for the requested type");
+        self.pointer as *mut T
+    }
+
+    // Makes host writes to the mapping visible to the device. Only needed for
+    // memory types that are not HostCoherent.
+    pub fn flush(&self) -> Result<(), Error> {
+        self.flush_range(0, self.size)
+    }
+
+    // As flush, for `size` bytes starting `offset` bytes into the mapping.
+    pub fn flush_range(&self, offset: usize, size: usize) -> Result<(), Error> {
+        let range = self.atom_range(offset, size);
+        unsafe { self.state.vkFlushMappedMemoryRanges(*self.device, 1, &range).result().map(|_| ()) }
+    }
+
+    // Makes device writes to the mapping visible to the host. Only needed for
+    // memory types that are not HostCoherent.
+    pub fn invalidate(&self) -> Result<(), Error> {
+        self.invalidate_range(0, self.size)
+    }
+
+    // As invalidate, for `size` bytes starting `offset` bytes into the mapping.
+    pub fn invalidate_range(&self, offset: usize, size: usize) -> Result<(), Error> {
+        let range = self.atom_range(offset, size);
+        unsafe { self.state.vkInvalidateMappedMemoryRanges(*self.device, 1, &range).result().map(|_| ()) }
+    }
+
+    // Widens a range of the mapping out to nonCoherentAtomSize boundaries. The
+    // start never falls before the driver's mapping, since that begins on a
+    // boundary. If the end would run past it, the range is left open, which
+    // Vulkan takes to mean the rest of the mapping.
+    fn atom_range(&self, offset: usize, size: usize) -> MappedMemoryRange {
+        assert!(offset.checked_add(size).is_some_and(|end| end <= self.size), "MappedMemory range is out of bounds");
+
+        let start = self.offset + offset as DeviceSize;
+        let end = start + size as DeviceSize;
+        let aligned_start = start / self.atom_size * self.atom_size;
+        let aligned_end = end.div_ceil(self.atom_size) * self.atom_size;
+        let mapped_end = self.offset + self.size as DeviceSize;
+
+        MappedMemoryRange {
+            memory: self.memory,
+            offset: aligned_start,
+            size: if aligned_end > mapped_end { VK_WHOLE_SIZE } else { aligned_end - aligned_start },
+            ..Default::default()
+        }
+    }
+}
+
+// The mapping is only reached through the MappedMemory, which hands out
+// shared slices of it through & and mutable ones through &mut, as a Vec
+// would, so it can be sent and shared as one.
+unsafe impl<'d> Send for MappedMemory<'d> {}
+unsafe impl<'d> Sync for MappedMemory<'d> {}
+
+impl<'d> Deref for MappedMemory<'d> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.pointer, self.size) }
+    }
+}
+
+impl<'d> DerefMut for MappedMemory<'d> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.pointer, self.size) }
+    }
+}
+
+impl<'d> Drop for MappedMemory<'d> {
+    fn drop(&mut self) {
+        unsafe { self.state.vkUnmapMemory(*self.device, self.memory); }
+        self.state.end_map(self.memory);
+    }
+}
+
+// What a resource's memory is for, when the exact property flags it is
+// allocated with do not matter.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryUsage {
+    // Only used by the device, such as attachments, sampled images and
+    // vertex data uploaded once through a staging buffer.
+    GpuOnly,
+    // Written by the host and read by the device, such as staging and
+    // per-frame uniform buffers.
+    CpuToGpu,
+    // Written by the device and read back by the host.
+    GpuToCpu
+}
+
+impl MemoryUsage {
+    // The (required, preferred) property flags to look for, best first. The
+    // last pair asks only for what the usage cannot do without, so a unified
+    // memory device, whose types are all both DeviceLocal and HostVisible,
+    // still finds one.
+    pub fn candidates(self) -> Vec<(MemoryPropertyFlags, MemoryPropertyFlags)> {
+        match self {
+            MemoryUsage::GpuOnly  => vec![
+                (MemoryPropertyFlags::DeviceLocal, MemoryPropertyFlags::empty()),
+                (MemoryPropertyFlags::empty(), MemoryPropertyFlags::DeviceLocal)
+            ],
+            MemoryUsage::CpuToGpu => vec![
+                (MemoryPropertyFlags::HostVisible | MemoryPropertyFlags::HostCoherent, MemoryPropertyFlags::DeviceLocal),
+                (MemoryPropertyFlags::HostVisible, MemoryPropertyFlags::DeviceLocal)
+            ],
+            MemoryUsage::GpuToCpu => vec![
+                (MemoryPropertyFlags::HostVisible | MemoryPropertyFlags::HostCached, MemoryPropertyFlags::HostCoherent),
+                (MemoryPropertyFlags::HostVisible, MemoryPropertyFlags::HostCoherent)
+            ]
+        }
+    }
+}
+
+// Picks, from the types of `properties` allowed by `memory_type_bits`, one
+// with all of the `required` flags. Types with more of the `preferred` flags
+// win, then those with fewer flags nobody asked for, then the lowest index.
+pub fn find_memory_type(properties: &PhysicalDeviceMemoryProperties, memory_type_bits: u32, required: MemoryPropertyFlags,
+                        preferred: MemoryPropertyFlags) -> Option<u32> {
+    (0..properties.memoryTypeCount)
+        .filter(|&index| memory_type_bits & (1 << index) != 0)
+        .filter(|&index| properties.memoryTypes[index as usize].propertyFlags.contains(required))
+        .min_by_key(|&index| {
+            let flags = properties.memoryTypes[index as usize].propertyFlags;
+            let unrequested = flags & !(required | preferred);
+            (cmp::Reverse((flags & preferred).bits().count_ones()), unrequested.bits().count_ones())
+        })
+}
+
+// What find_memory_type picks for the first of `usage`'s candidates that any
+// allowed type satisfies.
+pub fn find_memory_type_for_usage(properties: &PhysicalDeviceMemoryProperties, memory_type_bits: u32, usage: MemoryUsage) -> Option<u32> {
+    usage.candidates().into_iter()
+        .filter_map(|(required, preferred)| find_memory_type(properties, memory_type_bits, required, preferred))
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_memory_type, find_memory_type_for_usage, MemoryUsage};
+    use types::*;
+
+    fn properties(types: &[MemoryPropertyFlags]) -> PhysicalDeviceMemoryProperties {
+        let mut properties = PhysicalDeviceMemoryProperties { ..Default::default() };
+        properties.memoryTypeCount = types.len() as u32;
+        for (index, &flags) in types.iter().enumerate() {
+            properties.memoryTypes[index].propertyFlags = flags;
+        }
+        properties
+    }
+
+    const DEVICE_LOCAL: MemoryPropertyFlags = MemoryPropertyFlags::DeviceLocal;
+    const HOST_VISIBLE: MemoryPropertyFlags = MemoryPropertyFlags::HostVisible;
+    const HOST_COHERENT: MemoryPropertyFlags = MemoryPropertyFlags::HostCoherent;
+    const HOST_CACHED: MemoryPropertyFlags = MemoryPropertyFlags::HostCached;
+
+    #[test]
+    fn only_allowed_types_with_the_required_flags_are_picked() {
+        let properties = properties(&[DEVICE_LOCAL, HOST_VISIBLE | HOST_COHERENT, DEVICE_LOCAL]);
+        assert_eq!(find_memory_type(&properties, !0, DEVICE_LOCAL, MemoryPropertyFlags::empty()), Some(0));
+        assert_eq!(find_memory_type(&properties, 0b110, DEVICE_LOCAL, MemoryPropertyFlags::empty()), Some(2));
+        assert_eq!(find_memory_type(&properties, 0b010, DEVICE_LOCAL, MemoryPropertyFlags::empty()), None);
+        assert_eq!(find_memory_type(&properties, !0, HOST_CACHED, MemoryPropertyFlags::empty()), None);
+    }
+
+    #[test]
+    fn types_past_the_count_are_ignored() {
+        let mut properties = properties(&[HOST_VISIBLE]);
+        properties.memoryTypes[1].propertyFlags = DEVICE_LOCAL;
+        assert_eq!(find_memory_type(&properties, !0, DEVICE_LOCAL, MemoryPropertyFlags::empty()), None);
+    }
+
+    #[test]
+    fn preferred_flags_win_then_fewer_unrequested_flags() {
+        let properties = properties(&[HOST_VISIBLE | HOST_COHERENT | HOST_CACHED, HOST_VISIBLE | HOST_COHERENT, DEVICE_LOCAL | HOST_VISIBLE | HOST_COHERENT]);
+        assert_eq!(find_memory_type(&properties, !0, HOST_VISIBLE, MemoryPropertyFlags::empty()), Some(1));
+        assert_eq!(find_memory_type(&properties, !0, HOST_VISIBLE, DEVICE_LOCAL), Some(2));
+        assert_eq!(find_memory_type(&properties, !0, HOST_VISIBLE, HOST_CACHED), Some(0));
+    }
+
+    #[test]
+    fn usage_falls_back_to_its_later_candidates() {
+        // A unified memory device, with no plain DeviceLocal type and nothing
+        // HostCached.
+        let properties = properties(&[DEVICE_LOCAL | HOST_VISIBLE | HOST_COHERENT, HOST_VISIBLE]);
+        assert_eq!(find_memory_type_for_usage(&properties, !0, MemoryUsage::GpuOnly), Some(0));
+        assert_eq!(find_memory_type_for_usage(&properties, !0, MemoryUsage::CpuToGpu), Some(0));
+        assert_eq!(find_memory_type_for_usage(&properties, !0, MemoryUsage::GpuToCpu), Some(0));
+        assert_eq!(find_memory_type_for_usage(&properties, 0b10, MemoryUsage::CpuToGpu), Some(1));
+        assert_eq!(find_memory_type_for_usage(&properties, 0, MemoryUsage::GpuOnly), None);
+    }
+}
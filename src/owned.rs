@@ -0,0 +1,275 @@
+// Owning wrappers around Vulkan handles. Each one remembers the object it was
+// created from and the allocation callbacks it was created with, and destroys
+// its handle when dropped. Children borrow their parent, so a buffer cannot
+// outlive its device and a device cannot outlive its instance.
+//
+// An owned instance or device also keeps the functions loaded for it, and
+// implements SpockInstance or SpockDevice through them without looking its
+// handle up first. The wrappers deref to the raw handle, so the other Spock*
+// traits can still be used on them. Destroying an instance or device takes the
+// free functions destroy_instance and destroy_device, which cannot be reached
+// through the deref, so an owned handle is only ever destroyed by its wrapper.
+
+use std::mem;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use cached::{CachedCommandBuffer, CachedPhysicalDevice, CachedQueue};
+use debug::{self, DebugMessage, DebugMessenger, Severity};
+use types::*;
+use vk::{device_state, instance_fns, DeviceDispatch, DeviceState, InstanceDispatch, InstanceFns};
+use {destroy_device, destroy_instance, SpockDevice, SpockInstance, SpockPhysicalDevice};
+
+pub struct OwnedInstance {
+    handle: Instance,
+    fns: Arc<InstanceFns>,
+    allocator: Option<AllocationCallbacks>
+}
+
+impl OwnedInstance {
+    pub fn new(create_info: InstanceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<OwnedInstance, Error> {
+        let handle = try!(::create_instance(create_info, allocator_opt));
+        Ok(unsafe { OwnedInstance::from_raw(handle, allocator_opt) })
+    }
+
+    // Takes ownership of an instance created elsewhere through spock.
+    // `allocator_opt` must be the callbacks it was created with.
+    pub unsafe fn from_raw(handle: Instance, allocator_opt: Option<AllocationCallbacks>) -> OwnedInstance {
+        let fns = instance_fns(handle.as_raw()).expect("instance was not created through spock");
+        OwnedInstance { handle: handle, fns: fns, allocator: allocator_opt }
+    }
+
+    pub fn handle(&self) -> Instance {
+        self.handle
+    }
+
+    // Gives up ownership without destroying the instance.
+    pub fn into_raw(self) -> Instance {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+
+    // Panics if `physical_device` is not one of this instance's, as the
+    // device would then borrow an instance it did not come from, which could
+    // be destroyed while the device is still alive.
+    pub fn create_device(&self, physical_device: PhysicalDevice, create_info: DeviceCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<OwnedDevice<'_>, Error> {
+        let physical_devices = try!(self.enumerate_all_physical_devices());
+        assert!(physical_devices.contains(&physical_device), "OwnedInstance.create_device must be given one of the instance's physical devices");
+        let handle = try!(SpockPhysicalDevice::create_device(&physical_device, create_info, allocator_opt));
+        Ok(unsafe { OwnedDevice::from_raw(handle, self, allocator_opt) })
+    }
+
+    // As enumerate_all_physical_devices, with this instance's functions
+    // attached to each one.
+    pub fn enumerate_cached_physical_devices(&self) -> Result<Vec<CachedPhysicalDevice>, Error> {
+        let handles = try!(self.enumerate_all_physical_devices());
+        Ok(handles.into_iter().map(|handle| unsafe { CachedPhysicalDevice::with_fns(handle, self.fns.clone()) }).collect())
+    }
+
+    // Needs VK_EXT_debug_utils or VK_EXT_debug_report to have been enabled,
+    // and fails with ExtensionNotPresent otherwise.
+    pub fn create_debug_messenger<F>(&self, min_severity: Severity, callback: F, allocator_opt: Option<AllocationCallbacks>) -> Result<DebugMessenger<'_>, Error>
+        where F: Fn(&DebugMessage) + Send + Sync + 'static
+    {
+        DebugMessenger::new(self, min_severity, Box::new(callback), allocator_opt)
+    }
+
+    pub fn create_stderr_debug_messenger(&self, min_severity: Severity) -> Result<DebugMessenger<'_>, Error> {
+        self.create_debug_messenger(min_severity, debug::print_to_stderr, None)
+    }
+}
+
+impl InstanceDispatch for OwnedInstance {
+    type Fns<'a> = &'a InstanceFns;
+
+    fn instance(&self) -> &Instance {
+        &self.handle
+    }
+
+    fn fns(&self) -> &InstanceFns {
+        &self.fns
+    }
+}
+
+impl Deref for OwnedInstance {
+    type Target = Instance;
+
+    fn deref(&self) -> &Instance {
+        &self.handle
+    }
+}
+
+impl Drop for OwnedInstance {
+    fn drop(&mut self) {
+        destroy_instance(self.handle, self.allocator);
+    }
+}
+
+pub struct OwnedDevice<'i> {
+    handle: Device,
+    state: Arc<DeviceState>,
+    instance: &'i OwnedInstance,
+    allocator: Option<AllocationCallbacks>
+}
+
+impl<'i> OwnedDevice<'i> {
+    // Takes ownership of a device created from one of `instance`'s physical
+    // devices. `allocator_opt` must be the callbacks it was created with.
+    pub unsafe fn from_raw(handle: Device, instance: &'i OwnedInstance, allocator_opt: Option<AllocationCallbacks>) -> OwnedDevice<'i> {
+        let state = device_state(handle.as_raw()).expect("device was not created through spock");
+        OwnedDevice { handle: handle, state: state, instance: instance, allocator: allocator_opt }
+    }
+
+    pub fn handle(&self) -> Device {
+        self.handle
+    }
+
+    pub fn instance(&self) -> &'i OwnedInstance {
+        self.instance
+    }
+
+    // Gives up ownership without destroying the device.
+    pub fn into_raw(self) -> Device {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+
+    // As get_queue, with this device's state attached to the queue.
+    pub fn get_cached_queue(&self, queue_family_index: u32, queue_index: u32) -> Result<CachedQueue, Error> {
+        let handle = try!(self.get_queue(queue_family_index, queue_index));
+        Ok(unsafe { CachedQueue::with_state(handle, self.state.clone()) })
+    }
+
+    // As allocate_command_buffers, with this device's state attached to each
+    // command buffer, so that recording into them looks nothing up.
+    pub fn allocate_cached_command_buffers(&self, allocate_info: CommandBufferAllocateInfo) -> Result<Vec<CachedCommandBuffer>, Error> {
+        let handles = try!(self.allocate_command_buffers(allocate_info));
+        Ok(handles.into_iter().map(|handle| unsafe { CachedCommandBuffer::with_state(handle, self.state.clone()) }).collect())
+    }
+
+    pub fn create_buffer(&self, create_info: BufferCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<OwnedBuffer<'_>, Error> {
+        let handle = try!(SpockDevice::create_buffer(self, create_info, allocator_opt));
+        Ok(OwnedBuffer { handle: handle, device: self, allocator: allocator_opt })
+    }
+
+    pub fn create_image(&self, create_info: ImageCreateInfo, allocator_opt: Option<AllocationCallbacks>) -> Result<OwnedImage<'_>, Error> {
+        let handle = try!(SpockDevice::create_image(self, create_info, allocator_opt));
+        Ok(OwnedImage { handle: handle, device: self, allocator: allocator_opt })
+    }
+}
+
+impl<'i> DeviceDispatch for OwnedDevice<'i> {
+    type State<'a> = &'a DeviceState where Self: 'a;
+
+    fn device(&self) -> &Device {
+        &self.handle
+    }
+
+    fn state(&self) -> &DeviceState {
+        &self.state
+    }
+}
+
+impl<'i> Deref for OwnedDevice<'i> {
+    type Target = Device;
+
+    fn deref(&self) -> &Device {
+        &self.handle
+    }
+}
+
+impl<'i> Drop for OwnedDevice<'i> {
+    fn drop(&mut self) {
+        destroy_device(self.handle, self.allocator);
+    }
+}
+
+// OwnedDevice is covariant in the instance lifetime, so a single lifetime is
+// enough to tie these to both their device and its instance.
+pub struct OwnedBuffer<'d> {
+    handle: Buffer,
+    device: &'d OwnedDevice<'d>,
+    allocator: Option<AllocationCallbacks>
+}
+
+impl<'d> OwnedBuffer<'d> {
+    // Takes ownership of a buffer created from `device`. `allocator_opt` must
+    // be the callbacks it was created with.
+    pub unsafe fn from_raw(handle: Buffer, device: &'d OwnedDevice<'d>, allocator_opt: Option<AllocationCallbacks>) -> OwnedBuffer<'d> {
+        OwnedBuffer { handle: handle, device: device, allocator: allocator_opt }
+    }
+
+    pub fn handle(&self) -> Buffer {
+        self.handle
+    }
+
+    pub fn device(&self) -> &'d OwnedDevice<'d> {
+        self.device
+    }
+
+    // Gives up ownership without destroying the buffer.
+    pub fn into_raw(self) -> Buffer {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+
+impl<'d> Deref for OwnedBuffer<'d> {
+    type Target = Buffer;
+
+    fn deref(&self) -> &Buffer {
+        &self.handle
+    }
+}
+
+impl<'d> Drop for OwnedBuffer<'d> {
+    fn drop(&mut self) {
+        SpockDevice::destroy_buffer(self.device, self.handle, self.allocator);
+    }
+}
+
+pub struct OwnedImage<'d> {
+    handle: Image,
+    device: &'d OwnedDevice<'d>,
+    allocator: Option<AllocationCallbacks>
+}
+
+impl<'d> OwnedImage<'d> {
+    // Takes ownership of an image created from `device`. `allocator_opt` must
+    // be the callbacks it was created with.
+    pub unsafe fn from_raw(handle: Image, device: &'d OwnedDevice<'d>, allocator_opt: Option<AllocationCallbacks>) -> OwnedImage<'d> {
+        OwnedImage { handle: handle, device: device, allocator: allocator_opt }
+    }
+
+    pub fn handle(&self) -> Image {
+        self.handle
+    }
+
+    pub fn device(&self) -> &'d OwnedDevice<'d> {
+        self.device
+    }
+
+    // Gives up ownership without destroying the image.
+    pub fn into_raw(self) -> Image {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+
+impl<'d> Deref for OwnedImage<'d> {
+    type Target = Image;
+
+    fn deref(&self) -> &Image {
+        &self.handle
+    }
+}
+
+impl<'d> Drop for OwnedImage<'d> {
+    fn drop(&mut self) {
+        SpockDevice::destroy_image(self.device, self.handle, self.allocator);
+    }
+}
@@ -0,0 +1,136 @@
+// Results read back from a query pool by SpockDevice::get_query_pool_results.
+// Vulkan writes each query's values as a run of integers, 32 or 64 bits wide,
+// followed by its availability when WithAvailability is asked for. The
+// QueryResults accessors decode a run into what the pool's query type means.
+
+use types::*;
+
+// The integer types results can be read as. u64 asks for Result64.
+pub trait QueryValue: Copy + Default + Into<u64> {
+    const FLAGS: QueryResultFlags;
+}
+
+impl QueryValue for u32 {
+    const FLAGS: QueryResultFlags = QueryResultFlags::empty();
+}
+
+impl QueryValue for u64 {
+    const FLAGS: QueryResultFlags = QueryResultFlags::Result64;
+}
+
+pub struct QueryResults<T> {
+    data: Vec<T>,
+    values_per_query: usize,
+    stride: usize,
+    available: Vec<bool>
+}
+
+impl<T: QueryValue> QueryResults<T> {
+    // Splits `data`, as written with `flags`, into its queries. `all_available`
+    // says whether the command returned Success rather than NotReady, which
+    // is all there is to go on without WithAvailability.
+    pub fn new(data: Vec<T>, query_count: usize, values_per_query: usize, flags: QueryResultFlags, all_available: bool) -> QueryResults<T> {
+        let with_availability = flags.contains(QueryResultFlags::WithAvailability);
+        let stride = values_per_query + if with_availability { 1 } else { 0 };
+        let available = (0..query_count).map(|index| {
+            if with_availability {
+                data[index * stride + values_per_query].into() != 0
+            } else {
+                all_available
+            }
+        }).collect();
+
+        QueryResults {
+            data: data,
+            values_per_query: values_per_query,
+            stride: stride,
+            available: available
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.available.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.available.is_empty()
+    }
+
+    pub fn is_available(&self, index: usize) -> bool {
+        self.available[index]
+    }
+
+    // The values of query `index`, or None if they were not available yet.
+    pub fn get(&self, index: usize) -> Option<&[T]> {
+        if self.available[index] {
+            Some(self.values(index))
+        } else {
+            None
+        }
+    }
+
+    // The values of query `index` whether or not they were available. With
+    // Partial these are intermediate results; without it they are undefined.
+    pub fn values(&self, index: usize) -> &[T] {
+        let start = index * self.stride;
+        &self.data[start..start + self.values_per_query]
+    }
+
+    // The number of samples that passed the depth and stencil tests, for an
+    // Occlusion query.
+    pub fn occlusion(&self, index: usize) -> Option<u64> {
+        self.get(index).map(|values| values[0].into())
+    }
+
+    // The timestamp written by a Timestamp query, in nanoseconds.
+    // `timestamp_period` is the physical device's limits.timestampPeriod.
+    // Only the differences between timestamps mean anything.
+    pub fn timestamp(&self, index: usize, timestamp_period: f32) -> Option<f64> {
+        self.get(index).map(|values| values[0].into() as f64 * timestamp_period as f64)
+    }
+
+    // The counters of a PipelineStatistics query created with `flags`.
+    pub fn pipeline_statistics(&self, index: usize, flags: QueryPipelineStatisticFlags) -> Option<PipelineStatistics> {
+        self.get(index).map(|values| PipelineStatistics::decode(values, flags))
+    }
+}
+
+// The counters a PipelineStatistics query collects. Those it was not created
+// to collect are None.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PipelineStatistics {
+    pub input_assembly_vertices: Option<u64>,
+    pub input_assembly_primitives: Option<u64>,
+    pub vertex_shader_invocations: Option<u64>,
+    pub geometry_shader_invocations: Option<u64>,
+    pub geometry_shader_primitives: Option<u64>,
+    pub clipping_invocations: Option<u64>,
+    pub clipping_primitives: Option<u64>,
+    pub fragment_shader_invocations: Option<u64>,
+    pub tessellation_control_shader_patches: Option<u64>,
+    pub tessellation_evaluation_shader_invocations: Option<u64>,
+    pub compute_shader_invocations: Option<u64>
+}
+
+impl PipelineStatistics {
+    // Counters are written in the order of their bits, lowest first, so the
+    // fields below are listed, and so evaluated, in that order too.
+    fn decode<T: QueryValue>(values: &[T], flags: QueryPipelineStatisticFlags) -> PipelineStatistics {
+        let mut counters = values.iter().map(|&value| value.into());
+        let mut next = |flag| if flags.contains(flag) { counters.next() } else { None };
+
+        PipelineStatistics {
+            input_assembly_vertices: next(QueryPipelineStatisticFlags::InputAssemblyVertices),
+            input_assembly_primitives: next(QueryPipelineStatisticFlags::InputAssemblyPrimitives),
+            vertex_shader_invocations: next(QueryPipelineStatisticFlags::VertexShaderInvocations),
+            geometry_shader_invocations: next(QueryPipelineStatisticFlags::GeometryShaderInvocations),
+            geometry_shader_primitives: next(QueryPipelineStatisticFlags::GeometryShaderPrimitives),
+            clipping_invocations: next(QueryPipelineStatisticFlags::ClippingInvocations),
+            clipping_primitives: next(QueryPipelineStatisticFlags::ClippingPrimitives),
+            fragment_shader_invocations: next(QueryPipelineStatisticFlags::FragmentShaderInvocations),
+            tessellation_control_shader_patches: next(QueryPipelineStatisticFlags::TessellationControlShaderPatches),
+            tessellation_evaluation_shader_invocations: next(QueryPipelineStatisticFlags::TessellationEvaluationShaderInvocations),
+            compute_shader_invocations: next(QueryPipelineStatisticFlags::ComputeShaderInvocations)
+        }
+    }
+}
@@ -0,0 +1,716 @@
+// Moves data between the host and device-local buffers and images through a
+// host-visible ring buffer. Uploads are copied into the ring straight away and
+// recorded into a batch, which is submitted by `flush`, or when the ring needs
+// the space back. Each upload returns a token that can be waited on or polled
+// to learn when the batch it went into has finished on the device.
+//
+// Reads go the other way through a buffer of their own, and block until the
+// data has arrived.
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::error;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use cached::CachedCommandBuffer;
+use memory::{MappedMemory, MemoryUsage};
+use types::*;
+use vk::{device_state, DeviceDispatch, DeviceState};
+use {bytes_of, PlainData, SpockCommandBuffer, SpockDevice, SpockPhysicalDevice, SpockQueue};
+
+pub const DEFAULT_CAPACITY: DeviceSize = 16 * 1024 * 1024;
+
+// vkCmdCopyBufferToImage needs the buffer offset to be a multiple of 4 and of
+// the texel block size. 96 is a multiple of every block size in core Vulkan.
+const IMAGE_OFFSET_ALIGNMENT: DeviceSize = 96;
+const BUFFER_OFFSET_ALIGNMENT: DeviceSize = 16;
+
+#[derive(Copy, Clone)]
+pub enum StagingError {
+    // read_image cannot return an image to Undefined or Preinitialized, which
+    // an image can only leave.
+    InvalidLayout(ImageLayout),
+    // An aspect mask naming more or fewer than one aspect.
+    InvalidAspect(ImageAspectFlags),
+    // An image spock did not see created, so whose format and extent it does
+    // not know.
+    UnknownImage(Image),
+    // A format whose texel blocks spock does not know the size of.
+    UnsupportedFormat(Format),
+    // upload_image was given a different number of bytes than its region
+    // holds.
+    WrongDataSize { expected: DeviceSize, actual: DeviceSize },
+    Vulkan(Error)
+}
+
+impl fmt::Display for StagingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StagingError::InvalidLayout(layout)              => write!(f, "Cannot copy an image in the {:?} layout", layout),
+            StagingError::InvalidAspect(aspect)              => write!(f, "Aspect mask {:#x} does not name a single aspect", aspect.bits()),
+            StagingError::UnknownImage(image)                => write!(f, "Image {:#x} was not created through spock", image.as_raw()),
+            StagingError::UnsupportedFormat(format)          => write!(f, "Cannot copy images of format {:?}", format),
+            StagingError::WrongDataSize { expected, actual } => write!(f, "Region holds {} bytes but {} were given", expected, actual),
+            StagingError::Vulkan(error)                      => write!(f, "{}", error)
+        }
+    }
+}
+
+impl fmt::Debug for StagingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl error::Error for StagingError {}
+
+impl From<Error> for StagingError {
+    fn from(error: Error) -> StagingError {
+        StagingError::Vulkan(error)
+    }
+}
+
+// Identifies the batch an upload was recorded into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UploadToken(u64);
+
+struct Batch {
+    serial: u64,
+    command_buffer: CachedCommandBuffer,
+    fence: Fence,
+    // Where the ring's head was after this batch's last upload. The space
+    // before it is free again once the batch has completed.
+    end: DeviceSize,
+    // The buffer ranges copied to since the batch's last barrier.
+    written: Vec<(Buffer, Range<DeviceSize>)>
+}
+
+pub struct StagingBelt<'d> {
+    device: &'d Device,
+    // Found once, so that recording into the belt's command buffers does not
+    // look the device up for every command.
+    state: Arc<DeviceState>,
+    queue: Queue,
+    command_pool: CommandPool,
+    buffer: Buffer,
+    memory: DeviceMemory,
+    mapped: Option<MappedMemory<'d>>,
+    capacity: DeviceSize,
+    head: DeviceSize,
+    tail: DeviceSize,
+    recording: Option<Batch>,
+    in_flight: VecDeque<Batch>,
+    spare: Vec<(CachedCommandBuffer, Fence)>,
+    next_serial: u64,
+    completed_serial: u64
+}
+
+impl<'d> StagingBelt<'d> {
+    pub fn new(device: &'d Device, queue: Queue, queue_family_index: u32) -> Result<StagingBelt<'d>, Error> {
+        StagingBelt::with_capacity(device, queue, queue_family_index, DEFAULT_CAPACITY)
+    }
+
+    // `queue` must belong to the family `queue_family_index`, and must not be
+    // used elsewhere while the belt is submitting to it.
+    pub fn with_capacity(device: &'d Device, queue: Queue, queue_family_index: u32, capacity: DeviceSize) -> Result<StagingBelt<'d>, Error> {
+        let (buffer, memory) = create_host_buffer(device, capacity, BufferUsageFlags::TransferSrc, MemoryUsage::CpuToGpu)?;
+
+        let command_pool_create_info = CommandPoolCreateInfo {
+            flags: CommandPoolCreateFlags::ResetCommandBuffer,
+            queueFamilyIndex: queue_family_index,
+            ..Default::default()
+        };
+        let mapped = device.map_memory(memory, 0, capacity)
+            .and_then(|mapped| device.create_command_pool(command_pool_create_info, None).map(|pool| (mapped, pool)));
+        let (mapped, command_pool) = match mapped {
+            Ok(result) => result,
+            Err(error) => {
+                device.destroy_buffer(buffer, None);
+                device.free_memory(memory, None);
+                return Err(error);
+            }
+        };
+
+        Ok(StagingBelt {
+            device: device,
+            state: device.state(),
+            queue: queue,
+            command_pool: command_pool,
+            buffer: buffer,
+            memory: memory,
+            mapped: Some(mapped),
+            capacity: capacity,
+            head: 0,
+            tail: 0,
+            recording: None,
+            in_flight: VecDeque::new(),
+            spare: Vec::new(),
+            next_serial: 1,
+            completed_serial: 0
+        })
+    }
+
+    // Copies `data` into `buffer` at `offset`. The copy is made available to
+    // every later command on the belt's queue, and uploads to overlapping
+    // ranges land in the order they were made. An empty upload records
+    // nothing, and its token is already complete.
+    pub fn upload_buffer<T: PlainData>(&mut self, data: &[T], buffer: Buffer, offset: DeviceSize) -> Result<UploadToken, Error> {
+        let bytes = bytes_of(data);
+        if bytes.is_empty() {
+            return Ok(UploadToken(self.completed_serial));
+        }
+        let source_offset = self.stage(bytes, BUFFER_OFFSET_ALIGNMENT)?;
+
+        let region = BufferCopy {
+            srcOffset: source_offset,
+            dstOffset: offset,
+            size: bytes.len() as DeviceSize
+        };
+        let destination = offset..offset.saturating_add(region.size);
+        let staging_buffer = self.buffer;
+        self.recording_command_buffer()?;
+        let batch = self.recording.as_mut().unwrap();
+
+        // Copies in one batch may otherwise run in any order, so one that
+        // overlaps an earlier one waits for the copies before it.
+        let overlaps = batch.written.iter().any(|&(written_buffer, ref written)| {
+            written_buffer == buffer && written.start < destination.end && destination.start < written.end
+        });
+        if overlaps {
+            let barrier = MemoryBarrier {
+                srcAccessMask: AccessFlags::TransferWrite,
+                dstAccessMask: AccessFlags::TransferWrite,
+                ..Default::default()
+            };
+            batch.command_buffer.cmd_pipeline_barrier(PipelineStageFlags::Transfer, PipelineStageFlags::Transfer, DependencyFlags::empty(), &[barrier], &[], &[]);
+            batch.written.clear();
+        }
+        batch.written.push((buffer, destination));
+
+        batch.command_buffer.cmd_copy_buffer(staging_buffer, buffer, &[region]);
+        Ok(self.current_token())
+    }
+
+    // Copies tightly packed texels for `region` into `image`, moving the
+    // region's subresources from `old_layout` to TransferDstOptimal for the
+    // copy and then to `new_layout`. `region.bufferOffset`, `bufferRowLength`
+    // and `bufferImageHeight` are filled in by the belt. Passing Undefined as
+    // `old_layout` discards whatever the subresources held before.
+    //
+    // `data` must hold exactly the region's texel blocks, of the single
+    // aspect `region.imageSubresource.aspectMask` names, for each of its
+    // layers, and `image` must have been created through spock so that its
+    // format is known.
+    pub fn upload_image(&mut self, data: &[u8], image: Image, region: BufferImageCopy, old_layout: ImageLayout, new_layout: ImageLayout) -> Result<UploadToken, StagingError> {
+        let aspect = region.imageSubresource.aspectMask;
+        if !aspect.bits().is_power_of_two() {
+            return Err(StagingError::InvalidAspect(aspect));
+        }
+        let info = match unsafe { device_state(self.device.as_raw()) }.and_then(|state| state.image_info(image)) {
+            Some(info) => info,
+            None       => return Err(StagingError::UnknownImage(image))
+        };
+        let block_size = aspect_block_size(info.format, aspect);
+        if block_size == 0 {
+            return Err(StagingError::UnsupportedFormat(info.format));
+        }
+        let (block_width, block_height, block_depth) = if aspect == ImageAspectFlags::Color { info.format.block_extent() } else { (1, 1, 1) };
+        let extent = region.imageExtent;
+        let expected = extent.width.div_ceil(block_width) as DeviceSize
+            * extent.height.div_ceil(block_height) as DeviceSize
+            * extent.depth.div_ceil(block_depth) as DeviceSize
+            * region.imageSubresource.layerCount as DeviceSize
+            * block_size;
+        if data.len() as DeviceSize != expected {
+            return Err(StagingError::WrongDataSize { expected: expected, actual: data.len() as DeviceSize });
+        }
+        let source_offset = self.stage(data, IMAGE_OFFSET_ALIGNMENT)?;
+
+        let region = BufferImageCopy {
+            bufferOffset: source_offset,
+            bufferRowLength: 0,
+            bufferImageHeight: 0,
+            ..region
+        };
+        let range = ImageSubresourceRange {
+            aspectMask: region.imageSubresource.aspectMask,
+            baseMipLevel: region.imageSubresource.mipLevel,
+            levelCount: 1,
+            baseArrayLayer: region.imageSubresource.baseArrayLayer,
+            layerCount: region.imageSubresource.layerCount
+        };
+        let to_transfer = ImageMemoryBarrier {
+            srcAccessMask: AccessFlags::MemoryRead | AccessFlags::MemoryWrite,
+            dstAccessMask: AccessFlags::TransferWrite,
+            oldLayout: old_layout,
+            newLayout: ImageLayout::TransferDstOptimal,
+            srcQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            dstQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            image: image,
+            subresourceRange: range,
+            ..Default::default()
+        };
+        let from_transfer = ImageMemoryBarrier {
+            srcAccessMask: AccessFlags::TransferWrite,
+            dstAccessMask: AccessFlags::MemoryRead | AccessFlags::MemoryWrite,
+            oldLayout: ImageLayout::TransferDstOptimal,
+            newLayout: new_layout,
+            ..to_transfer
+        };
+
+        let staging_buffer = self.buffer;
+        let command_buffer = self.recording_command_buffer()?;
+        command_buffer.cmd_pipeline_barrier(PipelineStageFlags::AllCommands, PipelineStageFlags::Transfer, DependencyFlags::empty(), &[], &[], &[to_transfer]);
+        command_buffer.cmd_copy_buffer_to_image(staging_buffer, image, ImageLayout::TransferDstOptimal, &[region]);
+        command_buffer.cmd_pipeline_barrier(PipelineStageFlags::Transfer, PipelineStageFlags::AllCommands, DependencyFlags::empty(), &[], &[], &[from_transfer]);
+        Ok(self.current_token())
+    }
+
+    // Submits everything recorded since the last flush.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let mut batch = match self.recording.take() {
+            Some(batch) => batch,
+            None        => return Ok(())
+        };
+        batch.end = self.head;
+
+        let barrier = MemoryBarrier {
+            srcAccessMask: AccessFlags::TransferWrite,
+            dstAccessMask: AccessFlags::MemoryRead | AccessFlags::MemoryWrite,
+            ..Default::default()
+        };
+        batch.command_buffer.cmd_pipeline_barrier(PipelineStageFlags::Transfer, PipelineStageFlags::AllCommands, DependencyFlags::empty(), &[barrier], &[], &[]);
+
+        match self.submit(&mut batch) {
+            Ok(()) => {
+                self.in_flight.push_back(batch);
+                Ok(())
+            },
+            Err(error) => {
+                self.spare.push((batch.command_buffer, batch.fence));
+                Err(error)
+            }
+        }
+    }
+
+    fn submit(&mut self, batch: &mut Batch) -> Result<(), Error> {
+        self.mapped.as_ref().unwrap().flush()?;
+        batch.command_buffer.end()?;
+
+        let submit_info = SubmitInfo {
+            commandBufferCount: 1,
+            pCommandBuffers: &*batch.command_buffer,
+            ..Default::default()
+        };
+        self.queue.submit(&[submit_info], batch.fence)
+    }
+
+    // Returns whether the batch `token` belongs to has finished, without
+    // submitting it if it has not been yet.
+    pub fn is_complete(&mut self, token: UploadToken) -> Result<bool, Error> {
+        self.retire(false)?;
+        Ok(token.0 <= self.completed_serial)
+    }
+
+    // Submits the batch `token` belongs to if necessary, and blocks until it
+    // has finished.
+    pub fn wait(&mut self, token: UploadToken) -> Result<(), Error> {
+        if self.recording.as_ref().is_some_and(|batch| batch.serial <= token.0) {
+            self.flush()?;
+        }
+        while token.0 > self.completed_serial && !self.in_flight.is_empty() {
+            self.retire(true)?;
+        }
+        Ok(())
+    }
+
+    // Reads `range` of `buffer` back to the host. Anything recorded on the
+    // belt is submitted first, and the read waits for the device to finish
+    // every earlier write to the buffer.
+    pub fn read_buffer(&mut self, buffer: Buffer, range: Range<DeviceSize>) -> Result<Vec<u8>, Error> {
+        assert!(range.start <= range.end, "StagingBelt::read_buffer was given a backwards range");
+        let size = range.end - range.start;
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let region = BufferCopy {
+            srcOffset: range.start,
+            dstOffset: 0,
+            size: size
+        };
+        let barrier = MemoryBarrier {
+            srcAccessMask: AccessFlags::MemoryWrite,
+            dstAccessMask: AccessFlags::TransferRead,
+            ..Default::default()
+        };
+        self.read_back(size, |command_buffer, readback| {
+            command_buffer.cmd_pipeline_barrier(PipelineStageFlags::AllCommands, PipelineStageFlags::Transfer, DependencyFlags::empty(), &[barrier], &[], &[]);
+            command_buffer.cmd_copy_buffer(buffer, readback, &[region]);
+        })
+    }
+
+    // Reads one subresource of `image` back to the host. The image is moved
+    // from `layout` to TransferSrcOptimal for the copy and back again after,
+    // so `layout` cannot be Undefined or Preinitialized, which an image can
+    // only leave. `subresource.aspectMask` must name a single aspect, as depth
+    // and stencil are copied out separately. The image's format and extent
+    // are the ones spock saw it created with, so it must have been created
+    // through spock or be one of a Swapchain's images.
+    //
+    // A linear image comes back laid out as get_image_subresource_layout
+    // describes it, with rows rowPitch bytes apart and, for a 3D image,
+    // slices depthPitch apart, so that offsets into it are the ones mapping
+    // its memory would use; the padding between rows is zeroed. An optimal
+    // image has no layout the host can see, and its texel blocks come back
+    // tightly packed, a row at a time and then a slice at a time.
+    pub fn read_image(&mut self, image: Image, layout: ImageLayout, subresource: ImageSubresource) -> Result<Vec<u8>, StagingError> {
+        if layout == ImageLayout::Undefined || layout == ImageLayout::Preinitialized {
+            return Err(StagingError::InvalidLayout(layout));
+        }
+        let aspect = subresource.aspectMask;
+        if !aspect.bits().is_power_of_two() {
+            return Err(StagingError::InvalidAspect(aspect));
+        }
+        let info = match unsafe { device_state(self.device.as_raw()) }.and_then(|state| state.image_info(image)) {
+            Some(info) => info,
+            None       => return Err(StagingError::UnknownImage(image))
+        };
+        let block_size = aspect_block_size(info.format, aspect);
+        if block_size == 0 {
+            return Err(StagingError::UnsupportedFormat(info.format));
+        }
+
+        let level = subresource.mipLevel;
+        let extent = Extent3D {
+            width: cmp::max(info.extent.width >> level, 1),
+            height: cmp::max(info.extent.height >> level, 1),
+            depth: cmp::max(info.extent.depth >> level, 1)
+        };
+        let (block_width, block_height, block_depth) = if aspect == ImageAspectFlags::Color { info.format.block_extent() } else { (1, 1, 1) };
+        let row_bytes = extent.width.div_ceil(block_width) as DeviceSize * block_size;
+        let rows = extent.height.div_ceil(block_height) as DeviceSize;
+        let slices = extent.depth.div_ceil(block_depth) as DeviceSize;
+        let size = row_bytes * rows * slices;
+
+        // The copy is always tightly packed, as a linear image's rowPitch
+        // need not be a whole number of texel blocks, which bufferRowLength
+        // is counted in. Its rows are spread out to their pitch afterwards.
+        let region = BufferImageCopy {
+            bufferOffset: 0,
+            bufferRowLength: 0,
+            bufferImageHeight: 0,
+            imageSubresource: ImageSubresourceLayers {
+                aspectMask: aspect,
+                mipLevel: level,
+                baseArrayLayer: subresource.arrayLayer,
+                layerCount: 1
+            },
+            imageOffset: Offset3D { x: 0, y: 0, z: 0 },
+            imageExtent: extent
+        };
+        let to_transfer = ImageMemoryBarrier {
+            srcAccessMask: AccessFlags::MemoryWrite,
+            dstAccessMask: AccessFlags::TransferRead,
+            oldLayout: layout,
+            newLayout: ImageLayout::TransferSrcOptimal,
+            srcQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            dstQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            image: image,
+            subresourceRange: ImageSubresourceRange {
+                aspectMask: aspect,
+                baseMipLevel: level,
+                levelCount: 1,
+                baseArrayLayer: subresource.arrayLayer,
+                layerCount: 1
+            },
+            ..Default::default()
+        };
+        let from_transfer = ImageMemoryBarrier {
+            srcAccessMask: AccessFlags::empty(),
+            dstAccessMask: AccessFlags::MemoryRead | AccessFlags::MemoryWrite,
+            oldLayout: ImageLayout::TransferSrcOptimal,
+            newLayout: layout,
+            ..to_transfer
+        };
+        let packed = self.read_back(size, |command_buffer, readback| {
+            command_buffer.cmd_pipeline_barrier(PipelineStageFlags::AllCommands, PipelineStageFlags::Transfer, DependencyFlags::empty(), &[], &[], &[to_transfer]);
+            command_buffer.cmd_copy_image_to_buffer(image, ImageLayout::TransferSrcOptimal, readback, &[region]);
+            command_buffer.cmd_pipeline_barrier(PipelineStageFlags::Transfer, PipelineStageFlags::AllCommands, DependencyFlags::empty(), &[], &[], &[from_transfer]);
+        })?;
+        if info.tiling != ImageTiling::Linear {
+            return Ok(packed);
+        }
+
+        let pitches = self.device.get_image_subresource_layout(image, subresource);
+        let row_pitch = cmp::max(pitches.rowPitch, row_bytes);
+        let slice_pitch = if slices > 1 { cmp::max(pitches.depthPitch, row_pitch * rows) } else { row_pitch * rows };
+        let mut data = vec![0; ((slices - 1) * slice_pitch + (rows - 1) * row_pitch + row_bytes) as usize];
+        for (index, row) in packed.chunks(row_bytes as usize).enumerate() {
+            let (slice, row_in_slice) = (index as DeviceSize / rows, index as DeviceSize % rows);
+            let start = (slice * slice_pitch + row_in_slice * row_pitch) as usize;
+            data[start..start + row.len()].copy_from_slice(row);
+        }
+        Ok(data)
+    }
+
+    // Runs the commands `record` writes into a fresh host-visible buffer of
+    // `size` bytes, waits for them, and returns the buffer's contents.
+    fn read_back<F: FnOnce(&mut CachedCommandBuffer, Buffer)>(&mut self, size: DeviceSize, record: F) -> Result<Vec<u8>, Error> {
+        self.flush()?;
+        let (readback, memory) = create_host_buffer(self.device, size, BufferUsageFlags::TransferDst, MemoryUsage::GpuToCpu)?;
+
+        let barrier = BufferMemoryBarrier {
+            srcAccessMask: AccessFlags::TransferWrite,
+            dstAccessMask: AccessFlags::HostRead,
+            srcQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            dstQueueFamilyIndex: VK_QUEUE_FAMILY_IGNORED,
+            buffer: readback,
+            offset: 0,
+            size: VK_WHOLE_SIZE,
+            ..Default::default()
+        };
+        let result = self.execute(|command_buffer| {
+            record(command_buffer, readback);
+            command_buffer.cmd_pipeline_barrier(PipelineStageFlags::Transfer, PipelineStageFlags::Host, DependencyFlags::empty(), &[], &[barrier], &[]);
+        }).and_then(|_| {
+            let mapped = self.device.map_memory(memory, 0, size)?;
+            mapped.invalidate()?;
+            Ok(mapped.to_vec())
+        });
+
+        self.device.destroy_buffer(readback, None);
+        self.device.free_memory(memory, None);
+        result
+    }
+
+    // Records commands into a command buffer of their own, submits it and
+    // blocks until it has finished.
+    fn execute<F: FnOnce(&mut CachedCommandBuffer)>(&mut self, record: F) -> Result<(), Error> {
+        let (mut command_buffer, fence) = match self.spare.pop() {
+            Some(spare) => spare,
+            None        => self.create_command_buffer()?
+        };
+
+        let result = self.execute_on(&mut command_buffer, fence, record);
+        let reset = self.device.reset_fences(&[fence])
+            .and_then(|_| command_buffer.reset(CommandBufferResetFlags::empty()));
+        self.spare.push((command_buffer, fence));
+        result.and(reset)
+    }
+
+    fn execute_on<F: FnOnce(&mut CachedCommandBuffer)>(&mut self, command_buffer: &mut CachedCommandBuffer, fence: Fence, record: F) -> Result<(), Error> {
+        let begin_info = CommandBufferBeginInfo {
+            flags: CommandBufferUsageFlags::OneTimeSubmit,
+            ..Default::default()
+        };
+        command_buffer.begin(begin_info)?;
+        record(command_buffer);
+        command_buffer.end()?;
+
+        let submit_info = SubmitInfo {
+            commandBufferCount: 1,
+            pCommandBuffers: &**command_buffer,
+            ..Default::default()
+        };
+        self.queue.submit(&[submit_info], fence)?;
+        self.device.wait_for_fences(&[fence], true, u64::MAX).map(|_| ())
+    }
+
+    fn current_token(&self) -> UploadToken {
+        UploadToken(self.recording.as_ref().map_or(self.completed_serial, |batch| batch.serial))
+    }
+
+    // Copies `bytes` into the ring and returns the offset they were put at.
+    fn stage(&mut self, bytes: &[u8], alignment: DeviceSize) -> Result<DeviceSize, Error> {
+        let size = bytes.len() as DeviceSize;
+        assert!(size <= self.capacity, "StagingBelt cannot stage more than its capacity at once");
+
+        let offset = loop {
+            if let Some(offset) = self.find_space(size, alignment) {
+                break offset;
+            }
+            if self.recording.is_some() {
+                self.flush()?;
+            } else {
+                self.retire(true)?;
+            }
+        };
+
+        let start = offset as usize;
+        self.mapped.as_mut().unwrap()[start..start + bytes.len()].copy_from_slice(bytes);
+        self.head = offset + size;
+        Ok(offset)
+    }
+
+    // The ring holds data from `tail` up to `head`, wrapping at the end. New
+    // data goes after `head`, or at the start once it no longer fits at the
+    // end, and never reaches `tail` while anything is outstanding.
+    fn find_space(&mut self, size: DeviceSize, alignment: DeviceSize) -> Option<DeviceSize> {
+        if self.recording.is_none() && self.in_flight.is_empty() {
+            self.head = 0;
+            self.tail = 0;
+            return Some(0);
+        }
+
+        let offset = align_up(self.head, alignment);
+        if self.head >= self.tail {
+            if offset + size <= self.capacity {
+                Some(offset)
+            } else if size < self.tail {
+                Some(0)
+            } else {
+                None
+            }
+        } else if offset + size < self.tail {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    fn recording_command_buffer(&mut self) -> Result<&mut CachedCommandBuffer, Error> {
+        if self.recording.is_none() {
+            let (mut command_buffer, fence) = match self.spare.pop() {
+                Some(spare) => spare,
+                None        => self.create_command_buffer()?
+            };
+
+            let begin_info = CommandBufferBeginInfo {
+                flags: CommandBufferUsageFlags::OneTimeSubmit,
+                ..Default::default()
+            };
+            if let Err(error) = command_buffer.begin(begin_info) {
+                self.spare.push((command_buffer, fence));
+                return Err(error);
+            }
+
+            self.recording = Some(Batch { serial: self.next_serial, command_buffer: command_buffer, fence: fence, end: self.head, written: Vec::new() });
+            self.next_serial += 1;
+        }
+        Ok(&mut self.recording.as_mut().unwrap().command_buffer)
+    }
+
+    fn create_command_buffer(&self) -> Result<(CachedCommandBuffer, Fence), Error> {
+        let allocate_info = CommandBufferAllocateInfo {
+            commandPool: self.command_pool,
+            level: CommandBufferLevel::Primary,
+            commandBufferCount: 1,
+            ..Default::default()
+        };
+        let command_buffer = self.device.allocate_command_buffers(allocate_info)?[0];
+        match self.device.create_fence(FenceCreateInfo { ..Default::default() }, None) {
+            Ok(fence)  => Ok((unsafe { CachedCommandBuffer::with_state(command_buffer, self.state.clone()) }, fence)),
+            Err(error) => {
+                self.device.free_command_buffers(self.command_pool, &[command_buffer]);
+                Err(error)
+            }
+        }
+    }
+
+    // Moves finished batches out of flight, freeing their part of the ring.
+    // With `block` set, waits for the oldest batch if none have finished.
+    fn retire(&mut self, block: bool) -> Result<(), Error> {
+        let mut first = true;
+        while let Some(fence) = self.in_flight.front().map(|batch| batch.fence) {
+            let status = if block && first {
+                self.device.wait_for_fences(&[fence], true, u64::MAX)?
+            } else {
+                self.device.get_fence_status(fence)?
+            };
+            if status != Success::Success {
+                break;
+            }
+            first = false;
+
+            let mut batch = self.in_flight.pop_front().unwrap();
+            self.tail = batch.end;
+            self.completed_serial = batch.serial;
+            self.device.reset_fences(&[batch.fence])?;
+            batch.command_buffer.reset(CommandBufferResetFlags::empty())?;
+            self.spare.push((batch.command_buffer, batch.fence));
+        }
+        Ok(())
+    }
+}
+
+impl<'d> Drop for StagingBelt<'d> {
+    fn drop(&mut self) {
+        let fences: Vec<Fence> = self.in_flight.iter().map(|batch| batch.fence).collect();
+        if !fences.is_empty() {
+            let _ = self.device.wait_for_fences(&fences, true, u64::MAX);
+        }
+
+        let batches = self.recording.take().into_iter().chain(self.in_flight.drain(..));
+        let spare = batches.map(|batch| (batch.command_buffer, batch.fence)).chain(self.spare.drain(..));
+        for (command_buffer, fence) in spare {
+            self.device.free_command_buffers(self.command_pool, &[command_buffer.handle()]);
+            self.device.destroy_fence(fence, None);
+        }
+
+        self.device.destroy_command_pool(self.command_pool, None);
+        self.mapped = None;
+        self.device.destroy_buffer(self.buffer, None);
+        self.device.free_memory(self.memory, None);
+    }
+}
+
+// Creates a buffer of `size` bytes with memory for `memory_usage` bound to it.
+fn create_host_buffer(device: &Device, size: DeviceSize, usage: BufferUsageFlags, memory_usage: MemoryUsage) -> Result<(Buffer, DeviceMemory), Error> {
+    let buffer_create_info = BufferCreateInfo {
+        size: size,
+        usage: usage,
+        sharingMode: SharingMode::Exclusive,
+        ..Default::default()
+    };
+    let buffer = device.create_buffer(buffer_create_info, None)?;
+
+    let requirements = device.get_buffer_memory_requirements(buffer);
+    let memory_type_index = match device.get_physical_device().find_memory_type_for_usage(requirements.memoryTypeBits, memory_usage) {
+        Some(index) => index,
+        None        => {
+            device.destroy_buffer(buffer, None);
+            return Err(Error::OutOfDeviceMemory);
+        }
+    };
+    let allocate_info = MemoryAllocateInfo {
+        allocationSize: requirements.size,
+        memoryTypeIndex: memory_type_index,
+        ..Default::default()
+    };
+    let memory = match device.allocate_memory(allocate_info, None) {
+        Ok(memory) => memory,
+        Err(error) => {
+            device.destroy_buffer(buffer, None);
+            return Err(error);
+        }
+    };
+    if let Err(error) = device.bind_buffer_memory(buffer, memory, 0) {
+        device.destroy_buffer(buffer, None);
+        device.free_memory(memory, None);
+        return Err(error);
+    }
+    Ok((buffer, memory))
+}
+
+// The size of a texel block of `aspect` as vkCmdCopyImageToBuffer writes it
+// out. Depth and stencil are copied separately, and depth formats narrower
+// than 32 bits other than D16 are padded out to 32.
+fn aspect_block_size(format: Format, aspect: ImageAspectFlags) -> DeviceSize {
+    if aspect == ImageAspectFlags::Stencil {
+        1
+    } else if aspect == ImageAspectFlags::Depth {
+        match format {
+            Format::D16Unorm | Format::D16UnormS8Uint => 2,
+            _                                         => 4
+        }
+    } else {
+        format.block_size() as DeviceSize
+    }
+}
+
+fn align_up(value: DeviceSize, alignment: DeviceSize) -> DeviceSize {
+    value.div_ceil(alignment) * alignment
+}
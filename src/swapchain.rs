@@ -0,0 +1,367 @@
+// A swapchain over a Surface, with a view of each of its images. The device
+// must have been created with VK_KHR_swapchain enabled.
+//
+// When presentation reports the swapchain out of date or suboptimal, which is
+// how a resized or reconfigured window shows up, it is recreated in place from
+// the surface's current capabilities. Its images, views and extent may then
+// all have changed; `generation` counts recreations so that whatever was built
+// against them, such as framebuffers, can tell when to be rebuilt.
+
+use std::cmp;
+use std::ptr;
+
+use types::*;
+use vk::*;
+use wsi::Surface;
+use {SpockDevice, SpockPhysicalDevice};
+
+pub const EXTENSIONS: &[&str] = &[VK_KHR_SWAPCHAIN_EXTENSION_NAME];
+
+// How many times acquire_next_image recreates a swapchain that keeps going
+// out of date before giving up, as it does while a window is being resized
+// faster than it can keep up with.
+const MAX_RECREATE_ATTEMPTS: u32 = 8;
+
+// What to ask of the surface. Anything it does not support falls back to
+// something it must: Fifo for the present mode, and for the format the first
+// one it lists, after B8G8R8A8Srgb and B8G8R8A8Unorm.
+#[derive(Copy, Clone)]
+pub struct SwapchainOptions {
+    pub format: Option<SurfaceFormatKHR>,
+    pub present_mode: PresentModeKHR,
+    // The extent to use when the surface leaves it to the swapchain, as
+    // Wayland does. Otherwise the window's size is used.
+    pub extent: Extent2D,
+    // The number of images to ask for on top of the surface's minimum, so
+    // that one can be acquired while the others are being presented.
+    pub extra_images: u32,
+    pub usage: ImageUsageFlags
+}
+
+impl Default for SwapchainOptions {
+    fn default() -> SwapchainOptions {
+        SwapchainOptions {
+            format: None,
+            present_mode: PresentModeKHR::Fifo,
+            extent: Extent2D { width: 0, height: 0 },
+            extra_images: 1,
+            usage: ImageUsageFlags::ColorAttachment
+        }
+    }
+}
+
+pub struct Swapchain<'a> {
+    device: &'a Device,
+    surface: &'a Surface<'a>,
+    queue_node_index: u32,
+    options: SwapchainOptions,
+    allocator: Option<AllocationCallbacks>,
+    handle: SwapchainKHR,
+    format: SurfaceFormatKHR,
+    present_mode: PresentModeKHR,
+    extent: Extent2D,
+    images: Vec<Image>,
+    views: Vec<ImageView>,
+    generation: u64
+}
+
+impl<'a> Swapchain<'a> {
+    // `queue_node_index` is the queue family images will be presented from,
+    // which must be able to present to `surface`. Images are created with
+    // exclusive sharing, so it should be the family that renders to them too.
+    // Fails with OutOfDateKHR if the surface has no area, as a minimised
+    // window does.
+    pub fn new(device: &'a Device, surface: &'a Surface<'a>, queue_node_index: u32, options: SwapchainOptions, allocator_opt: Option<AllocationCallbacks>) -> Result<Swapchain<'a>, Error> {
+        let loaded = unsafe { device_state(device.as_raw()).is_some_and(|state| state.vkCreateSwapchainKHR.is_some()) };
+        if !loaded {
+            return Err(Error::ExtensionNotPresent);
+        }
+        if !device.get_physical_device().get_surface_support(queue_node_index, surface.handle())? {
+            return Err(Error::FeatureNotPresent);
+        }
+
+        let mut swapchain = Swapchain {
+            device: device,
+            surface: surface,
+            queue_node_index: queue_node_index,
+            options: options,
+            allocator: allocator_opt,
+            handle: SwapchainKHR::null(),
+            format: SurfaceFormatKHR { format: Format::Undefined, colorSpace: ColorSpaceKHR::SrgbNonlinear },
+            present_mode: PresentModeKHR::Fifo,
+            extent: Extent2D { width: 0, height: 0 },
+            images: Vec::new(),
+            views: Vec::new(),
+            generation: 0
+        };
+        if !swapchain.create()? {
+            return Err(Error::OutOfDateKHR);
+        }
+        Ok(swapchain)
+    }
+
+    // Acquires the next image to render to, returning its index, or None if
+    // none became available within `timeout` nanoseconds. `semaphore` and
+    // `fence`, either of which may be null, are signaled once the image can
+    // be written to. An out of date swapchain is recreated and retried, up to
+    // MAX_RECREATE_ATTEMPTS times before failing with OutOfDateKHR; a
+    // suboptimal one is recreated after the image has been presented. While
+    // the surface has no area, there is no image to acquire, and this
+    // returns None straight away.
+    pub fn acquire_next_image(&mut self, timeout: u64, semaphore: Semaphore, fence: Fence) -> Result<Option<u32>, Error> {
+        for _ in 0..MAX_RECREATE_ATTEMPTS {
+            let mut index = 0;
+            let result = unsafe { vkAcquireNextImageKHR(*self.device, self.handle, timeout, semaphore, fence, &mut index) };
+            match result.result() {
+                Ok(Success::Success) | Ok(Success::SuboptimalKHR) => return Ok(Some(index)),
+                Ok(_)                                             => return Ok(None),
+                Err(Error::OutOfDateKHR)                          => {
+                    if !self.recreate()? {
+                        return Ok(None);
+                    }
+                },
+                Err(error)                                        => return Err(error)
+            }
+        }
+        Err(Error::OutOfDateKHR)
+    }
+
+    // As acquire_next_image, waiting for as long as it takes. Fails with
+    // OutOfDateKHR while the surface has no area.
+    pub fn acquire_next_image_blocking(&mut self, semaphore: Semaphore, fence: Fence) -> Result<u32, Error> {
+        self.acquire_next_image(u64::MAX, semaphore, fence)?.ok_or(Error::OutOfDateKHR)
+    }
+
+    // Presents image `image_index` on `queue`, raw or cached, once
+    // `wait_semaphores` are signaled. The swapchain is recreated if that
+    // showed it to be out of date or suboptimal.
+    pub fn queue_present<Q: QueueDispatch>(&mut self, queue: &mut Q, wait_semaphores: &[Semaphore], image_index: u32) -> Result<(), Error> {
+        assert!(image_index < self.image_count(), "Swapchain.queue_present must be given the index of one of its images");
+
+        let present_info = PresentInfoKHR {
+            waitSemaphoreCount: wait_semaphores.len() as u32,
+            pWaitSemaphores: wait_semaphores.as_ptr(),
+            swapchainCount: 1,
+            pSwapchains: &self.handle,
+            pImageIndices: &image_index,
+            ..Default::default()
+        };
+        let result = unsafe { queue.state().vkQueuePresentKHR(*queue.queue(), &present_info) };
+        match result.result() {
+            Ok(Success::SuboptimalKHR) | Err(Error::OutOfDateKHR) => self.recreate().map(|_| ()),
+            Ok(_)                                                 => Ok(()),
+            Err(error)                                            => Err(error)
+        }
+    }
+
+    // Recreates the swapchain for a new window size, for surfaces whose
+    // extent is left to the swapchain and so never go out of date.
+    pub fn resize(&mut self, extent: Extent2D) -> Result<(), Error> {
+        self.options.extent = extent;
+        self.recreate().map(|_| ())
+    }
+
+    // Recreates the swapchain from the surface's current state, returning
+    // whether it did. While the surface has no area, as when its window is
+    // minimised, no swapchain can be made for it, and the current one is
+    // left as it is until the window is restored.
+    //
+    // The swapchain cannot tell which queues are still using its images, so
+    // this waits for the whole device to go idle before they and their views
+    // are destroyed. That stalls every queue, which is only acceptable
+    // because windows are resized rarely.
+    pub fn recreate(&mut self) -> Result<bool, Error> {
+        self.device.wait_idle()?;
+        if !self.create()? {
+            return Ok(false);
+        }
+        self.generation += 1;
+        Ok(true)
+    }
+
+    // Creates a swapchain from the surface's current state, retiring the
+    // existing one, if any, in its favour. Nothing changes unless every part
+    // of the new swapchain is created. Returns false without creating
+    // anything if the surface has no area.
+    fn create(&mut self) -> Result<bool, Error> {
+        let physical_device = self.device.get_physical_device();
+        let surface = self.surface.handle();
+        let capabilities = physical_device.get_surface_capabilities(surface)?;
+        let extent = choose_extent(&capabilities, self.options.extent);
+        if extent.width == 0 || extent.height == 0 {
+            return Ok(false);
+        }
+        let format = choose_format(&physical_device.get_surface_formats(surface)?, self.options.format)?;
+        let present_modes = physical_device.get_surface_present_modes(surface)?;
+        let present_mode = if present_modes.contains(&self.options.present_mode) { self.options.present_mode } else { PresentModeKHR::Fifo };
+
+        let mut image_count = capabilities.minImageCount + self.options.extra_images;
+        if capabilities.maxImageCount > 0 {
+            image_count = cmp::min(image_count, capabilities.maxImageCount);
+        }
+
+        let create_info = SwapchainCreateInfoKHR {
+            surface: surface,
+            minImageCount: image_count,
+            imageFormat: format.format,
+            imageColorSpace: format.colorSpace,
+            imageExtent: extent,
+            imageArrayLayers: 1,
+            imageUsage: self.options.usage,
+            imageSharingMode: SharingMode::Exclusive,
+            preTransform: capabilities.currentTransform,
+            compositeAlpha: choose_composite_alpha(capabilities.supportedCompositeAlpha),
+            presentMode: present_mode,
+            clipped: VK_TRUE,
+            oldSwapchain: self.handle,
+            ..Default::default()
+        };
+
+        let mut handle = SwapchainKHR::null();
+        unsafe { vkCreateSwapchainKHR(*self.device, &create_info, pointer_of_option!(self.allocator), &mut handle).result()?; }
+
+        let mut views = Vec::new();
+        let images = unsafe { ::enumerate(|count, images| vkGetSwapchainImagesKHR(*self.device, handle, count, images)) }
+            .and_then(|images| {
+                for &image in &images {
+                    views.push(self.create_view(image, format.format)?);
+                }
+                Ok(images)
+            });
+        let images = match images {
+            Ok(images) => images,
+            Err(error) => {
+                for view in views {
+                    self.device.destroy_image_view(view, self.allocator);
+                }
+                unsafe { vkDestroySwapchainKHR(*self.device, handle, pointer_of_option!(self.allocator)); }
+                return Err(error);
+            }
+        };
+
+        // The old swapchain was retired by creating this one, so only the new
+        // one is of any use from here on.
+        self.destroy();
+        self.handle = handle;
+        self.format = format;
+        self.present_mode = present_mode;
+        self.extent = extent;
+        self.images = images;
+        self.views = views;
+        Ok(true)
+    }
+
+    fn create_view(&self, image: Image, format: Format) -> Result<ImageView, Error> {
+        let view_info = ImageViewCreateInfo {
+            image: image,
+            viewType: ImageViewType::Type2D,
+            format: format,
+            subresourceRange: ImageSubresourceRange {
+                aspectMask: ImageAspectFlags::Color,
+                baseMipLevel: 0,
+                levelCount: 1,
+                baseArrayLayer: 0,
+                layerCount: 1
+            },
+            ..Default::default()
+        };
+        self.device.create_image_view(view_info, self.allocator)
+    }
+
+    fn destroy(&mut self) {
+        for view in self.views.drain(..) {
+            self.device.destroy_image_view(view, self.allocator);
+        }
+        self.images.clear();
+        if self.handle != SwapchainKHR::null() {
+            unsafe { vkDestroySwapchainKHR(*self.device, self.handle, pointer_of_option!(self.allocator)); }
+            self.handle = SwapchainKHR::null();
+        }
+    }
+
+    pub fn handle(&self) -> SwapchainKHR {
+        self.handle
+    }
+
+    pub fn surface(&self) -> &'a Surface<'a> {
+        self.surface
+    }
+
+    pub fn queue_node_index(&self) -> u32 {
+        self.queue_node_index
+    }
+
+    pub fn image_count(&self) -> u32 {
+        self.images.len() as u32
+    }
+
+    pub fn images(&self) -> &[Image] {
+        &self.images
+    }
+
+    pub fn image_views(&self) -> &[ImageView] {
+        &self.views
+    }
+
+    pub fn format(&self) -> Format {
+        self.format.format
+    }
+
+    pub fn color_space(&self) -> ColorSpaceKHR {
+        self.format.colorSpace
+    }
+
+    pub fn present_mode(&self) -> PresentModeKHR {
+        self.present_mode
+    }
+
+    pub fn extent(&self) -> Extent2D {
+        self.extent
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl<'a> Drop for Swapchain<'a> {
+    fn drop(&mut self) {
+        self.destroy();
+    }
+}
+
+// A single Undefined format means the surface takes any format at all. A
+// surface with no formats cannot be presented to.
+fn choose_format(available: &[SurfaceFormatKHR], wanted: Option<SurfaceFormatKHR>) -> Result<SurfaceFormatKHR, Error> {
+    let srgb = SurfaceFormatKHR { format: Format::B8G8R8A8Srgb, colorSpace: ColorSpaceKHR::SrgbNonlinear };
+    let unorm = SurfaceFormatKHR { format: Format::B8G8R8A8Unorm, colorSpace: ColorSpaceKHR::SrgbNonlinear };
+    if available.len() == 1 && available[0].format == Format::Undefined {
+        return Ok(wanted.unwrap_or(srgb));
+    }
+
+    let supported = |format: &SurfaceFormatKHR| available.iter().any(|other| other.format == format.format && other.colorSpace == format.colorSpace);
+    wanted.into_iter()
+        .chain(vec![srgb, unorm])
+        .find(supported)
+        .or(available.first().cloned())
+        .ok_or(Error::FormatNotSupported)
+}
+
+// An extent of u32::MAX by u32::MAX means the swapchain decides it.
+fn choose_extent(capabilities: &SurfaceCapabilitiesKHR, wanted: Extent2D) -> Extent2D {
+    if capabilities.currentExtent.width != u32::MAX {
+        return capabilities.currentExtent;
+    }
+    Extent2D {
+        width: cmp::max(capabilities.minImageExtent.width, cmp::min(capabilities.maxImageExtent.width, wanted.width)),
+        height: cmp::max(capabilities.minImageExtent.height, cmp::min(capabilities.maxImageExtent.height, wanted.height))
+    }
+}
+
+fn choose_composite_alpha(supported: CompositeAlphaFlagsKHR) -> CompositeAlphaFlagsKHR {
+    [CompositeAlphaFlagsKHR::Opaque, CompositeAlphaFlagsKHR::Inherit, CompositeAlphaFlagsKHR::PreMultiplied, CompositeAlphaFlagsKHR::PostMultiplied]
+        .iter()
+        .cloned()
+        .find(|&alpha| supported.contains(alpha))
+        .unwrap_or(CompositeAlphaFlagsKHR::Opaque)
+}
@@ -1,165 +1,530 @@
 extern crate libc;
-use libc::{uint32_t, uint64_t, int32_t, size_t, wchar_t, c_void, c_float};
+use libc::{uint32_t, uint64_t, int32_t, size_t, c_char, c_void, c_float};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::mem;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use loader;
 use types::*;
 
-#[link(name = "vulkan-1")]
-#[allow(non_snake_case)]
-#[allow(dead_code)]
-extern "C" {
-    pub fn vkCreateInstance(pCreateInfo: *const InstanceCreateInfo, pAllocator: *const AllocationCallbacks, pInstance: *mut Instance) -> Error;
-    pub fn vkDestroyInstance(instance: Instance, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkEnumeratePhysicalDevices(instance: Instance, pPhysicalDeviceCount: *mut uint32_t, pPhysicalDevices: *mut PhysicalDevice) -> Error;
-    pub fn vkGetPhysicalDeviceFeatures(physicalDevice: PhysicalDevice, pFeatures: *mut PhysicalDeviceFeatures) -> c_void;
-    pub fn vkGetPhysicalDeviceFormatProperties(physicalDevice: PhysicalDevice, format: Format, pFormatProperties: *mut FormatProperties) -> c_void;
-    pub fn vkGetPhysicalDeviceImageFormatProperties(physicalDevice: PhysicalDevice, format: Format, imageType: ImageType, tiling: ImageTiling,
-                                                usage: ImageUsageFlags, flags: ImageCreateFlags, pImageFormatProperties: *mut ImageFormatProperties) -> Error;
-    pub fn vkGetPhysicalDeviceProperties(physicalDevice: PhysicalDevice, pProperties: *mut PhysicalDeviceProperties) -> c_void;
-    pub fn vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice: PhysicalDevice, pQueueFamilyProperyCount: *mut uint32_t, pQueueFamilyProperties: *mut QueueFamilyProperties) -> c_void;
-    pub fn vkGetPhysicalDeviceMemoryProperties(physicalDevice: PhysicalDevice, pMemoryProperties: *mut PhysicalDeviceMemoryProperties) -> c_void;
-    pub fn vkGetInstanceProcAddr(instance: Instance, pName: *const wchar_t) -> VoidFunction;
-    pub fn vkGetDeviceProcAddr(device: Device, pName: *const wchar_t) -> VoidFunction;
-    pub fn vkCreateDevice(physicalDevice: PhysicalDevice, pCreateInfo: *const DeviceCreateInfo, pAllocator: *const AllocationCallbacks, pDevice: *mut Device) -> Error;
-    pub fn vkDestroyDevice(device: Device, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkEnumerateInstanceExtensionProperties(pLayerName: *const wchar_t, pPropertyCount: *mut uint32_t, pProperties: *mut ExtensionProperties) -> Error;
-    pub fn vkEnumerateDeviceExtensionProperties(physicalDevice: PhysicalDevice, pLayerName: *const wchar_t, pPropertyCount: *mut uint32_t, pProperties: *mut ExtensionProperties) -> Error;
-    pub fn vkEnumerateInstanceLayerProperties(pPropertyCount: *mut uint32_t, pProperties: *mut LayerProperties) -> Error;
-    pub fn vkEnumerateDeviceLayerProperties(physicalDevice: PhysicalDevice, pPropertyCount: *mut uint32_t, pProperties: *mut LayerProperties) -> Error;
-    pub fn vkGetDeviceQueue(device: Device, queueFamilyIndex: uint32_t, queueIndex: uint32_t, pQueue: *mut Queue) -> Error;
-    pub fn vkQueueSubmit(queue: Queue, submitCount: uint32_t, pSubmits: *const SubmitInfo) -> Error;
-    pub fn vkQueueWaitIdle(queue: Queue) -> Error;
-    pub fn vkDeviceWaitIdle(device: Device) -> Error;
-    pub fn vkAllocateMemory(device: Device, pAllocateInfo: *const MemoryAllocateInfo, pAllocator: *const AllocationCallbacks, pMemory: *mut DeviceMemory) -> Error;
-    pub fn vkFreeMemory(device: Device, memory: DeviceMemory, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkMapMemory(device: Device, memory: DeviceMemory, offset: DeviceSize, size: DeviceSize, flags: MemoryMapFlags, ppData: *mut *mut c_void) -> Error;
-    pub fn vkUnmapMemory(device: Device, memory: DeviceMemory) -> c_void;
-    pub fn vkFlushMappedMemoryRanges(device: Device, memoryRangeCount: uint32_t, pMemoryRanges: *const MappedMemoryRange) -> Error;
-    pub fn vkInvalidateMappedMemoryRanges(device: Device, memoryRangeCount: uint32_t, pMemoryRanges: *const MappedMemoryRange) -> Error;
-    pub fn vkGetDeviceMemoryCommitment(device: Device, memory: DeviceMemory, pCommittedMemoryInBytes: *mut DeviceSize) -> c_void;
-    pub fn vkBindBufferMemory(device: Device, buffer: Buffer, memory: DeviceMemory, memoryOffset: DeviceSize) -> Error;
-    pub fn vkBindImageMemory(device: Device, image: Image, memory: DeviceMemory, memoryOffset: DeviceSize) -> Error;
-    pub fn vkGetBufferMemoryRequirements(device: Device, buffer: Buffer, pMemoryRequirements: *mut MemoryRequirements) -> c_void;
-    pub fn vkGetImageMemoryRequirements(device: Device, image: Image, pMemoryRequirements: *mut MemoryRequirements) -> c_void;
-    pub fn vkGetImageSparseMemoryRequirements(device: Device, image: Image, pSparseMemoryRequirementCount: *mut uint32_t, pSparseMemoryRequirements: *mut SparseImageMemoryRequirements) -> c_void;
-    pub fn vkGetPhysicalDeviceSparseImageFormatProperties(physicalDevice: PhysicalDevice, format: Format, imageType: ImageType, samples: SampleCountFlags,
-                                                      usage: ImageUsageFlags, tiling: ImageTiling, pPropertyCount: *mut uint32_t, pProperties: *mut SparseImageFormatProperties) -> c_void;
-    pub fn vkQueueBindSparse(queue: Queue, bindInfoCount: uint32_t, pBindInfo: *const BindSparseInfo, fence: Fence) -> Error;
-    pub fn vkCreateFence(device: Device, pCreateInfo: *const FenceCreateInfo, pAllocator: *const AllocationCallbacks, pFence: *mut Fence) -> Error;
-    pub fn vkDestroyFence(device: Device, fence: Fence, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkResetFences(device: Device, fenceCount: uint32_t, pFences: *const Fence) -> Error;
-    pub fn vkGetFenceStatus(device: Device, fence: Fence) -> Error;
-    pub fn vkWaitForFences(device: Device, fenceCount: uint32_t, pFences: *const Fence, waitAll: Bool32, timeout: uint64_t) -> Error;
-    pub fn vkCreateSemaphore(device: Device, pCreateInfo: *const SemaphoreCreateInfo, pAllocator: *const AllocationCallbacks, pSemaphore: *mut Semaphore) -> Error;
-    pub fn vkDestroySemaphore(device: Device, semaphore: Semaphore, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateEvent(device: Device, pCreateInfo: *const EventCreateInfo, pAllocator: *const AllocationCallbacks, pEvent: *mut Event) -> Error;
-    pub fn vkDestroyEvent(device: Device, event: Event, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkGetEventStatus(device: Device, event: Event) -> Error;
-    pub fn vkSetEvent(device: Device, event: Event) -> Error;
-    pub fn vkResetEvent(device: Device, event: Event) -> Error;
-    pub fn vkCreateQueryPool(device: Device, pCreateInfo: *const QueryPoolCreateInfo, pAllocator: *const AllocationCallbacks, pQueryPool: *mut QueryPool) -> Error;
-    pub fn vkDestroyQueryPool(device: Device, queryPool: QueryPool, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkGetQueryPoolErrors(device: Device, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t, dataSize: size_t, pData: *mut c_void,
-                             stride: DeviceSize, flags: QueryErrorFlags) -> Error;
-    pub fn vkCreateBuffer(device: Device, pCreateInfo: *const BufferCreateInfo, pAllocator: *const AllocationCallbacks, pBuffer: *mut Buffer) -> Error;
-    pub fn vkDestroyBuffer(device: Device, buffer: Buffer, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateBufferView(device: Device, pCreateInfo: *const BufferViewCreateInfo, pAllocator: *const AllocationCallbacks, pView: *mut BufferView) -> Error;
-    pub fn vkDestroyBufferView(device: Device, bufferView: BufferView, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateImage(device: Device, pCreateInfo: *const ImageCreateInfo, pAllocator: *const AllocationCallbacks, pImage: *mut Image) -> Error;
-    pub fn vkDestroyImage(device: Device, image: Image, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkGetImageSubresourceLayout(device: Device, image: Image, pSubresource: *const ImageSubresource, pLayout: *mut SubresourceLayout) -> c_void;
-    pub fn vkCreateImageView(device: Device, pCreateInfo: *const ImageViewCreateInfo, pAllocator: *const AllocationCallbacks, pView: *mut ImageView) -> Error;
-    pub fn vkDestroyImageView(device: Device, imageView: ImageView, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateShaderModule(device: Device, pCreateInfo: *const ShaderModuleCreateInfo, pAllocator: *const AllocationCallbacks, pShaderModule: *mut ShaderModule) -> Error;
-    pub fn vkDestroyShaderModule(device: Device, shaderModule: ShaderModule, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreatePipelineCache(device: Device, pCreateInfo: *const PipelineCacheCreateInfo, pAllocator: *const AllocationCallbacks, pPipelineCache: *mut PipelineCache) -> Error;
-    pub fn vkDestroyPipelineCache(device: Device, pipelineCache: PipelineCache, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkGetPipelineCacheData(device: Device, pipelineCache: PipelineCache, pDataSize: *mut size_t, pData: *mut c_void) -> Error;
-    pub fn vkMergePipelineCaches(device: Device, dstCache: PipelineCache, srcCacheCount: uint32_t, pSrcCaches: *const PipelineCache) -> Error;
-    pub fn vkCreateGraphicsPipelines(device: Device, pipelineCache: PipelineCache, createInfoCount: uint32_t, pCreateInfos: *const GraphicsPipelineCreateInfo,
-                                 pAllocator: *const AllocationCallbacks, pPipelines: *mut Pipeline) -> Error;
-    pub fn vkCreateComputePipelines(device: Device, pipelineCache: PipelineCache, createInfoCount: uint32_t, pCreateInfos: *const ComputePipelineCreateInfo,
-                                pAllocator: *const AllocationCallbacks, pPipelines: *mut Pipeline) -> Error;
-    pub fn vkDestroyPipeline(device: Device, pipeline: Pipeline, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreatePipelineLayout(device: Device, pCreateInfo: *const PipelineLayoutCreateInfo, pAllocator: *const AllocationCallbacks, pPipelineLayout: *mut PipelineLayout) -> Error;
-    pub fn vkDestroyPipelineLayout(device: Device, pipelineLayout: PipelineLayout, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateSampler(device: Device, pCreateInfo: *const SamplerCreateInfo, pAllocator: *const AllocationCallbacks) -> Error;
-    pub fn vkDestroySampler(device: Device, sampler: Sampler, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateDescriptorSetLayout(device: Device, pCreateInfo: *const DescriptorSetLayoutCreateInfo, pAllocator: *const AllocationCallbacks, pSetLayout: *mut DescriptorSetLayout) -> Error;
-    pub fn vkDestroyDescriptorSetLayout(device: Device, descriptorSetLayout: DescriptorSetLayout, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateDescriptorPool(device: Device, pCreateInfo: *const DescriptorPoolCreateInfo, pAllocator: *const AllocationCallbacks, pDescriptorPool: *mut DescriptorPool) -> Error;
-    pub fn vkDestroyDescriptorPool(device: Device, descriptorPool: DescriptorPool, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkResetDescriptorPool(device: Device, descriptorPool: DescriptorPool, flags: DescriptorPoolResetFlags) -> Error;
-    pub fn vkAllocateDescriptorSets(device: Device, pAllocateInfo: *const DescriptorSetAllocateInfo, pDescriptorSets: *mut DescriptorSet) -> Error;
-    pub fn vkFreeDescriptorSets(device: Device, descriptorPool: DescriptorPool, descriptorSetCount: uint32_t, pDescriptorSets: *const DescriptorSet) -> Error;
-    pub fn vkUpdateDescriptorSets(device: Device, descriptorWriteCount: uint32_t, pDescriptorWrites: *const WriteDescriptorSet, descriptorCopyCount: uint32_t,
-                              pDescriptorCopies: *const CopyDescriptorSet) -> c_void;
-    pub fn vkCreateFramebuffer(device: Device, pCreateInfo: *const FramebufferCreateInfo, pAllocator: *const AllocationCallbacks, pFramebuffer: *mut Framebuffer) -> Error;
-    pub fn vkDestroyFramebuffer(device: Device, framebuffer: Framebuffer, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkCreateRenderPass(device: Device, pCreateInfo: *const RenderPassCreateInfo, pAllocator: *const AllocationCallbacks, pRenderPass: *mut RenderPass) -> Error;
-    pub fn vkDestroyRenderPass(device: Device, renderPass: RenderPass, pAllactore: *const AllocationCallbacks) -> c_void;
-    pub fn vkGetRenderAreaGranularity(device: Device, renderPass: RenderPass, pGranularity: *mut Extent2D) -> c_void;
-    pub fn vkCreateCommandPool(device: Device, pCreateInfo: *const CommandPoolCreateInfo, pAllocator: *const AllocationCallbacks, pCommandPool: *mut CommandPool) -> Error;
-    pub fn vkDestroyCommandPool(device: Device, commandPool: CommandPool, pAllocator: *const AllocationCallbacks) -> c_void;
-    pub fn vkResetCommandPool(device: Device, commandPool: CommandPool, flags: CommandPoolResetFlags) -> Error;
-    pub fn vkAllocateCommandBuffers(device: Device, pAllocateInfo: *const CommandBufferAllocateInfo, pCommandBuffers: *mut CommandBuffer) -> Error;
-    pub fn vkFreeCommandBuffers(device: Device, commandPool: CommandPool, commandBufferCount: uint32_t, pCommandBuffers: *const CommandBuffer) -> c_void;
-    pub fn vkBeginCommandBuffer(commandBuffer: CommandBuffer, pBeginInfo: *const CommandBufferBeginInfo) -> Error;
-    pub fn vkEndCommandBuffer(commandBuffer: CommandBuffer) -> Error;
-    pub fn vkResetCommandBuffer(commandBuffer: CommandBuffer, flags: CommandBufferResetFlags) -> Error;
-    pub fn vkCmdBindPipeline(commandBuffer: CommandBuffer, pipelineBindPoint: PipelineBindPoint, pipeline: Pipeline) -> c_void;
-    pub fn vkCmdSetViewport(commandBuffer: CommandBuffer, firstViewport: uint32_t, viewportCount: uint32_t, pViewports: *const Viewport) -> c_void;
-    pub fn vkCmdSetScissor(commandBuffer: CommandBuffer, firstScissor: uint32_t, scissorCount: uint32_t, pScissors: *const Rect2D) -> c_void;
-    pub fn vkCmdSetLineWidth(commandBuffer: CommandBuffer, lineWidth: c_float) -> c_void;
-    pub fn vkCmdSetDepthBias(commandBuffer: CommandBuffer, depthBiasConstantFactor: c_float, depthBiasClamp: c_float, depthBiasSlopeFactor: c_float) -> c_void;
-    pub fn vkCmdSetBlendConstants(commandBuffer: CommandBuffer, blendConstants: [c_float; 4]) -> c_void;
-    pub fn vkCmdSetDepthBounds(commandBuffer: CommandBuffer, minDepthBounds: c_float, maxDepthBounds: c_float) -> c_void;
-    pub fn vkCmdSetStencilCompareMask(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, compareMask: uint32_t) -> c_void;
-    pub fn vkCmdSetStencilWriteMask(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, writeMask: uint32_t) -> c_void;
-    pub fn vkCmdSetStencilReference(commandBuffer: CommandBuffer, faceMask: StencilFaceFlags, reference: uint32_t) -> c_void;
-    pub fn vkCmdBindDescriptorSets(commandBuffer: CommandBuffer, pipelineBindPoint: PipelineBindPoint, layout: PipelineLayout, firstSet: uint32_t,
-                               descriptorSetCount: uint32_t, pDescriptorSets: *const DescriptorSet, dynamicOffsetCount: uint32_t, pDynamicOffsets: *const uint32_t) -> c_void;
-    pub fn vkCmdBindIndexBuffer(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, indexType: IndexType) -> c_void;
-    pub fn vkCmdBindVertexBuffer(commandBuffer: CommandBuffer, firstBinding: uint32_t, bindingCount: uint32_t, pBuffers: *const Buffer, pOffsets: *const DeviceSize) -> c_void;
-    pub fn vkCmdDraw(commandBuffer: CommandBuffer, vertexCount: uint32_t, instanceCount: uint32_t, firstVertext: uint32_t, firstInstance: uint32_t) -> c_void;
-    pub fn vkCmdDrawIndexed(commandBuffer: CommandBuffer, indexCount: uint32_t, instanceCount: uint32_t, firstIndex: uint32_t, vertexOffset: int32_t, firstInstance: uint32_t) -> c_void;
-    pub fn vkCmdDrawIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, drawCount: uint32_t, stride: uint32_t) -> c_void;
-    pub fn vkCmdDrawIndexedIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize, drawCount: uint32_t, stride: uint32_t) -> c_void;
-    pub fn vkCmdDispatch(commandBuffer: CommandBuffer, x: uint32_t, y: uint32_t, z: uint32_t) -> c_void;
-    pub fn vkCmdDispatchIndirect(commandBuffer: CommandBuffer, buffer: Buffer, offset: DeviceSize) -> c_void;
-    pub fn vkCmdCopyBuffer(commandBuffer: CommandBuffer, srcBuffer: Buffer, dstBuffer: Buffer, regionCount: uint32_t, pRegions: *const BufferCopy) -> c_void;
-    pub fn vkCmdCopyImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
-                      regionCount: uint32_t, pRegions: *const ImageCopy) -> c_void;
-    pub fn vkCmdBlitImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
-                      regionCount: uint32_t, pRegions: *const ImageBlit, filter: Filter) -> c_void;
-    pub fn vkCmdCopyBufferToImage(commandBuffer: CommandBuffer, srcBuffer: Buffer, dstImage: Image, dstImageLayout: ImageLayout, regionCount: uint32_t,
-                              pRegions: *const BufferImageCopy) -> c_void;
-    pub fn vkCmdCopyImageToBuffer(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstBuffer: Buffer, regionCount: uint32_t,
-                              pRegions: *const BufferImageCopy) -> c_void;
-    pub fn vkCmdUpdateBuffer(commandBuffer: CommandBuffer, dstBuffer: Buffer, dstOffset: DeviceSize, dataSize: DeviceSize, pData: *const uint32_t) -> c_void;
-    pub fn vkCmdFillBuffer(commandBuffer: CommandBuffer, dstBuffer: Buffer, dstOffset: DeviceSize, size: DeviceSize, data: uint32_t) -> c_void;
-    pub fn vkCmdClearColorImage(commandBuffer: CommandBuffer, image: Image, imageLayout: ImageLayout, pColor: *const ClearColorValue, rangeCount: uint32_t,
-                            pRanges: *const ImageSubresourceRange) -> c_void;
-    pub fn vkCmdClearDepthStencilImage(commandBuffer: CommandBuffer, image: Image, imageLayout: ImageLayout, pDepthStencil: *const ClearDepthStencilValue,
-                                   rangeCount: uint32_t, pRanges: *const ImageSubresourceRange) -> c_void;
-    pub fn vkCmdClearAttachments(commandBuffer: CommandBuffer, attachmentCount: uint32_t, pAttachments: *const ClearAttachment, rectCount: uint32_t, pRects: *const ClearRect) -> c_void;
-    pub fn vkCmdResolveImage(commandBuffer: CommandBuffer, srcImage: Image, srcImageLayout: ImageLayout, dstImage: Image, dstImageLayout: ImageLayout,
-                         regionCount: uint32_t, pRegions: *const ImageResolve) -> c_void;
-    pub fn vkCmdSetEvent(commandBuffer: CommandBuffer, event: Event, stageMask: PipelineStageFlags) -> c_void;
-    pub fn vkCmdResetEvent(commandBuffer: CommandBuffer, event: Event, stageMask: PipelineStageFlags) -> c_void;
-    pub fn vkCmdWaitEvents(commandBuffer: CommandBuffer, eventCount: uint32_t, pEvents: *const Event, srcStageMask: PipelineStageFlags, dstStageMask: PipelineStageFlags,
-                       memoryBarrierCount: uint32_t, pMemoryBarriers: *const MemoryBarrier, bufferMemoryBarrierCount: uint32_t, pMemoryBarriers: *const BufferMemoryBarrier,
-                       imageMemoryBarrierCount: uint32_t, pImageMemoryBarriers: *const ImageMemoryBarrier) -> c_void;
-    pub fn vkCmdPipelineBarrier(commandBuffer: CommandBuffer, srcStageMask: PipelineStageFlags, dstStageMask: PipelineStageFlags, dependencyFlags: DependencyFlags,
-                            memoryBarrierCount: uint32_t, pMemoryBarriers: *const MemoryBarrier, bufferMemoryBarrierCount: uint32_t,
-                            pBufferMemoryBarriers: *const BufferMemoryBarrier, imageMemoryBarrierCount: uint32_t, pImageMemoryBarriers: *const ImageMemoryBarrier) -> c_void;
-    pub fn vkCmdBeginQuery(commandBuffer: CommandBuffer, queryPool: QueryPool, query: uint32_t, flags: QueryControlFlags) -> c_void;
-    pub fn vkCmdEndQuery(commandBuffer: CommandBuffer, queryPool: QueryPool, query: uint32_t) -> c_void;
-    pub fn vkCmdResetQueryPool(commandBuffer: CommandBuffer, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t) -> c_void;
-    pub fn vkCmdWriteTimestamp(commandBuffer: CommandBuffer, pipelineStage: PipelineStageFlags, queryPool: QueryPool, query: uint32_t) -> c_void;
-    pub fn vkCmdCopyQueryPoolErrors(commandBuffer: CommandBuffer, queryPool: QueryPool, firstQuery: uint32_t, queryCount: uint32_t, dstBuffer: Buffer,
-                                 dstOffset: DeviceSize, stride: DeviceSize, flags: QueryErrorFlags) -> c_void;
-    pub fn vkCmdPushConstants(commandBuffer: CommandBuffer, layout: PipelineLayout, stageFlags: ShaderStageFlags, offset: uint32_t, size: uint32_t, pValues: *const c_void) -> c_void;
-    pub fn vkCmdBeginRenderPass(commandBuffer: CommandBuffer, pRenderPassBegin: *const RenderPassBeginInfo, contents: SubpassContents) -> c_void;
-    pub fn vkCmdNextSubpass(commandBuffer: CommandBuffer, contents: SubpassContents) -> c_void;
-    pub fn vkCmdEndRenderPass(commandBuffer: CommandBuffer) -> c_void;
-    pub fn vkCmdExecuteCommands(commandBuffer: CommandBuffer, commandBufferCount: uint32_t, pCommandBuffers: *const CommandBuffer) -> c_void;
+// Declares a table of function pointers along with a loader that resolves
+// each of them by name. Commands in the second block can be called through a
+// method of the same name on the table, and also get a free function which
+// finds the table through its first (dispatchable) parameter; commands in the
+// first block are wrapped by hand below. The free functions take the
+// registry's lock and look the table up on every call, so the Spock* traits
+// go through the tables that the *Dispatch traits below hand them instead.
+macro_rules! dispatch_table {
+    ($table:ident($lookup:ident) {
+        $(fn $manual_name:ident($($manual_param:ident: $manual_ty:ty),*) $(-> $manual_ret:ty)*;)*
+    } {
+        $(fn $name:ident($handle:ident: $handle_ty:ty $(, $param:ident: $ty:ty)*) $(-> $ret:ty)*;)*
+    }) => {
+        #[allow(non_snake_case)]
+        pub struct $table {
+            $(pub $manual_name: Option<unsafe extern "system" fn($($manual_ty),*) $(-> $manual_ret)*>,)*
+            $(pub $name: Option<unsafe extern "system" fn($handle_ty $(, $ty)*) $(-> $ret)*>,)*
+        }
+
+        impl $table {
+            pub unsafe fn load<F>(mut get_proc_addr: F) -> $table where F: FnMut(&CStr) -> Option<VoidFunction> {
+                $table {
+                    $($manual_name: mem::transmute(get_proc_addr(command_name(concat!(stringify!($manual_name), "\0")))),)*
+                    $($name: mem::transmute(get_proc_addr(command_name(concat!(stringify!($name), "\0")))),)*
+                }
+            }
+
+            $(
+                #[allow(non_snake_case)]
+                pub unsafe fn $name(&self, $handle: $handle_ty $(, $param: $ty)*) $(-> $ret)* {
+                    match self.$name {
+                        Some(function) => function($handle $(, $param)*),
+                        None           => panic!(concat!(stringify!($name), " could not be loaded"))
+                    }
+                }
+            )*
+        }
+
+        $(
+            #[allow(non_snake_case)]
+            pub unsafe fn $name($handle: $handle_ty $(, $param: $ty)*) $(-> $ret)* {
+                $lookup($handle.as_raw()).$name($handle $(, $param)*)
+            }
+        )*
+    }
+}
+
+unsafe fn command_name(name: &'static str) -> &'static CStr {
+    CStr::from_bytes_with_nul_unchecked(name.as_bytes())
+}
+
+// Every dispatchable handle points at an object whose first word is the
+// loader's dispatch table pointer. That pointer is shared between an object
+// and everything created from it (an instance and its physical devices, a
+// device and its queues and command buffers), so the function tables loaded
+// for an instance or device are stored against it. Null handles have no
+// object to read it from, and are never found.
+unsafe fn dispatch_key(handle: *mut c_void) -> Option<usize> {
+    if handle.is_null() {
+        None
+    } else {
+        Some(*(handle as *const usize))
+    }
+}
+
+struct Registry<T> {
+    tables: OnceLock<RwLock<HashMap<usize, Arc<T>>>>
+}
+
+impl<T> Registry<T> {
+    const fn new() -> Registry<T> {
+        Registry { tables: OnceLock::new() }
+    }
+
+    fn tables(&self) -> &RwLock<HashMap<usize, Arc<T>>> {
+        self.tables.get_or_init(|| RwLock::new(HashMap::new()))
+    }
+
+    unsafe fn insert(&self, handle: *mut c_void, table: T) -> Arc<T> {
+        let key = dispatch_key(handle).expect("cannot register a null handle");
+        let table = Arc::new(table);
+        self.tables().write().unwrap().insert(key, table.clone());
+        table
+    }
+
+    unsafe fn get(&self, handle: *mut c_void) -> Option<Arc<T>> {
+        dispatch_key(handle).and_then(|key| self.tables().read().unwrap().get(&key).cloned())
+    }
+
+    unsafe fn remove(&self, handle: *mut c_void) -> Option<Arc<T>> {
+        dispatch_key(handle).and_then(|key| self.tables().write().unwrap().remove(&key))
+    }
+}
+
+// What is kept for a device: the functions loaded for it, the physical
+// device it was created from and that device's limits, and the memory,
+// images and query pools created from it. It derefs to the functions, so that commands can
+// be called straight through it.
+pub struct DeviceState {
+    pub fns: DeviceFns,
+    pub physical_device: PhysicalDevice,
+    pub limits: PhysicalDeviceLimits,
+    memory: Mutex<HashMap<DeviceMemory, Allocation>>,
+    images: Mutex<HashMap<Image, ImageInfo>>,
+    swapchains: Mutex<HashMap<SwapchainKHR, ImageInfo>>,
+    // The number of values each query in a pool results in.
+    query_pools: Mutex<HashMap<QueryPool, u32>>
+}
+
+struct Allocation {
+    size: DeviceSize,
+    mapped: bool
+}
+
+// What an image was created with, which Vulkan cannot be asked for later.
+// Swapchain images are described by their swapchain.
+#[derive(Copy, Clone)]
+pub struct ImageInfo {
+    pub format: Format,
+    pub extent: Extent3D,
+    pub tiling: ImageTiling,
+    swapchain: SwapchainKHR
+}
+
+impl DeviceState {
+    // Marks `memory` as mapped and returns its size. Vulkan does not allow an
+    // allocation to be mapped twice at once, so that fails with
+    // MemoryMapFailed, as does memory that was not allocated through spock.
+    pub fn begin_map(&self, memory: DeviceMemory) -> Result<DeviceSize, Error> {
+        match self.memory.lock().unwrap().get_mut(&memory) {
+            Some(ref mut allocation) if !allocation.mapped => {
+                allocation.mapped = true;
+                Ok(allocation.size)
+            },
+            _ => Err(Error::MemoryMapFailed)
+        }
+    }
+
+    pub fn end_map(&self, memory: DeviceMemory) {
+        if let Some(allocation) = self.memory.lock().unwrap().get_mut(&memory) {
+            allocation.mapped = false;
+        }
+    }
+
+    // None for images that were not created through spock, or that belong
+    // to a swapchain whose images were never asked for.
+    pub fn image_info(&self, image: Image) -> Option<ImageInfo> {
+        self.images.lock().unwrap().get(&image).cloned()
+    }
+
+    // The number of values each query of `query_pool` results in, leaving
+    // out availability: one for occlusion and timestamp queries, and one per
+    // statistic a pipeline statistics pool counts. None for pools that were
+    // not created through spock.
+    pub fn query_values(&self, query_pool: QueryPool) -> Option<u32> {
+        self.query_pools.lock().unwrap().get(&query_pool).cloned()
+    }
+}
+
+impl Deref for DeviceState {
+    type Target = DeviceFns;
+
+    fn deref(&self) -> &DeviceFns {
+        &self.fns
+    }
+}
+
+static ENTRY: OnceLock<EntryFns> = OnceLock::new();
+static INSTANCES: Registry<InstanceFns> = Registry::new();
+static DEVICES: Registry<DeviceState> = Registry::new();
+
+fn entry_table() -> &'static EntryFns {
+    ENTRY.get_or_init(|| {
+        match loader::loader() {
+            Ok(loader) => unsafe { EntryFns::load(|name| loader.get_instance_proc_addr(Instance::null(), name)) },
+            Err(error) => panic!("{}", error)
+        }
+    })
+}
+
+unsafe fn instance_table(handle: *mut c_void) -> Arc<InstanceFns> {
+    instance_fns(handle).expect("instance was not created through spock")
+}
+
+unsafe fn device_table(handle: *mut c_void) -> Arc<DeviceState> {
+    device_state(handle).expect("device was not created through spock")
+}
+
+// The functions loaded for the instance that `handle` (an Instance or
+// PhysicalDevice) belongs to.
+pub unsafe fn instance_fns(handle: *mut c_void) -> Option<Arc<InstanceFns>> {
+    INSTANCES.get(handle)
+}
+
+// What is kept for the device that `handle` (a Device, Queue or
+// CommandBuffer) belongs to.
+pub unsafe fn device_state(handle: *mut c_void) -> Option<Arc<DeviceState>> {
+    DEVICES.get(handle)
+}
+
+// The physical device that the device `handle` (a Device, Queue or
+// CommandBuffer) belongs to was created from.
+pub unsafe fn physical_device_of(handle: *mut c_void) -> Option<PhysicalDevice> {
+    device_state(handle).map(|state| state.physical_device)
+}
+
+// What the SpockInstance methods are called on. A raw Instance finds its
+// functions in the registry above on every call; an OwnedInstance keeps the
+// ones it was created with, and skips the lookup.
+pub trait InstanceDispatch {
+    type Fns<'a>: Deref<Target = InstanceFns> where Self: 'a;
+
+    fn instance(&self) -> &Instance;
+    fn fns(&self) -> Self::Fns<'_>;
+}
+
+impl InstanceDispatch for Instance {
+    type Fns<'a> = Arc<InstanceFns>;
+
+    fn instance(&self) -> &Instance {
+        self
+    }
+
+    fn fns(&self) -> Arc<InstanceFns> {
+        unsafe { instance_table(self.as_raw()) }
+    }
+}
+
+// As InstanceDispatch, for the SpockDevice methods.
+pub trait DeviceDispatch {
+    type State<'a>: Deref<Target = DeviceState> where Self: 'a;
+
+    fn device(&self) -> &Device;
+    fn state(&self) -> Self::State<'_>;
+}
+
+impl DeviceDispatch for Device {
+    type State<'a> = Arc<DeviceState>;
+
+    fn device(&self) -> &Device {
+        self
+    }
+
+    fn state(&self) -> Arc<DeviceState> {
+        unsafe { device_table(self.as_raw()) }
+    }
+}
+
+// As InstanceDispatch, for the SpockPhysicalDevice methods, which are
+// called through the functions of the physical device's instance.
+pub trait PhysicalDeviceDispatch {
+    type Fns<'a>: Deref<Target = InstanceFns> where Self: 'a;
+
+    fn physical_device(&self) -> &PhysicalDevice;
+    fn fns(&self) -> Self::Fns<'_>;
+}
+
+impl PhysicalDeviceDispatch for PhysicalDevice {
+    type Fns<'a> = Arc<InstanceFns>;
+
+    fn physical_device(&self) -> &PhysicalDevice {
+        self
+    }
+
+    fn fns(&self) -> Arc<InstanceFns> {
+        unsafe { instance_table(self.as_raw()) }
+    }
+}
+
+// As DeviceDispatch, for the SpockQueue methods.
+pub trait QueueDispatch {
+    type State<'a>: Deref<Target = DeviceState> where Self: 'a;
+
+    fn queue(&self) -> &Queue;
+    fn state(&self) -> Self::State<'_>;
+}
+
+impl QueueDispatch for Queue {
+    type State<'a> = Arc<DeviceState>;
+
+    fn queue(&self) -> &Queue {
+        self
+    }
+
+    fn state(&self) -> Arc<DeviceState> {
+        unsafe { device_table(self.as_raw()) }
+    }
+}
+
+// As DeviceDispatch, for the SpockCommandBuffer methods. Recording into a raw
+// CommandBuffer looks its device up for every command; the wrapper in
+// `cached` does it once.
+pub trait CommandBufferDispatch {
+    type State<'a>: Deref<Target = DeviceState> where Self: 'a;
+
+    fn command_buffer(&self) -> &CommandBuffer;
+    fn state(&self) -> Self::State<'_>;
+}
+
+impl CommandBufferDispatch for CommandBuffer {
+    type State<'a> = Arc<DeviceState>;
+
+    fn command_buffer(&self) -> &CommandBuffer {
+        self
+    }
+
+    fn state(&self) -> Arc<DeviceState> {
+        unsafe { device_table(self.as_raw()) }
+    }
+}
+
+include!("generated/vk.rs");
+
+#[allow(non_snake_case)]
+pub unsafe fn vkGetInstanceProcAddr(instance: Instance, pName: *const c_char) -> Option<VoidFunction> {
+    match loader::loader() {
+        Ok(loader) => loader.get_instance_proc_addr(instance, CStr::from_ptr(pName)),
+        Err(_)     => None
+    }
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkCreateInstance(pCreateInfo: *const InstanceCreateInfo, pAllocator: *const AllocationCallbacks, pInstance: *mut Instance) -> ResultCode {
+    let create_instance = entry_table().vkCreateInstance.expect("vkCreateInstance could not be loaded");
+    let result = create_instance(pCreateInfo, pAllocator, pInstance);
+    if result == ResultCode::Success {
+        let instance = *pInstance;
+        INSTANCES.insert(instance.as_raw(), InstanceFns::load(|name| vkGetInstanceProcAddr(instance, name.as_ptr())));
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkEnumerateInstanceExtensionProperties(pLayerName: *const c_char, pPropertyCount: *mut uint32_t, pProperties: *mut ExtensionProperties) -> ResultCode {
+    let enumerate = entry_table().vkEnumerateInstanceExtensionProperties.expect("vkEnumerateInstanceExtensionProperties could not be loaded");
+    enumerate(pLayerName, pPropertyCount, pProperties)
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkEnumerateInstanceLayerProperties(pPropertyCount: *mut uint32_t, pProperties: *mut LayerProperties) -> ResultCode {
+    let enumerate = entry_table().vkEnumerateInstanceLayerProperties.expect("vkEnumerateInstanceLayerProperties could not be loaded");
+    enumerate(pPropertyCount, pProperties)
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkDestroyInstance(instance: Instance, pAllocator: *const AllocationCallbacks) {
+    if instance.is_null() {
+        return;
+    }
+    let table = INSTANCES.remove(instance.as_raw()).expect("instance was not created through spock");
+    let destroy_instance = table.vkDestroyInstance.expect("vkDestroyInstance could not be loaded");
+    destroy_instance(instance, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkCreateDevice(physicalDevice: PhysicalDevice, pCreateInfo: *const DeviceCreateInfo, pAllocator: *const AllocationCallbacks, pDevice: *mut Device) -> ResultCode {
+    let table = instance_table(physicalDevice.as_raw());
+    let create_device = table.vkCreateDevice.expect("vkCreateDevice could not be loaded");
+    let get_device_proc_addr = table.vkGetDeviceProcAddr.expect("vkGetDeviceProcAddr could not be loaded");
+
+    let result = create_device(physicalDevice, pCreateInfo, pAllocator, pDevice);
+    if result == ResultCode::Success {
+        let device = *pDevice;
+        let mut properties = PhysicalDeviceProperties{..Default::default()};
+        table.vkGetPhysicalDeviceProperties(physicalDevice, &mut properties);
+        DEVICES.insert(device.as_raw(), DeviceState {
+            fns: DeviceFns::load(|name| get_device_proc_addr(device, name.as_ptr())),
+            physical_device: physicalDevice,
+            limits: properties.limits,
+            memory: Mutex::new(HashMap::new()),
+            images: Mutex::new(HashMap::new()),
+            swapchains: Mutex::new(HashMap::new()),
+            query_pools: Mutex::new(HashMap::new())
+        });
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkDestroyDevice(device: Device, pAllocator: *const AllocationCallbacks) {
+    if device.is_null() {
+        return;
+    }
+    let table = DEVICES.remove(device.as_raw()).expect("device was not created through spock");
+    let destroy_device = table.vkDestroyDevice.expect("vkDestroyDevice could not be loaded");
+    destroy_device(device, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkAllocateMemory(device: Device, pAllocateInfo: *const MemoryAllocateInfo, pAllocator: *const AllocationCallbacks,
+                               pMemory: *mut DeviceMemory) -> ResultCode {
+    let state = device_table(device.as_raw());
+    let allocate_memory = state.vkAllocateMemory.expect("vkAllocateMemory could not be loaded");
+
+    let result = allocate_memory(device, pAllocateInfo, pAllocator, pMemory);
+    if result == ResultCode::Success {
+        state.memory.lock().unwrap().insert(*pMemory, Allocation { size: (*pAllocateInfo).allocationSize, mapped: false });
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkFreeMemory(device: Device, memory: DeviceMemory, pAllocator: *const AllocationCallbacks) {
+    let state = device_table(device.as_raw());
+    let free_memory = state.vkFreeMemory.expect("vkFreeMemory could not be loaded");
+    // A MappedMemory only borrows the device, so safe code could otherwise
+    // free memory out from under one and go on using its pointer. The lock is
+    // let go of before panicking, so that it is not poisoned.
+    let mapped = {
+        let mut allocations = state.memory.lock().unwrap();
+        match allocations.get(&memory) {
+            Some(allocation) if allocation.mapped => true,
+            _                                     => {
+                allocations.remove(&memory);
+                false
+            }
+        }
+    };
+    assert!(!mapped, "vkFreeMemory cannot free memory while it is mapped by a MappedMemory");
+    free_memory(device, memory, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkCreateImage(device: Device, pCreateInfo: *const ImageCreateInfo, pAllocator: *const AllocationCallbacks, pImage: *mut Image) -> ResultCode {
+    let state = device_table(device.as_raw());
+    let create_image = state.vkCreateImage.expect("vkCreateImage could not be loaded");
+
+    let result = create_image(device, pCreateInfo, pAllocator, pImage);
+    if result == ResultCode::Success {
+        let create_info = &*pCreateInfo;
+        state.images.lock().unwrap().insert(*pImage, ImageInfo {
+            format: create_info.format,
+            extent: create_info.extent,
+            tiling: create_info.tiling,
+            swapchain: SwapchainKHR::null()
+        });
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkDestroyImage(device: Device, image: Image, pAllocator: *const AllocationCallbacks) {
+    let state = device_table(device.as_raw());
+    let destroy_image = state.vkDestroyImage.expect("vkDestroyImage could not be loaded");
+    state.images.lock().unwrap().remove(&image);
+    destroy_image(device, image, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkCreateQueryPool(device: Device, pCreateInfo: *const QueryPoolCreateInfo, pAllocator: *const AllocationCallbacks,
+                                pQueryPool: *mut QueryPool) -> ResultCode {
+    let state = device_table(device.as_raw());
+    let create_query_pool = state.vkCreateQueryPool.expect("vkCreateQueryPool could not be loaded");
+
+    let result = create_query_pool(device, pCreateInfo, pAllocator, pQueryPool);
+    if result == ResultCode::Success {
+        let create_info = &*pCreateInfo;
+        let values = match create_info.queryType {
+            QueryType::PipelineStatistics => create_info.pipelineStatistics.bits().count_ones(),
+            _                             => 1
+        };
+        state.query_pools.lock().unwrap().insert(*pQueryPool, values);
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkDestroyQueryPool(device: Device, queryPool: QueryPool, pAllocator: *const AllocationCallbacks) {
+    let state = device_table(device.as_raw());
+    let destroy_query_pool = state.vkDestroyQueryPool.expect("vkDestroyQueryPool could not be loaded");
+    state.query_pools.lock().unwrap().remove(&queryPool);
+    destroy_query_pool(device, queryPool, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkCreateSwapchainKHR(device: Device, pCreateInfo: *const SwapchainCreateInfoKHR, pAllocator: *const AllocationCallbacks,
+                                   pSwapchain: *mut SwapchainKHR) -> ResultCode {
+    let state = device_table(device.as_raw());
+    let create_swapchain = state.vkCreateSwapchainKHR.expect("vkCreateSwapchainKHR could not be loaded");
+
+    let result = create_swapchain(device, pCreateInfo, pAllocator, pSwapchain);
+    if result == ResultCode::Success {
+        let create_info = &*pCreateInfo;
+        state.swapchains.lock().unwrap().insert(*pSwapchain, ImageInfo {
+            format: create_info.imageFormat,
+            extent: Extent3D { width: create_info.imageExtent.width, height: create_info.imageExtent.height, depth: 1 },
+            tiling: ImageTiling::Optimal,
+            swapchain: *pSwapchain
+        });
+    }
+    result
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkDestroySwapchainKHR(device: Device, swapchain: SwapchainKHR, pAllocator: *const AllocationCallbacks) {
+    let state = device_table(device.as_raw());
+    let destroy_swapchain = state.vkDestroySwapchainKHR.expect("vkDestroySwapchainKHR could not be loaded");
+    if state.swapchains.lock().unwrap().remove(&swapchain).is_some() {
+        state.images.lock().unwrap().retain(|_, info| info.swapchain != swapchain);
+    }
+    destroy_swapchain(device, swapchain, pAllocator);
+}
+
+#[allow(non_snake_case)]
+pub unsafe fn vkGetSwapchainImagesKHR(device: Device, swapchain: SwapchainKHR, pSwapchainImageCount: *mut uint32_t, pSwapchainImages: *mut Image) -> ResultCode {
+    let state = device_table(device.as_raw());
+    let get_images = state.vkGetSwapchainImagesKHR.expect("vkGetSwapchainImagesKHR could not be loaded");
+
+    let result = get_images(device, swapchain, pSwapchainImageCount, pSwapchainImages);
+    if pSwapchainImages.is_null() || result.as_raw() < 0 {
+        return result;
+    }
+    if let Some(info) = state.swapchains.lock().unwrap().get(&swapchain).cloned() {
+        let mut images = state.images.lock().unwrap();
+        for index in 0..*pSwapchainImageCount as usize {
+            images.insert(*pSwapchainImages.add(index), info);
+        }
+    }
+    result
 }
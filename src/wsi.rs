@@ -0,0 +1,180 @@
+// Window system integration: surfaces to present to, created from the native
+// handles of an Xlib, XCB or Wayland window, or without a window at all
+// through VK_EXT_headless_surface. The instance must have been created with
+// the extensions the kind of surface needs, which are listed below.
+//
+// The queries on a surface, such as its capabilities and formats, are on
+// SpockPhysicalDevice.
+
+use std::mem;
+use std::ops::Deref;
+use std::ptr;
+
+use types::*;
+use vk::*;
+
+pub const XLIB_EXTENSIONS: &[&str] = &[VK_KHR_SURFACE_EXTENSION_NAME, VK_KHR_XLIB_SURFACE_EXTENSION_NAME];
+pub const XCB_EXTENSIONS: &[&str] = &[VK_KHR_SURFACE_EXTENSION_NAME, VK_KHR_XCB_SURFACE_EXTENSION_NAME];
+pub const WAYLAND_EXTENSIONS: &[&str] = &[VK_KHR_SURFACE_EXTENSION_NAME, VK_KHR_WAYLAND_SURFACE_EXTENSION_NAME];
+pub const HEADLESS_EXTENSIONS: &[&str] = &[VK_KHR_SURFACE_EXTENSION_NAME, VK_EXT_HEADLESS_SURFACE_EXTENSION_NAME];
+
+type CreateSurface<T> = unsafe extern "system" fn(Instance, *const T, *const AllocationCallbacks, *mut SurfaceKHR) -> ResultCode;
+
+// A surface, destroyed when dropped. It borrows the instance it was created
+// from, which may be a raw Instance or an OwnedInstance.
+pub struct Surface<'i> {
+    handle: SurfaceKHR,
+    instance: &'i Instance,
+    allocator: Option<AllocationCallbacks>
+}
+
+impl<'i> Surface<'i> {
+    // Creates a surface for an Xlib window.
+    //
+    // # Safety
+    //
+    // `display` and `window` must stay valid for as long as the surface.
+    pub unsafe fn from_xlib(instance: &'i Instance, display: *mut Display, window: Window, allocator_opt: Option<AllocationCallbacks>) -> Result<Surface<'i>, Error> {
+        let create_info = XlibSurfaceCreateInfoKHR {
+            dpy: display,
+            window: window,
+            ..Default::default()
+        };
+        let create = instance.fns().vkCreateXlibSurfaceKHR;
+        Surface::create(instance, create, &create_info, allocator_opt)
+    }
+
+    // Creates a surface for an XCB window.
+    //
+    // # Safety
+    //
+    // `connection` and `window` must stay valid for as long as the surface.
+    pub unsafe fn from_xcb(instance: &'i Instance, connection: *mut xcb_connection_t, window: xcb_window_t, allocator_opt: Option<AllocationCallbacks>) -> Result<Surface<'i>, Error> {
+        let create_info = XcbSurfaceCreateInfoKHR {
+            connection: connection,
+            window: window,
+            ..Default::default()
+        };
+        let create = instance.fns().vkCreateXcbSurfaceKHR;
+        Surface::create(instance, create, &create_info, allocator_opt)
+    }
+
+    // Creates a surface for a Wayland surface.
+    //
+    // # Safety
+    //
+    // `display` and `surface` must stay valid for as long as the surface.
+    pub unsafe fn from_wayland(instance: &'i Instance, display: *mut wl_display, surface: *mut wl_surface, allocator_opt: Option<AllocationCallbacks>) -> Result<Surface<'i>, Error> {
+        let create_info = WaylandSurfaceCreateInfoKHR {
+            display: display,
+            surface: surface,
+            ..Default::default()
+        };
+        let create = instance.fns().vkCreateWaylandSurfaceKHR;
+        Surface::create(instance, create, &create_info, allocator_opt)
+    }
+
+    // A surface with no window behind it, which presentation to succeeds on
+    // without showing anything. Useful for exercising swapchain code where
+    // there is no display, such as in CI.
+    pub fn headless(instance: &'i Instance, allocator_opt: Option<AllocationCallbacks>) -> Result<Surface<'i>, Error> {
+        let create_info = HeadlessSurfaceCreateInfoEXT { ..Default::default() };
+        unsafe {
+            let create = instance.fns().vkCreateHeadlessSurfaceEXT;
+            Surface::create(instance, create, &create_info, allocator_opt)
+        }
+    }
+
+    // Takes ownership of a surface created elsewhere.
+    //
+    // # Safety
+    //
+    // `handle` must have been created from `instance`, and `allocator_opt`
+    // must be the callbacks it was created with.
+    pub unsafe fn from_raw(handle: SurfaceKHR, instance: &'i Instance, allocator_opt: Option<AllocationCallbacks>) -> Surface<'i> {
+        Surface { handle: handle, instance: instance, allocator: allocator_opt }
+    }
+
+    // The platform's create command is only loaded if the instance enabled
+    // its extension; without it the surface cannot be created.
+    unsafe fn create<T>(instance: &'i Instance, create: Option<CreateSurface<T>>, create_info: &T, allocator_opt: Option<AllocationCallbacks>) -> Result<Surface<'i>, Error> {
+        let create = create.ok_or(Error::ExtensionNotPresent)?;
+        let mut handle = SurfaceKHR::null();
+        create(*instance, create_info, pointer_of_option!(allocator_opt), &mut handle).result()?;
+        Ok(Surface { handle: handle, instance: instance, allocator: allocator_opt })
+    }
+
+    pub fn handle(&self) -> SurfaceKHR {
+        self.handle
+    }
+
+    pub fn instance(&self) -> &'i Instance {
+        self.instance
+    }
+
+    // Gives up ownership without destroying the surface.
+    pub fn into_raw(self) -> SurfaceKHR {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+}
+
+impl<'i> Deref for Surface<'i> {
+    type Target = SurfaceKHR;
+
+    fn deref(&self) -> &SurfaceKHR {
+        &self.handle
+    }
+}
+
+impl<'i> Drop for Surface<'i> {
+    fn drop(&mut self) {
+        unsafe { vkDestroySurfaceKHR(*self.instance, self.handle, pointer_of_option!(self.allocator)); }
+    }
+}
+
+// Whether queues of the family `queue_family_index` can present to windows
+// on `display` with the visual `visual_id`, before any surface exists.
+//
+// # Safety
+//
+// `display` must be an open Xlib display, and `physical_device` must come
+// from an instance created with XLIB_EXTENSIONS.
+pub unsafe fn xlib_presentation_support(physical_device: PhysicalDevice, queue_family_index: u32, display: *mut Display, visual_id: VisualID) -> bool {
+    vkGetPhysicalDeviceXlibPresentationSupportKHR(physical_device, queue_family_index, display, visual_id) != VK_FALSE
+}
+
+// As xlib_presentation_support, for XCB.
+//
+// # Safety
+//
+// `connection` must be an open XCB connection, and `physical_device` must
+// come from an instance created with XCB_EXTENSIONS.
+pub unsafe fn xcb_presentation_support(physical_device: PhysicalDevice, queue_family_index: u32, connection: *mut xcb_connection_t, visual_id: xcb_visualid_t) -> bool {
+    vkGetPhysicalDeviceXcbPresentationSupportKHR(physical_device, queue_family_index, connection, visual_id) != VK_FALSE
+}
+
+// As xlib_presentation_support, for Wayland.
+//
+// # Safety
+//
+// `display` must be a connected Wayland display, and `physical_device` must
+// come from an instance created with WAYLAND_EXTENSIONS.
+pub unsafe fn wayland_presentation_support(physical_device: PhysicalDevice, queue_family_index: u32, display: *mut wl_display) -> bool {
+    vkGetPhysicalDeviceWaylandPresentationSupportKHR(physical_device, queue_family_index, display) != VK_FALSE
+}
+
+// Drivers may report present modes from extensions newer than our registry,
+// which have no PresentModeKHR variant; those are left out.
+pub fn present_mode_of_raw(raw: i32) -> Option<PresentModeKHR> {
+    match raw {
+        0             => Some(PresentModeKHR::Immediate),
+        1             => Some(PresentModeKHR::Mailbox),
+        2             => Some(PresentModeKHR::Fifo),
+        3             => Some(PresentModeKHR::FifoRelaxed),
+        1_000_111_000 => Some(PresentModeKHR::SharedDemandRefresh),
+        1_000_111_001 => Some(PresentModeKHR::SharedContinuousRefresh),
+        _             => None
+    }
+}